@@ -0,0 +1,61 @@
+#![feature(test)]
+
+extern crate test;
+
+use num_enum::TryFromPrimitive;
+use test::{black_box, Bencher};
+use whispe_rs_types::NtStatus;
+
+// The previous num_enum derive over the same catalog, kept as the baseline
+macro_rules! define_ntstatus {
+    (
+        statuses { $($name:ident = $value:literal => $message:literal [$($alias:ident),*],)* }
+        $($rest:tt)*
+    ) => {
+        #[repr(i32)]
+        #[allow(non_camel_case_types, dead_code)]
+        #[derive(Copy, Clone, TryFromPrimitive)]
+        enum NumEnumStatus {
+            $($name = $value as _,)*
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/ntstatus.rs"));
+
+/// Every known value and a few unknown ones, so both hits and misses are measured.
+///
+/// Visited with a prime stride rather than in declaration order, which would flatter branch prediction.
+fn values() -> Vec<u32> {
+    let values: Vec<_> = NtStatus::ALL
+        .iter()
+        .map(|status| status.bits())
+        .chain([0x00000004, 0x7FFFFFFF, 0xC0FF0001, 0xFFFFFFFF])
+        .collect();
+
+    (0..values.len())
+        .map(|i| values[i * 7919 % values.len()])
+        .collect()
+}
+
+#[bench]
+fn bench_perfect_hash(b: &mut Bencher) {
+    let values = values();
+    b.iter(|| {
+        values
+            .iter()
+            .filter(|value| NtStatus::try_from(black_box(**value)).is_ok())
+            .count()
+    });
+}
+
+#[bench]
+fn bench_num_enum(b: &mut Bencher) {
+    let values = values();
+    b.iter(|| {
+        values
+            .iter()
+            .filter(|value| NumEnumStatus::try_from_primitive(black_box(**value as i32)).is_ok())
+            .count()
+    });
+}
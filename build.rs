@@ -0,0 +1,254 @@
+use std::{
+    collections::{hash_map::RandomState, BTreeMap, HashMap},
+    env,
+    fmt::Write,
+    fs,
+    hash::{BuildHasher, Hash, Hasher},
+    path::PathBuf,
+    time::SystemTime,
+};
+
+const CATALOG: &str = "data/ntstatus.tsv";
+
+// Fixes the crate-wide hash seed for reproducible builds, see `hash::SEED`
+const SEED_VAR: &str = "WHISPERS_HASH_SEED";
+
+const LOOKUP: &str = "src/ntstatus_lookup.rs";
+include!("src/ntstatus_lookup.rs");
+
+const EMPTY_SLOT: u16 = u16::MAX;
+
+// Facilities of the optional status families, keyed by the cargo feature that compiles them in
+const FAMILIES: &[(&str, &[u16])] = &[
+    ("status-rpc", &[0x2, 0x3]),
+    ("status-tpm", &[0x29, 0x2A]),
+    ("status-ctx", &[0xA]),
+    ("status-vhd", &[0x3A, 0x5C]),
+];
+
+fn is_enabled(value: u32) -> bool {
+    let facility = ((value >> 16) & 0xFFF) as u16;
+    FAMILIES.iter().all(|(feature, facilities)| {
+        let var = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+        !facilities.contains(&facility) || env::var_os(var).is_some()
+    })
+}
+
+/// Builds a hash-and-displace table: every value picks a bucket, and each bucket gets the
+/// displacement that moves all of its values into free slots. Slots hold indices into `values`.
+fn perfect_hash(values: &[u32]) -> (Vec<u16>, Vec<u16>) {
+    let mut buckets = vec![Vec::new(); 1 << LOOKUP_BUCKET_BITS];
+    for (index, &value) in values.iter().enumerate() {
+        buckets[(lookup_mix(value) >> (32 - LOOKUP_BUCKET_BITS)) as usize].push(index);
+    }
+
+    let mut order: Vec<_> = (0..buckets.len()).collect();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut displacements = vec![0u16; buckets.len()];
+    let mut slots = vec![EMPTY_SLOT; 1 << LOOKUP_SLOT_BITS];
+    let slot_of = |value: u32, displacement: u16| {
+        (lookup_mix(value ^ displacement as u32) & ((1 << LOOKUP_SLOT_BITS) - 1)) as usize
+    };
+
+    for bucket in order {
+        let members = &buckets[bucket];
+        let displacement = (0..=u16::MAX)
+            .find(|&displacement| {
+                let mut taken: Vec<_> = members
+                    .iter()
+                    .map(|&index| slot_of(values[index], displacement))
+                    .collect();
+                taken.sort_unstable();
+                taken.dedup();
+                taken.len() == members.len() && taken.iter().all(|&slot| slots[slot] == EMPTY_SLOT)
+            })
+            .expect("no displacement fits, grow the slot table");
+
+        displacements[bucket] = displacement;
+        for &index in members {
+            slots[slot_of(values[index], displacement)] = index as u16;
+        }
+    }
+
+    (displacements, slots)
+}
+
+/// A new seed every time this script runs, from the OS-seeded keys of `RandomState`.
+fn random_seed() -> u32 {
+    let mut hasher = RandomState::new().build_hasher();
+    SystemTime::now().hash(&mut hasher);
+    std::process::id().hash(&mut hasher);
+    let hash = hasher.finish();
+    (hash ^ (hash >> 32)) as u32
+}
+
+fn write_numbers(out: &mut String, numbers: &[u16]) {
+    for (i, number) in numbers.iter().enumerate() {
+        if i % 16 == 0 {
+            out.push_str("\n            ");
+        }
+        write!(out, "{number}, ").unwrap();
+    }
+    out.push_str("\n        ");
+}
+
+struct Status {
+    name: String,
+    value: u32,
+    message: String,
+    /// Set for `NAME<TAB>=TARGET` lines, which share the value of an earlier status.
+    alias: bool,
+}
+
+fn parse_catalog(source: &str) -> Vec<Status> {
+    let mut statuses = Vec::<Status>::new();
+
+    for (line_no, line) in source.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, '\t');
+        let (Some(name), Some(value)) = (fields.next(), fields.next()) else {
+            panic!(
+                "{CATALOG}:{}: expected NAME<TAB>VALUE<TAB>MESSAGE or NAME<TAB>=TARGET",
+                line_no + 1
+            );
+        };
+
+        if let Some(target) = value.strip_prefix('=') {
+            let value = statuses
+                .iter()
+                .find(|status| status.name == target)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "{CATALOG}:{}: {name} aliases undeclared {target}",
+                        line_no + 1
+                    )
+                })
+                .value;
+
+            statuses.push(Status {
+                name: name.to_owned(),
+                value,
+                message: String::new(),
+                alias: true,
+            });
+            continue;
+        }
+
+        let Some(message) = fields.next() else {
+            panic!("{CATALOG}:{}: {name} has no message", line_no + 1);
+        };
+        let value = value
+            .strip_prefix("0x")
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .unwrap_or_else(|| panic!("{CATALOG}:{}: invalid value {value:?}", line_no + 1));
+
+        statuses.push(Status {
+            name: name.to_owned(),
+            value,
+            message: message.to_owned(),
+            alias: false,
+        });
+    }
+
+    statuses
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={CATALOG}");
+    println!("cargo:rerun-if-changed={LOOKUP}");
+    println!("cargo:rerun-if-env-changed={SEED_VAR}");
+
+    // An override is parsed by `hash::SEED`, so a malformed one fails there with a clear error
+    let seed = env::var(SEED_VAR).unwrap_or_else(|_| random_seed().to_string());
+    println!("cargo:rustc-env=WHISPERS_BUILD_SEED={seed}");
+
+    let source = fs::read_to_string(CATALOG).expect("failed to read the NTSTATUS catalog");
+    let statuses = parse_catalog(&source);
+
+    // Aliases are declared with `=TARGET`, any other repeated value is a typo
+    let mut names = HashMap::new();
+    let mut values = HashMap::new();
+    let mut canonical = HashMap::new();
+    let mut variants = Vec::new();
+    let mut aliases = HashMap::<&str, Vec<&str>>::new();
+
+    for status in &statuses {
+        if names.insert(status.name.as_str(), status.value).is_some() {
+            panic!("{CATALOG}: {} is declared twice", status.name);
+        }
+
+        match values.get(&status.value) {
+            Some(first) if !status.alias => panic!(
+                "{CATALOG}: {} repeats the value {:#010X} of {first}, declare it as `{}<TAB>={first}`",
+                status.name, status.value, status.name
+            ),
+            Some(_) => {}
+            None => {
+                values.insert(status.value, status.name.as_str());
+            }
+        }
+
+        if !is_enabled(status.value) {
+            continue;
+        }
+
+        match canonical.get(&status.value) {
+            Some(&target) => aliases
+                .entry(target)
+                .or_default()
+                .push(status.name.as_str()),
+            None => {
+                canonical.insert(status.value, status.name.as_str());
+                variants.push(status);
+            }
+        }
+    }
+
+    assert!(variants.len() < EMPTY_SLOT as usize);
+
+    let mut facilities = BTreeMap::<u16, Vec<&Status>>::new();
+    for status in &variants {
+        let facility = ((status.value >> 16) & 0xFFF) as u16;
+        facilities.entry(facility).or_default().push(status);
+    }
+
+    let mut out = String::from("define_ntstatus! {\n    statuses {\n");
+    for status in &variants {
+        let aliases = aliases
+            .get(status.name.as_str())
+            .map_or(&[][..], Vec::as_slice);
+        writeln!(
+            out,
+            "        {} = {:#010X}u32 => {:?} [{}],",
+            status.name,
+            status.value,
+            status.message,
+            aliases.join(", ")
+        )
+        .unwrap();
+    }
+
+    out.push_str("    }\n    facilities {\n");
+    for (facility, members) in &mut facilities {
+        members.sort_by_key(|status| status.value);
+        let members: Vec<_> = members.iter().map(|status| status.name.as_str()).collect();
+        writeln!(out, "        {facility:#X} => [{}],", members.join(", ")).unwrap();
+    }
+    let values: Vec<_> = variants.iter().map(|status| status.value).collect();
+    let (displacements, slots) = perfect_hash(&values);
+
+    out.push_str("    }\n    lookup {\n        displacements [");
+    write_numbers(&mut out, &displacements);
+    out.push_str("]\n        slots [");
+    write_numbers(&mut out, &slots);
+    out.push_str("]\n");
+    out.push_str("    }\n}\n");
+
+    let path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("ntstatus.rs");
+    fs::write(path, out).expect("failed to write the generated NTSTATUS catalog");
+}
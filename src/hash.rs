@@ -3,14 +3,37 @@ use core::ops::Range;
 
 #[macro_export]
 macro_rules! nt_hash {
+    ($s:expr, algorithm = $a:expr) => {{
+        const _NT_HASH: u32 = $crate::hash_algorithm!($a).nt_hash($s);
+        _NT_HASH
+    }};
     ($s:expr) => {{
         const _NT_HASH: u32 = $crate::hash::nt_hash($s);
         _NT_HASH
     }};
 }
 
+#[macro_export]
+macro_rules! nt_hash64 {
+    ($s:expr, algorithm = $a:expr) => {{
+        const _NT_HASH: u64 = $crate::hash_algorithm64!($a).nt_hash($s);
+        _NT_HASH
+    }};
+    ($s:expr) => {{
+        const _NT_HASH: u64 = $crate::hash::nt_hash64($s);
+        _NT_HASH
+    }};
+}
+
 #[macro_export]
 macro_rules! hash_str {
+    ($s:expr, algorithm = $a:expr) => {
+        $crate::hash_str!($s, 0..usize::MAX, algorithm = $a)
+    };
+    ($s:expr, $r:expr, algorithm = $a:expr) => {{
+        const _HASH: u32 = $crate::hash_algorithm!($a).hash($s, $r);
+        _HASH
+    }};
     ($s:expr, $r:expr) => {{
         const _HASH: u32 = $crate::hash::hash($s, $r);
         _HASH
@@ -20,6 +43,24 @@ macro_rules! hash_str {
     };
 }
 
+#[macro_export]
+macro_rules! hash_str_ci {
+    ($s:expr, algorithm = $a:expr) => {
+        $crate::hash_str_ci!($s, 0..usize::MAX, algorithm = $a)
+    };
+    ($s:expr, $r:expr, algorithm = $a:expr) => {{
+        const _HASH: u32 = $crate::hash_algorithm!($a).hash_ci($s, $r);
+        _HASH
+    }};
+    ($s:expr, $r:expr) => {{
+        const _HASH: u32 = $crate::hash::hash_ci($s, $r);
+        _HASH
+    }};
+    ($s:expr) => {
+        $crate::hash_str_ci!($s, 0..usize::MAX)
+    };
+}
+
 #[macro_export]
 macro_rules! hash_bytes_fn {
     ($h:ident, $s:expr, $r:expr) => {{
@@ -30,28 +71,633 @@ macro_rules! hash_bytes_fn {
 
 #[macro_export]
 macro_rules! hash_bytes {
+    ($s:expr, $r:expr, algorithm = $a:expr) => {{
+        const _HASH: u32 = $crate::hash_algorithm!($a).hash_bytes($s, $r, 1);
+        _HASH
+    }};
     ($s:expr, $r:expr) => {{
-        const _HASH: u32 = $crate::hash::hash_bytes_impl($s, $r);
+        const _HASH: u32 = $crate::hash::hash_bytes_impl($s, $r, 1);
         _HASH
     }};
 }
 
+/// Resolves `Crc32` as well as `HashAlgorithm::Crc32` in the `algorithm = ...` macro argument.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! hash_algorithm {
+    ($a:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::hash::HashAlgorithm::{self, *};
+        const _ALGORITHM: HashAlgorithm = $a;
+        _ALGORITHM
+    }};
+}
+
+/// Like [`hash_algorithm!`] for the `*64` macros, where `Elf` does not resolve.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! hash_algorithm64 {
+    ($a:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::hash::HashAlgorithm64::{self, *};
+        const _ALGORITHM: HashAlgorithm64 = $a;
+        _ALGORITHM
+    }};
+}
+
+/// Crate-wide seed mixed into every [`HashAlgorithm`], so hashes differ between builds.
+///
+/// `build.rs` draws it from OS entropy each time it runs, e.g. on a clean build, and incremental
+/// builds keep it. Set `WHISPERS_HASH_SEED` (decimal or `0x` hex) for reproducible builds, `0`
+/// gives the published hashes.
+pub const SEED: u32 = parse_seed(env!("WHISPERS_BUILD_SEED"));
+
+/// [`SEED`] spread over 64 bits for the 64-bit hashes, `0` when [`SEED`] is.
+pub const SEED64: u64 = (SEED as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+const fn parse_seed(seed: &str) -> u32 {
+    let (digits, radix) = match seed.as_bytes() {
+        [b'0', b'x' | b'X', digits @ ..] => (digits, 16),
+        digits => (digits, 10),
+    };
+    assert!(!digits.is_empty(), "WHISPERS_HASH_SEED is empty");
+
+    let mut seed = 0u32;
+    let mut i = 0;
+    while i < digits.len() {
+        let digit = match digits[i] {
+            b @ b'0'..=b'9' => b - b'0',
+            b @ b'a'..=b'f' if radix == 16 => b - b'a' + 10,
+            b @ b'A'..=b'F' if radix == 16 => b - b'A' + 10,
+            _ => panic!("WHISPERS_HASH_SEED is not a number"),
+        };
+
+        seed = match seed.checked_mul(radix) {
+            Some(seed) => match seed.checked_add(digit as u32) {
+                Some(seed) => seed,
+                None => panic!("WHISPERS_HASH_SEED does not fit in a u32"),
+            },
+            None => panic!("WHISPERS_HASH_SEED does not fit in a u32"),
+        };
+        i += 1;
+    }
+    seed
+}
+
+/// The string hashes `hash_str!`, `nt_hash!`, `hash_bytes!` and `wide_hash` can use.
+///
+/// Call sites pick one with `algorithm = ...`, everything else uses [`DEFAULT`](Self::DEFAULT).
+/// All but [`Elf`](Self::Elf) also have a 64-bit variant, see [`HashAlgorithm64`].
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// The xor djb2 variant from obfstr.
+    Djb2,
+    Sdbm,
+    /// The 32-bit PJW hash of the ELF symbol table.
+    ///
+    /// Its 28 bits collide on some `NtStatus` names under a few seeds, which
+    /// [`NtStatus::from_name_hash`](crate::ntstatus::NtStatus::from_name_hash) then cannot resolve.
+    Elf,
+    /// 32-bit FNV-1a.
+    Fnv1a,
+    /// CRC-32/ISO-HDLC, as in zlib.
+    Crc32,
+    /// 32-bit MurmurHash3 (x86_32).
+    Murmur3,
+}
+
+impl HashAlgorithm {
+    /// Selected by the `hash-*` cargo features, djb2 without one.
+    ///
+    /// Features unify across the dependency graph, so when several are enabled the first of djb2,
+    /// sdbm, elf, fnv1a, crc32 and murmur3 wins.
+    pub const DEFAULT: Self = if cfg!(feature = "hash-djb2") {
+        Self::Djb2
+    } else if cfg!(feature = "hash-sdbm") {
+        Self::Sdbm
+    } else if cfg!(feature = "hash-elf") {
+        Self::Elf
+    } else if cfg!(feature = "hash-fnv1a") {
+        Self::Fnv1a
+    } else if cfg!(feature = "hash-crc32") {
+        Self::Crc32
+    } else if cfg!(feature = "hash-murmur3") {
+        Self::Murmur3
+    } else {
+        Self::Djb2
+    };
+
+    /// The initial state each algorithm is published with, before [`SEED`] is mixed in.
+    #[inline]
+    pub const fn seed(self) -> u32 {
+        match self {
+            Self::Djb2 | Self::Sdbm => 3581,
+            Self::Elf | Self::Crc32 | Self::Murmur3 => 0,
+            Self::Fnv1a => 0x811C_9DC5,
+        }
+    }
+
+    /// The 64-bit variant of this algorithm, `None` for [`Elf`](Self::Elf).
+    #[inline]
+    pub const fn to_64(self) -> Option<HashAlgorithm64> {
+        match self {
+            Self::Djb2 => Some(HashAlgorithm64::Djb2),
+            Self::Sdbm => Some(HashAlgorithm64::Sdbm),
+            Self::Elf => None,
+            Self::Fnv1a => Some(HashAlgorithm64::Fnv1a),
+            Self::Crc32 => Some(HashAlgorithm64::Crc64),
+            Self::Murmur3 => Some(HashAlgorithm64::Murmur3),
+        }
+    }
+
+    /// Hashes from the published initial state mixed with [`SEED`], like the macros do.
+    #[inline]
+    pub const fn hash_bytes(self, s: &[u8], range: Range<usize>, step: usize) -> u32 {
+        self.hash_bytes_with_seed(self.seed() ^ SEED, s, range, step)
+    }
+
+    /// Hashes every `step`th byte of `s` within `range`, starting from `seed`.
+    #[inline]
+    pub const fn hash_bytes_with_seed(
+        self,
+        seed: u32,
+        s: &[u8],
+        range: Range<usize>,
+        step: usize,
+    ) -> u32 {
+        HashState32::new(self, seed).bytes(s, range, step)
+    }
+
+    /// Hashes the characters of `s` within `range`.
+    #[inline]
+    pub const fn hash(self, s: &str, range: Range<usize>) -> u32 {
+        self.hash_str_with_seed(self.seed() ^ SEED, s, range)
+    }
+
+    /// Like [`hash`](Self::hash) starting from `seed`.
+    #[inline]
+    pub const fn hash_str_with_seed(self, seed: u32, s: &str, range: Range<usize>) -> u32 {
+        HashState32::new(self, seed).utf8(s, range, false)
+    }
+
+    /// Hashes the characters after the `Nt`/`Zw` prefix.
+    #[inline]
+    pub const fn nt_hash(self, s: &str) -> u32 {
+        self.hash(s, 2..usize::MAX)
+    }
+
+    /// Hashes the characters of UTF-16 `s` within `range`, agreeing with [`hash`](Self::hash)
+    /// on the same string. Unpaired surrogates hash as their WTF-8 encoding.
+    #[inline]
+    pub const fn wide_hash(self, s: &[u16], range: Range<usize>) -> u32 {
+        self.wide_hash_with_seed(self.seed() ^ SEED, s, range)
+    }
+
+    /// Like [`wide_hash`](Self::wide_hash) starting from `seed`.
+    #[inline]
+    pub const fn wide_hash_with_seed(self, seed: u32, s: &[u16], range: Range<usize>) -> u32 {
+        HashState32::new(self, seed).utf16(s, range, false)
+    }
+
+    #[inline]
+    pub fn hash_utf16str(self, s: &widestring::Utf16Str) -> u32 {
+        self.wide_hash(s.as_slice(), 0..usize::MAX)
+    }
+
+    /// [`hash`](Self::hash) with ASCII letters lowercased, e.g. for loader module names.
+    #[inline]
+    pub const fn hash_ci(self, s: &str, range: Range<usize>) -> u32 {
+        HashState32::new(self, self.seed() ^ SEED).utf8(s, range, true)
+    }
+
+    /// [`wide_hash`](Self::wide_hash) with ASCII letters lowercased, agreeing with [`hash_ci`](Self::hash_ci).
+    #[inline]
+    pub const fn wide_hash_ci(self, s: &[u16], range: Range<usize>) -> u32 {
+        HashState32::new(self, self.seed() ^ SEED).utf16(s, range, true)
+    }
+
+    #[inline]
+    pub fn hash_utf16str_ci(self, s: &widestring::Utf16Str) -> u32 {
+        self.wide_hash_ci(s.as_slice(), 0..usize::MAX)
+    }
+}
+
+/// The 64-bit variants of the [`HashAlgorithm`]s behind `hash64`, `nt_hash64!` and `wide_hash64`.
+///
+/// PJW is only published at 32 bits, and widening its shift leaves short names readable in the
+/// hash, so there is no ELF variant.
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm64 {
+    /// djb2 with a 64-bit state.
+    Djb2,
+    /// sdbm with a 64-bit state.
+    Sdbm,
+    /// 64-bit FNV-1a.
+    Fnv1a,
+    /// CRC-64/XZ.
+    Crc64,
+    /// The first half of MurmurHash3 x64_128.
+    Murmur3,
+}
+
+impl HashAlgorithm64 {
+    /// The 64-bit variant of [`HashAlgorithm::DEFAULT`], or djb2 when that is ELF.
+    pub const DEFAULT: Self = match HashAlgorithm::DEFAULT.to_64() {
+        Some(algorithm) => algorithm,
+        None => Self::Djb2,
+    };
+
+    /// The initial state each algorithm is published with, before [`SEED64`] is mixed in.
+    #[inline]
+    pub const fn seed(self) -> u64 {
+        match self {
+            Self::Djb2 | Self::Sdbm => 3581,
+            Self::Crc64 | Self::Murmur3 => 0,
+            Self::Fnv1a => 0xCBF2_9CE4_8422_2325,
+        }
+    }
+
+    /// Hashes from the published initial state mixed with [`SEED64`].
+    #[inline]
+    pub const fn hash_bytes(self, s: &[u8], range: Range<usize>, step: usize) -> u64 {
+        self.hash_bytes_with_seed(self.seed() ^ SEED64, s, range, step)
+    }
+
+    /// Hashes every `step`th byte of `s` within `range`, starting from `seed`.
+    #[inline]
+    pub const fn hash_bytes_with_seed(
+        self,
+        seed: u64,
+        s: &[u8],
+        range: Range<usize>,
+        step: usize,
+    ) -> u64 {
+        HashState64::new(self, seed).bytes(s, range, step)
+    }
+
+    /// Hashes the characters of `s` within `range`.
+    #[inline]
+    pub const fn hash(self, s: &str, range: Range<usize>) -> u64 {
+        HashState64::new(self, self.seed() ^ SEED64).utf8(s, range, false)
+    }
+
+    /// Hashes the characters after the `Nt`/`Zw` prefix.
+    #[inline]
+    pub const fn nt_hash(self, s: &str) -> u64 {
+        self.hash(s, 2..usize::MAX)
+    }
+
+    /// Hashes the characters of UTF-16 `s` within `range`, agreeing with [`hash`](Self::hash).
+    #[inline]
+    pub const fn wide_hash(self, s: &[u16], range: Range<usize>) -> u64 {
+        HashState64::new(self, self.seed() ^ SEED64).utf16(s, range, false)
+    }
+
+    /// [`hash`](Self::hash) with ASCII letters lowercased.
+    #[inline]
+    pub const fn hash_ci(self, s: &str, range: Range<usize>) -> u64 {
+        HashState64::new(self, self.seed() ^ SEED64).utf8(s, range, true)
+    }
+
+    /// [`wide_hash`](Self::wide_hash) with ASCII letters lowercased, agreeing with [`hash_ci`](Self::hash_ci).
+    #[inline]
+    pub const fn wide_hash_ci(self, s: &[u16], range: Range<usize>) -> u64 {
+        HashState64::new(self, self.seed() ^ SEED64).utf16(s, range, true)
+    }
+}
+
+/// The code point starting at `s[i]` and the number of units it takes.
+const fn decode_utf16(s: &[u16], i: usize) -> (u32, usize) {
+    let unit = s[i] as u32;
+    if unit & 0xFC00 == 0xD800 && i + 1 < s.len() && s[i + 1] & 0xFC00 == 0xDC00 {
+        let low = s[i + 1] as u32;
+        return (0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00), 2);
+    }
+    (unit, 1)
+}
+
+// There are two widths, each with its own state. The 64-bit algorithms are not the 32-bit ones
+// widened (CRC-64 has its own polynomial, MurmurHash3 x64_128 its own block size), so only the
+// input encodings below are shared, and `HashWidth` picks a width by type for generic callers.
+
+/// Byte-at-a-time state of a [`HashAlgorithm`], so every input encoding feeds the same core.
+#[derive(Clone, Copy)]
+struct HashState32 {
+    algorithm: HashAlgorithm,
+    hash: u32,
+    block: u32,
+    len: u32,
+}
+
+impl HashState32 {
+    const fn new(algorithm: HashAlgorithm, seed: u32) -> Self {
+        Self {
+            algorithm,
+            hash: match algorithm {
+                // Continues a CRC of `seed`, like zlib's `crc32(seed, ...)`
+                HashAlgorithm::Crc32 => !seed,
+                _ => seed,
+            },
+            block: 0,
+            len: 0,
+        }
+    }
+
+    const fn write(mut self, byte: u8) -> Self {
+        let (hash, byte) = (self.hash, byte as u32);
+        self.hash = match self.algorithm {
+            HashAlgorithm::Djb2 => hash.wrapping_mul(33) ^ byte,
+            HashAlgorithm::Sdbm => hash.wrapping_mul(65599).wrapping_add(byte),
+            HashAlgorithm::Elf => {
+                let hash = hash.rotate_left(4).wrapping_add(byte);
+                let high = hash & 0xF0000000;
+                (hash ^ high.rotate_right(24)) & !high
+            }
+            HashAlgorithm::Fnv1a => (hash ^ byte).wrapping_mul(0x0100_0193),
+            HashAlgorithm::Crc32 => CRC32_TABLE[((hash ^ byte) & 0xFF) as usize] ^ (hash >> 8),
+            // Streams the bytes through MurmurHash3's 4-byte blocks
+            HashAlgorithm::Murmur3 => {
+                self.block |= byte << (8 * (self.len % 4));
+                match self.len % 4 {
+                    3 => {
+                        let hash = (hash ^ murmur3_scramble(self.block))
+                            .rotate_left(13)
+                            .wrapping_mul(5)
+                            .wrapping_add(0xE654_6B64);
+                        self.block = 0;
+                        hash
+                    }
+                    _ => hash,
+                }
+            }
+        };
+        self.len = self.len.wrapping_add(1);
+        self
+    }
+
+    const fn finish(self) -> u32 {
+        match self.algorithm {
+            HashAlgorithm::Crc32 => !self.hash,
+            HashAlgorithm::Murmur3 => {
+                let mut hash = self.hash;
+                if self.len % 4 != 0 {
+                    hash ^= murmur3_scramble(self.block);
+                }
+
+                hash ^= self.len;
+                hash ^= hash >> 16;
+                hash = hash.wrapping_mul(0x85EB_CA6B);
+                hash ^= hash >> 13;
+                hash = hash.wrapping_mul(0xC2B2_AE35);
+                hash ^ (hash >> 16)
+            }
+            _ => self.hash,
+        }
+    }
+}
+
+/// Byte-at-a-time state of a [`HashAlgorithm64`].
+#[derive(Clone, Copy)]
+struct HashState64 {
+    algorithm: HashAlgorithm64,
+    hash: u64,
+    /// The second lane of MurmurHash3 x64_128.
+    lane: u64,
+    block: u128,
+    len: u64,
+}
+
+impl HashState64 {
+    const fn new(algorithm: HashAlgorithm64, seed: u64) -> Self {
+        Self {
+            algorithm,
+            hash: match algorithm {
+                HashAlgorithm64::Crc64 => !seed,
+                _ => seed,
+            },
+            lane: seed,
+            block: 0,
+            len: 0,
+        }
+    }
+
+    const fn write(mut self, byte: u8) -> Self {
+        let (hash, byte) = (self.hash, byte as u64);
+        self.hash = match self.algorithm {
+            HashAlgorithm64::Djb2 => hash.wrapping_mul(33) ^ byte,
+            HashAlgorithm64::Sdbm => hash.wrapping_mul(65599).wrapping_add(byte),
+            HashAlgorithm64::Fnv1a => (hash ^ byte).wrapping_mul(0x0100_0000_01B3),
+            HashAlgorithm64::Crc64 => CRC64_TABLE[((hash ^ byte) & 0xFF) as usize] ^ (hash >> 8),
+            // Streams the bytes through MurmurHash3 x64_128's 16-byte blocks
+            HashAlgorithm64::Murmur3 => {
+                self.block |= (byte as u128) << (8 * (self.len % 16));
+                match self.len % 16 {
+                    15 => {
+                        let mut hash = hash ^ murmur3_scramble64(self.block as u64);
+                        hash = hash
+                            .rotate_left(27)
+                            .wrapping_add(self.lane)
+                            .wrapping_mul(5)
+                            .wrapping_add(0x52DC_E729);
+
+                        self.lane ^= murmur3_scramble64_high((self.block >> 64) as u64);
+                        self.lane = self
+                            .lane
+                            .rotate_left(31)
+                            .wrapping_add(hash)
+                            .wrapping_mul(5)
+                            .wrapping_add(0x3849_5AB5);
+                        self.block = 0;
+                        hash
+                    }
+                    _ => hash,
+                }
+            }
+        };
+        self.len = self.len.wrapping_add(1);
+        self
+    }
+
+    /// The first 8 bytes of the 128-bit digest for MurmurHash3.
+    const fn finish(self) -> u64 {
+        match self.algorithm {
+            HashAlgorithm64::Crc64 => !self.hash,
+            HashAlgorithm64::Murmur3 => {
+                let (mut hash, mut lane) = (self.hash, self.lane);
+                if self.len % 16 > 8 {
+                    lane ^= murmur3_scramble64_high((self.block >> 64) as u64);
+                }
+                if self.len % 16 != 0 {
+                    hash ^= murmur3_scramble64(self.block as u64);
+                }
+
+                hash ^= self.len;
+                lane ^= self.len;
+                hash = hash.wrapping_add(lane);
+                lane = lane.wrapping_add(hash);
+                murmur3_fmix64(hash).wrapping_add(murmur3_fmix64(lane))
+            }
+            _ => self.hash,
+        }
+    }
+}
+
+/// The input encodings, written once over each width's `new`, `write` and `finish`.
+macro_rules! impl_hash_input {
+    ($($state:ty => $width:ty),*) => {$(
+        impl $state {
+            /// Feeds the UTF-8 (WTF-8 for surrogates) encoding of `c`.
+            const fn write_char(self, c: u32) -> Self {
+                match c {
+                    0..=0x7F => self.write(c as u8),
+                    0x80..=0x7FF => self
+                        .write(0xC0 | (c >> 6) as u8)
+                        .write(0x80 | (c & 0x3F) as u8),
+                    0x800..=0xFFFF => self
+                        .write(0xE0 | (c >> 12) as u8)
+                        .write(0x80 | ((c >> 6) & 0x3F) as u8)
+                        .write(0x80 | (c & 0x3F) as u8),
+                    _ => self
+                        .write(0xF0 | (c >> 18) as u8)
+                        .write(0x80 | ((c >> 12) & 0x3F) as u8)
+                        .write(0x80 | ((c >> 6) & 0x3F) as u8)
+                        .write(0x80 | (c & 0x3F) as u8),
+                }
+            }
+
+            /// Hashes every `step`th byte of `s` within `range`.
+            const fn bytes(mut self, s: &[u8], range: Range<usize>, step: usize) -> $width {
+                let mut i = range.start;
+                while i < min(s.len(), range.end) {
+                    self = self.write(s[i]);
+                    i += step;
+                }
+                self.finish()
+            }
+
+            /// Hashes the characters of `s` within `range`, lowercasing ASCII if `fold`.
+            const fn utf8(mut self, s: &str, range: Range<usize>, fold: bool) -> $width {
+                let s = s.as_bytes();
+
+                // Feeds the UTF-8 bytes of every character in range, counting characters at lead bytes
+                let (mut i, mut chars) = (0, 0);
+                while i < s.len() {
+                    if s[i] & 0xC0 != 0x80 {
+                        chars += 1;
+                    }
+
+                    let index = chars - 1;
+                    if index >= range.end {
+                        break;
+                    }
+                    if index >= range.start {
+                        // Multi-byte sequences never contain ASCII bytes, so this only folds A-Z
+                        self = self.write(match fold {
+                            true => s[i].to_ascii_lowercase(),
+                            false => s[i],
+                        });
+                    }
+                    i += 1;
+                }
+                self.finish()
+            }
+
+            /// Hashes the characters of UTF-16 `s` within `range`, lowercasing ASCII if `fold`.
+            const fn utf16(mut self, s: &[u16], range: Range<usize>, fold: bool) -> $width {
+                let (mut i, mut index) = (0, 0);
+                while i < s.len() && index < range.end {
+                    let (c, units) = decode_utf16(s, i);
+                    if index >= range.start {
+                        self = self.write_char(match fold && matches!(c, 0x41..=0x5A) {
+                            true => c + 0x20,
+                            false => c,
+                        });
+                    }
+                    i += units;
+                    index += 1;
+                }
+                self.finish()
+            }
+        }
+    )*};
+}
+
+impl_hash_input!(HashState32 => u32, HashState64 => u64);
+
+/// A hash output width, `u32` for [`HashAlgorithm`] and `u64` for [`HashAlgorithm64`].
+///
+/// Lets code pick the width with a type parameter, e.g. [`hash_as::<u64>`](hash_as), where the
+/// `*64` functions fix it by name.
+pub trait HashWidth: Copy + Eq + core::hash::Hash {
+    /// The algorithms available at this width.
+    type Algorithm: Copy + Eq;
+
+    /// [`HashAlgorithm::DEFAULT`] or [`HashAlgorithm64::DEFAULT`].
+    const DEFAULT: Self::Algorithm;
+
+    /// Hashes every `step`th byte of `s` within `range`, like `hash_bytes` on the algorithm.
+    fn hash_bytes(algorithm: Self::Algorithm, s: &[u8], range: Range<usize>, step: usize) -> Self;
+
+    /// Hashes the characters of `s` within `range`.
+    fn hash_str(algorithm: Self::Algorithm, s: &str, range: Range<usize>) -> Self;
+
+    /// Hashes the characters of UTF-16 `s` within `range`, agreeing with [`hash_str`](Self::hash_str).
+    fn wide_hash(algorithm: Self::Algorithm, s: &[u16], range: Range<usize>) -> Self;
+
+    /// [`hash_str`](Self::hash_str) with ASCII letters lowercased.
+    fn hash_ci(algorithm: Self::Algorithm, s: &str, range: Range<usize>) -> Self;
+
+    /// [`wide_hash`](Self::wide_hash) with ASCII letters lowercased.
+    fn wide_hash_ci(algorithm: Self::Algorithm, s: &[u16], range: Range<usize>) -> Self;
+}
+
+/// Forwards [`HashWidth`] to the inherent methods of each width's algorithm.
+macro_rules! impl_hash_width {
+    ($($width:ty => $algorithm:ty),*) => {$(
+        impl HashWidth for $width {
+            type Algorithm = $algorithm;
+
+            const DEFAULT: $algorithm = <$algorithm>::DEFAULT;
+
+            #[inline(always)]
+            fn hash_bytes(algorithm: $algorithm, s: &[u8], range: Range<usize>, step: usize) -> Self {
+                algorithm.hash_bytes(s, range, step)
+            }
+
+            #[inline(always)]
+            fn hash_str(algorithm: $algorithm, s: &str, range: Range<usize>) -> Self {
+                algorithm.hash(s, range)
+            }
+
+            #[inline(always)]
+            fn wide_hash(algorithm: $algorithm, s: &[u16], range: Range<usize>) -> Self {
+                algorithm.wide_hash(s, range)
+            }
+
+            #[inline(always)]
+            fn hash_ci(algorithm: $algorithm, s: &str, range: Range<usize>) -> Self {
+                algorithm.hash_ci(s, range)
+            }
+
+            #[inline(always)]
+            fn wide_hash_ci(algorithm: $algorithm, s: &[u16], range: Range<usize>) -> Self {
+                algorithm.wide_hash_ci(s, range)
+            }
+        }
+    )*};
+}
+
+impl_hash_width!(u32 => HashAlgorithm, u64 => HashAlgorithm64);
+
 pub fn hash_utf16str(s: &widestring::Utf16Str) -> u32 {
-    let bytes: &[u8] = unsafe { core::slice::from_raw_parts(s.as_ptr() as _, s.len() * 2) };
-    hash_bytes_impl(bytes, 0..usize::MAX, 2)
+    HashAlgorithm::DEFAULT.hash_utf16str(s)
 }
 
 /// Modified from the obfstr library
 pub const fn djb2(s: &[u8], range: Range<usize>, step: usize) -> u32 {
-    let mut result = 3581u32;
-    let mut i = range.start; // Starts at the 3rd character
-
-    // TODO: Fix min(.., range.end) code when step != 1
-    while i < min(s.len(), range.end) {
-        result = result.wrapping_mul(33) ^ s[i] as u32;
-        i += step;
-    }
-    result
+    HashAlgorithm::Djb2.hash_bytes_with_seed(HashAlgorithm::Djb2.seed(), s, range, step)
 }
 
 #[cfg(test)]
@@ -62,18 +708,16 @@ fn test_djb2_impl() {
     for test in kgs_tests.iter() {
         assert_eq!(djb2(test.0, 0..usize::MAX, 1), test.1);
     }
+
+    // Reference: obfstr::hash
+    for s in ["", "a", "NtCreateProcess", "STATUS_INVALID_HANDLE"] {
+        assert_eq!(djb2(s.as_bytes(), 0..usize::MAX, 1), obfstr::hash(s));
+    }
 }
 
 #[inline(always)]
 pub const fn sdbm(s: &[u8], range: Range<usize>, step: usize) -> u32 {
-    let mut result = 3581u32;
-    let mut i = range.start;
-
-    while i < min(s.len(), range.end) {
-        result = result.wrapping_mul(65599) + s[i] as u32;
-        i += step;
-    }
-    result
+    HashAlgorithm::Sdbm.hash_bytes_with_seed(HashAlgorithm::Sdbm.seed(), s, range, step)
 }
 
 #[test]
@@ -84,39 +728,197 @@ fn test_sdbm_impl() {
     for test in kgs_tests.iter() {
         assert_eq!(sdbm(test.0, 0..usize::MAX, 1), test.1);
     }
+
+    // The classic zero-seeded sdbm, hash(i) = hash(i - 1) * 65599 + str[i]
+    let classic = |s: &[u8]| HashAlgorithm::Sdbm.hash_bytes_with_seed(0, s, 0..usize::MAX, 1);
+    assert_eq!(classic(b""), 0);
+    assert_eq!(classic(b"a"), 97);
+    assert_eq!(classic(b"admin"), 525286959);
 }
 
 /// ElfHash (32-bit variant) hash function.
+pub const fn elf(s: &[u8], range: Range<usize>, step: usize) -> u32 {
+    HashAlgorithm::Elf.hash_bytes_with_seed(HashAlgorithm::Elf.seed(), s, range, step)
+}
+
+/// [`elf`] under the crate-wide [`SEED`].
 #[warn(dead_code)]
 pub const fn seeded_elf(s: &[u8], range: Range<usize>, step: usize) -> u32 {
-    let mut result: u32 = obfstr::random!(u32)
-        .wrapping_mul((range.end - range.start) as _)
-        .wrapping_mul(s.len() as _);
-
-    let mut high;
-
-    let mut i = range.start;
-    while i < min(s.len(), range.end) {
-        result = result.rotate_left(4) + s[i] as u32;
-        high = result & 0xF0000000;
-        if high != 0 {
-            result ^= high.rotate_right(24);
+    HashAlgorithm::Elf.hash_bytes(s, range, step)
+}
+
+#[cfg(test)]
+#[test]
+fn test_elf_impl() {
+    // Reference: the System V ABI symbol hash
+    let abi_tests: [(&[u8], u32); 4] = [
+        (b"", 0),
+        (b"main", 0x000737FE),
+        (b"exit", 0x0006CF04),
+        (b"printf", 0x077905A6),
+    ];
+
+    for test in abi_tests.iter() {
+        assert_eq!(elf(test.0, 0..usize::MAX, 1), test.1);
+    }
+}
+
+pub const fn fnv1a(s: &[u8], range: Range<usize>, step: usize) -> u32 {
+    HashAlgorithm::Fnv1a.hash_bytes_with_seed(HashAlgorithm::Fnv1a.seed(), s, range, step)
+}
+
+#[cfg(test)]
+#[test]
+fn test_fnv1a_impl() {
+    // Reference: the FNV test suite
+    let fnv_tests: [(&[u8], u32); 4] = [
+        (b"", 0x811C9DC5),
+        (b"a", 0xE40C292C),
+        (b"foobar", 0xBF9CF968),
+        (b"chongo was here!\n", 0xD49930D5),
+    ];
+
+    for test in fnv_tests.iter() {
+        assert_eq!(fnv1a(test.0, 0..usize::MAX, 1), test.1);
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                0 => crc >> 1,
+                _ => (crc >> 1) ^ 0xEDB8_8320,
+            };
+            bit += 1;
         }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
 
-        result &= !high;
-        i += step;
+/// CRC-64/XZ, for [`HashAlgorithm64::Crc64`].
+const CRC64_TABLE: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                0 => crc >> 1,
+                _ => (crc >> 1) ^ 0xC96C_5795_D787_0F42,
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
     }
+    table
+};
+
+pub const fn crc32(s: &[u8], range: Range<usize>, step: usize) -> u32 {
+    HashAlgorithm::Crc32.hash_bytes_with_seed(HashAlgorithm::Crc32.seed(), s, range, step)
+}
+
+#[cfg(test)]
+#[test]
+fn test_crc32_impl() {
+    // Reference: the CRC catalogue check value and zlib
+    let crc_tests: [(&[u8], u32); 4] = [
+        (b"", 0),
+        (b"a", 0xE8B7BE43),
+        (b"123456789", 0xCBF43926),
+        (b"The quick brown fox jumps over the lazy dog", 0x414FA339),
+    ];
+
+    for test in crc_tests.iter() {
+        assert_eq!(crc32(test.0, 0..usize::MAX, 1), test.1);
+    }
+
+    // Seeding continues the CRC
+    let first = crc32(b"12345", 0..usize::MAX, 1);
+    assert_eq!(
+        HashAlgorithm::Crc32.hash_bytes_with_seed(first, b"6789", 0..usize::MAX, 1),
+        0xCBF43926
+    );
+}
+
+pub const fn murmur3(s: &[u8], range: Range<usize>, step: usize) -> u32 {
+    HashAlgorithm::Murmur3.hash_bytes_with_seed(HashAlgorithm::Murmur3.seed(), s, range, step)
+}
+
+const fn murmur3_scramble(k: u32) -> u32 {
+    k.wrapping_mul(0xCC9E_2D51)
+        .rotate_left(15)
+        .wrapping_mul(0x1B87_3593)
+}
+
+const fn murmur3_scramble64(k: u64) -> u64 {
+    k.wrapping_mul(0x87C3_7B91_1142_53D5)
+        .rotate_left(31)
+        .wrapping_mul(0x4CF5_AD43_2745_937F)
+}
+
+const fn murmur3_scramble64_high(k: u64) -> u64 {
+    k.wrapping_mul(0x4CF5_AD43_2745_937F)
+        .rotate_left(33)
+        .wrapping_mul(0x87C3_7B91_1142_53D5)
+}
+
+const fn murmur3_fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+    k ^ (k >> 33)
+}
+
+#[cfg(test)]
+#[test]
+fn test_murmur3_impl() {
+    // Reference: the SMHasher x86_32 implementation
+    let murmur_tests: [(u32, &[u8], u32); 8] = [
+        (0, b"", 0),
+        (1, b"", 0x514E28B7),
+        (0xFFFFFFFF, b"", 0x81F16F39),
+        (0, b"\0\0\0\0", 0x2362F9DE),
+        (0x9747B28C, b"aaaa", 0x5A97808A),
+        (0x9747B28C, b"abc", 0xC84A62DD),
+        (0x9747B28C, b"Hello, world!", 0x24884CBA),
+        (
+            0x9747B28C,
+            b"The quick brown fox jumps over the lazy dog",
+            0x2FA826CD,
+        ),
+    ];
 
-    result
+    for test in murmur_tests.iter() {
+        assert_eq!(
+            HashAlgorithm::Murmur3.hash_bytes_with_seed(test.0, test.1, 0..usize::MAX, 1),
+            test.2
+        );
+    }
+    assert_eq!(murmur3(b"", 0..usize::MAX, 1), 0);
 }
 
 use crate::util::min;
-pub use djb2 as hash_bytes_impl;
 
+/// Hashes with [`HashAlgorithm::DEFAULT`].
+#[inline(always)]
+pub const fn hash_bytes_impl(s: &[u8], range: Range<usize>, step: usize) -> u32 {
+    HashAlgorithm::DEFAULT.hash_bytes(s, range, step)
+}
+
+/// Hashes the characters of UTF-16 `s` within `range`, like [`hash`] on the same string.
 #[inline(always)]
 pub fn wide_hash(s: impl AsRef<[u16]>, range: Range<usize>) -> u32 {
-    let (_, s, _) = unsafe { s.as_ref().align_to::<u8>() };
-    hash_bytes_impl(s, range, 2)
+    HashAlgorithm::DEFAULT.wide_hash(s.as_ref(), range)
 }
 
 #[test]
@@ -128,14 +930,72 @@ fn test_wide_hash() {
     assert_eq!(wide_hash(x, 0..usize::MAX), hash(y, 0..usize::MAX));
 }
 
+/// Hashes the characters of `s` within `range`.
 #[inline(always)]
 pub const fn hash(s: &str, range: Range<usize>) -> u32 {
-    hash_bytes_impl(s.as_bytes(), range, 1)
+    HashAlgorithm::DEFAULT.hash(s, range)
 }
 
 #[inline(always)]
 pub const fn nt_hash(s: &str) -> u32 {
-    crate::hash::hash(s, 2..s.len())
+    HashAlgorithm::DEFAULT.nt_hash(s)
+}
+
+/// [`hash`] with [`HashAlgorithm64::DEFAULT`].
+#[inline(always)]
+pub const fn hash64(s: &str, range: Range<usize>) -> u64 {
+    HashAlgorithm64::DEFAULT.hash(s, range)
+}
+
+#[inline(always)]
+pub const fn nt_hash64(s: &str) -> u64 {
+    HashAlgorithm64::DEFAULT.nt_hash(s)
+}
+
+/// [`wide_hash`] with [`HashAlgorithm64::DEFAULT`], like [`hash64`] on the same string.
+#[inline(always)]
+pub fn wide_hash64(s: impl AsRef<[u16]>, range: Range<usize>) -> u64 {
+    HashAlgorithm64::DEFAULT.wide_hash(s.as_ref(), range)
+}
+
+/// [`hash`] or [`hash64`] by the width `W`.
+#[inline(always)]
+pub fn hash_as<W: HashWidth>(s: &str, range: Range<usize>) -> W {
+    W::hash_str(W::DEFAULT, s, range)
+}
+
+/// [`nt_hash`](fn@nt_hash) or [`nt_hash64`](fn@nt_hash64) by the width `W`.
+#[inline(always)]
+pub fn nt_hash_as<W: HashWidth>(s: &str) -> W {
+    W::hash_str(W::DEFAULT, s, 2..usize::MAX)
+}
+
+/// [`wide_hash`] or [`wide_hash64`] by the width `W`.
+#[inline(always)]
+pub fn wide_hash_as<W: HashWidth>(s: impl AsRef<[u16]>, range: Range<usize>) -> W {
+    W::wide_hash(W::DEFAULT, s.as_ref(), range)
+}
+
+/// [`hash_bytes_impl`] at either width, `u64` hashing with [`HashAlgorithm64::DEFAULT`].
+#[inline(always)]
+pub fn hash_bytes_as<W: HashWidth>(s: &[u8], range: Range<usize>, step: usize) -> W {
+    W::hash_bytes(W::DEFAULT, s, range, step)
+}
+
+/// [`hash`] ignoring ASCII case.
+#[inline(always)]
+pub const fn hash_ci(s: &str, range: Range<usize>) -> u32 {
+    HashAlgorithm::DEFAULT.hash_ci(s, range)
+}
+
+/// [`wide_hash`] ignoring ASCII case, like [`hash_ci`] on the same string.
+#[inline(always)]
+pub fn wide_hash_ci(s: impl AsRef<[u16]>, range: Range<usize>) -> u32 {
+    HashAlgorithm::DEFAULT.wide_hash_ci(s.as_ref(), range)
+}
+
+pub fn hash_utf16str_ci(s: &widestring::Utf16Str) -> u32 {
+    HashAlgorithm::DEFAULT.hash_utf16str_ci(s)
 }
 
 #[cfg(test)]
@@ -148,3 +1008,444 @@ fn test_nt_hash() {
         crate::hash_str!("NtDelayExecution", 2..usize::MAX)
     );
 }
+
+#[cfg(test)]
+#[test]
+fn test_algorithm_per_call_site() {
+    use HashAlgorithm::*;
+
+    let algorithms = [Djb2, Sdbm, Elf, Fnv1a, Crc32, Murmur3];
+    const NAME: &str = "NtQueryInformationProcess";
+
+    let by_macro = [
+        crate::hash_str!(NAME, algorithm = Djb2),
+        crate::hash_str!(NAME, algorithm = HashAlgorithm::Sdbm),
+        crate::hash_str!(NAME, algorithm = Elf),
+        crate::hash_str!(NAME, algorithm = Fnv1a),
+        crate::hash_str!(NAME, algorithm = Crc32),
+        crate::hash_str!(NAME, algorithm = Murmur3),
+    ];
+
+    for (algorithm, hash) in algorithms.iter().zip(by_macro) {
+        assert_eq!(algorithm.hash(NAME, 0..usize::MAX), hash);
+        assert_eq!(
+            algorithm.wide_hash(obfstr::obfwide!("NtQueryInformationProcess"), 0..usize::MAX),
+            hash
+        );
+    }
+
+    // Every algorithm gives a different hash
+    for (i, a) in by_macro.iter().enumerate() {
+        assert!(!by_macro[i + 1..].contains(a));
+    }
+
+    assert_eq!(
+        crate::nt_hash!("ZwClose", algorithm = Crc32),
+        crate::hash_str!("NtClose", 2..usize::MAX, algorithm = Crc32)
+    );
+    assert_eq!(
+        crate::hash_bytes!(b"\x4d\x5a", 0..2, algorithm = Crc32),
+        Crc32.hash_bytes(b"MZ", 0..usize::MAX, 1)
+    );
+    assert_eq!(
+        crate::hash_bytes!(b"MZ", 0..usize::MAX),
+        hash_bytes_impl(b"MZ", 0..usize::MAX, 1)
+    );
+    assert_eq!(
+        crate::hash_str!(NAME),
+        HashAlgorithm::DEFAULT.hash(NAME, 0..usize::MAX)
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_seed() {
+    use HashAlgorithm::*;
+
+    assert_eq!(parse_seed("0"), 0);
+    assert_eq!(parse_seed("3735928559"), 0xDEADBEEF);
+    assert_eq!(parse_seed("0xdeadBEEF"), 0xDEADBEEF);
+    assert_eq!(parse_seed("0X10"), 16);
+
+    const NAME: &str = "NtAllocateVirtualMemory";
+    const WIDE: &widestring::Utf16Str = widestring::utf16str!("NtAllocateVirtualMemory");
+
+    // Compile-time and runtime hashes agree for every algorithm
+    let compile_time = [
+        (Djb2, crate::hash_str!(NAME, algorithm = Djb2)),
+        (Sdbm, crate::hash_str!(NAME, algorithm = Sdbm)),
+        (Elf, crate::hash_str!(NAME, algorithm = Elf)),
+        (Fnv1a, crate::hash_str!(NAME, algorithm = Fnv1a)),
+        (Crc32, crate::hash_str!(NAME, algorithm = Crc32)),
+        (Murmur3, crate::hash_str!(NAME, algorithm = Murmur3)),
+    ];
+
+    for (algorithm, hash) in compile_time {
+        assert_eq!(algorithm.wide_hash(WIDE.as_slice(), 0..usize::MAX), hash);
+        assert_eq!(algorithm.hash_utf16str(WIDE), hash);
+        assert_eq!(
+            algorithm.hash_bytes_with_seed(
+                algorithm.seed() ^ SEED,
+                NAME.as_bytes(),
+                0..usize::MAX,
+                1
+            ),
+            hash
+        );
+    }
+
+    assert_eq!(
+        crate::hash_str!(NAME),
+        wide_hash(WIDE.as_slice(), 0..usize::MAX)
+    );
+    assert_eq!(crate::nt_hash!(NAME), HashAlgorithm::DEFAULT.nt_hash(NAME));
+    assert_eq!(
+        crate::hash_bytes!(b"MZ", 0..usize::MAX),
+        HashAlgorithm::DEFAULT.hash_bytes(b"MZ", 0..usize::MAX, 1)
+    );
+    assert_eq!(
+        seeded_elf(b"MZ", 0..usize::MAX, 1),
+        Elf.hash_bytes(b"MZ", 0..usize::MAX, 1)
+    );
+
+    // hash-djb2 wins over any other hash-* feature
+    if cfg!(feature = "hash-djb2") {
+        assert!(HashAlgorithm::DEFAULT == Djb2);
+    }
+
+    // The seed moves every hash away from the published value
+    if SEED != 0 {
+        assert_ne!(crate::hash_str!(NAME, algorithm = Djb2), obfstr::hash(NAME));
+        assert_ne!(
+            crate::hash_str!(NAME, algorithm = Crc32),
+            crc32(NAME.as_bytes(), 0..usize::MAX, 1)
+        );
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_character_ranges() {
+    use crate::util::to_wide;
+    use HashAlgorithm::*;
+
+    let strings = [
+        "NtCreateFile",
+        "Ünïcödé",
+        "ŁódźKraków",
+        "名前付きパイプ",
+        "🦀Ferris🦀",
+        "a\u{7FF}\u{800}\u{FFFF}\u{10000}\u{10FFFF}",
+        "",
+    ];
+    #[allow(clippy::reversed_empty_ranges)]
+    let ranges = [
+        0..usize::MAX,
+        2..usize::MAX,
+        0..3,
+        1..4,
+        3..3,
+        5..2,
+        100..200,
+    ];
+
+    for algorithm in [Djb2, Sdbm, Elf, Fnv1a, Crc32, Murmur3] {
+        for s in strings {
+            let wide = &*to_wide(s);
+            let utf16 = widestring::Utf16Str::from_slice(wide).unwrap();
+
+            assert_eq!(
+                algorithm.hash(s, 0..usize::MAX),
+                algorithm.hash_utf16str(utf16)
+            );
+
+            for range in ranges.clone() {
+                let hash = algorithm.hash(s, range.clone());
+                assert_eq!(
+                    hash,
+                    algorithm.wide_hash(wide, range.clone()),
+                    "{s} {range:?}"
+                );
+
+                // A range selects the same characters as hashing them on their own
+                let start = s
+                    .char_indices()
+                    .nth(range.start)
+                    .map_or(s.len(), |(i, _)| i);
+                let end = s.char_indices().nth(range.end).map_or(s.len(), |(i, _)| i);
+                let chars = s.get(start..end.max(start)).unwrap();
+                assert_eq!(hash, algorithm.hash(chars, 0..usize::MAX), "{s} {range:?}");
+            }
+        }
+    }
+
+    // The whole UTF-16 unit counts, not only its low byte
+    assert_ne!(
+        wide_hash([0x0141], 0..usize::MAX),
+        wide_hash([0x0041], 0..usize::MAX)
+    );
+    assert_eq!(wide_hash([0x0141], 0..usize::MAX), hash("Ł", 0..usize::MAX));
+
+    // Unpaired surrogates still hash, as their WTF-8 bytes
+    let lone =
+        HashAlgorithm::Fnv1a.wide_hash_with_seed(0x811C_9DC5, &[0xD800, 0x41], 0..usize::MAX);
+    assert_eq!(lone, fnv1a(b"\xED\xA0\x80A", 0..usize::MAX, 1));
+
+    assert_eq!(nt_hash("NtŁódź"), hash("Łódź", 0..usize::MAX));
+    assert_eq!(
+        wide_hash(obfstr::obfwide!("ZwClose"), 2..usize::MAX),
+        nt_hash!("NtClose")
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_case_insensitive() {
+    use crate::util::to_wide;
+    use HashAlgorithm::*;
+
+    const KERNEL32: u32 = hash_ci("KERNEL32.DLL", 0..usize::MAX);
+    assert_eq!(KERNEL32, crate::hash_str_ci!("kernel32.dll"));
+    assert_eq!(KERNEL32, crate::hash_str!("kernel32.dll"));
+    assert_eq!(
+        KERNEL32,
+        wide_hash_ci(obfstr::obfwide!("Kernel32.Dll"), 0..usize::MAX)
+    );
+    assert_eq!(
+        KERNEL32,
+        hash_utf16str_ci(widestring::utf16str!("kErNeL32.dLl"))
+    );
+    assert_ne!(KERNEL32, crate::hash_str!("KERNEL32.DLL"));
+
+    for algorithm in [Djb2, Sdbm, Elf, Fnv1a, Crc32, Murmur3] {
+        for (upper, lower) in [
+            ("NTDLL.DLL", "ntdll.dll"),
+            ("ÜBER@[Z]`{A}", "Über@[z]`{a}"),
+            ("ŁÓDŹ-Kraków.DLL", "ŁÓdŹ-kraków.dll"),
+            ("🦀FERRIS", "🦀ferris"),
+        ] {
+            let wide = to_wide(upper);
+
+            for range in [0..usize::MAX, 2..usize::MAX, 1..5] {
+                let hash = algorithm.hash(lower, range.clone());
+                assert_eq!(algorithm.hash_ci(upper, range.clone()), hash);
+                assert_eq!(algorithm.hash_ci(lower, range.clone()), hash);
+                assert_eq!(algorithm.wide_hash_ci(&wide, range.clone()), hash);
+            }
+        }
+
+        // Only ASCII is folded
+        assert_ne!(
+            algorithm.hash_ci("Ü", 0..usize::MAX),
+            algorithm.hash_ci("ü", 0..usize::MAX)
+        );
+    }
+
+    assert_eq!(
+        crate::hash_str_ci!("NTDLL.DLL", 0..5, algorithm = Crc32),
+        Crc32.hash("ntdll", 0..usize::MAX)
+    );
+    assert_eq!(
+        crate::hash_str_ci!("NTDLL.DLL", algorithm = HashAlgorithm::Murmur3),
+        Murmur3.wide_hash_ci(obfstr::obfwide!("ntdll.DLL"), 0..usize::MAX)
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_hash64_impl() {
+    use HashAlgorithm64::*;
+
+    let unseeded = |algorithm: HashAlgorithm64, s: &[u8]| {
+        algorithm.hash_bytes_with_seed(algorithm.seed(), s, 0..usize::MAX, 1)
+    };
+
+    // Reference: the FNV test suite and the CRC catalogue check value
+    let published_tests: [(HashAlgorithm64, &[u8], u64); 4] = [
+        (Fnv1a, b"", 0xCBF29CE484222325),
+        (Fnv1a, b"a", 0xAF63DC4C8601EC8C),
+        (Fnv1a, b"foobar", 0x85944171F73967E8),
+        (Crc64, b"123456789", 0x995DC9BBDF1939FA),
+    ];
+
+    for test in published_tests.iter() {
+        assert_eq!(unseeded(test.0, test.1), test.2);
+    }
+
+    // Regression values of this implementation, matched by a separate port of CRC-64/XZ and of
+    // SMHasher's MurmurHash3_x64_128
+    let regression_tests: [(HashAlgorithm64, u64, &[u8], u64); 8] = [
+        (Crc64, 0, b"", 0),
+        (Crc64, 0, b"a", 0x330284772E652B05),
+        (Murmur3, 0, b"", 0),
+        (Murmur3, 0, b"hello", 0xCBD8A7B341BD9B02),
+        (Murmur3, 0, b"0123456789abcdef", 0x4BE06D94CF4AD1A7),
+        (
+            Murmur3,
+            0,
+            b"The quick brown fox jumps over the lazy dog",
+            0xE34BBC7BBC071B6C,
+        ),
+        (Murmur3, 1, b"", 0x4610ABE56EFF5CB5),
+        (Murmur3, 42, b"0123456789abcdefghij", 0x3F2923EF0C34068D),
+    ];
+
+    for test in regression_tests.iter() {
+        assert_eq!(
+            test.0
+                .hash_bytes_with_seed(test.1, test.2, 0..usize::MAX, 1),
+            test.3
+        );
+    }
+
+    // djb2 and sdbm only widen the state, so their low halves are the 32-bit hashes
+    for s in ["", "a", "NtCreateThreadEx", "STATUS_INVALID_HANDLE"] {
+        for algorithm in [HashAlgorithm::Djb2, HashAlgorithm::Sdbm] {
+            let wide = algorithm.to_64().unwrap().hash_bytes_with_seed(
+                3581,
+                s.as_bytes(),
+                0..usize::MAX,
+                1,
+            );
+            assert_eq!(
+                wide as u32,
+                algorithm.hash_bytes_with_seed(3581, s.as_bytes(), 0..usize::MAX, 1)
+            );
+        }
+    }
+
+    let wide = widestring::utf16str!("NtCreateThreadEx");
+    for algorithm in [Djb2, Sdbm, Fnv1a, Crc64, Murmur3] {
+        assert_eq!(
+            algorithm.wide_hash(wide.as_slice(), 0..usize::MAX),
+            algorithm.hash("NtCreateThreadEx", 0..usize::MAX)
+        );
+        assert_eq!(
+            algorithm.hash_ci("NtCreateThreadEx", 0..usize::MAX),
+            algorithm.hash("ntcreatethreadex", 0..usize::MAX)
+        );
+        assert_eq!(
+            algorithm.wide_hash_ci(wide.as_slice(), 0..usize::MAX),
+            algorithm.hash_ci("NtCreateThreadEx", 0..usize::MAX)
+        );
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_hash64() {
+    const NAME: &str = "NtCreateThreadEx";
+    let wide = widestring::utf16str!("NtCreateThreadEx");
+
+    assert_eq!(nt_hash64!(NAME), hash64("CreateThreadEx", 0..usize::MAX));
+    assert_eq!(nt_hash64!(NAME), nt_hash64("ZwCreateThreadEx"));
+    assert_eq!(
+        wide_hash64(wide, 0..usize::MAX),
+        hash64(NAME, 0..usize::MAX)
+    );
+    assert_eq!(wide_hash64(wide, 2..usize::MAX), nt_hash64(NAME));
+
+    assert_eq!(
+        nt_hash64!(NAME, algorithm = Fnv1a),
+        HashAlgorithm64::Fnv1a.nt_hash(NAME)
+    );
+    assert_eq!(
+        nt_hash64!(NAME, algorithm = HashAlgorithm64::Murmur3),
+        HashAlgorithm64::Murmur3.nt_hash(NAME)
+    );
+    // ELF has no 64-bit variant, so hash-elf leaves the 64-bit hashes on djb2
+    assert!(HashAlgorithm::Elf.to_64().is_none());
+    assert!(
+        HashAlgorithm64::DEFAULT
+            == HashAlgorithm::DEFAULT
+                .to_64()
+                .unwrap_or(HashAlgorithm64::Djb2)
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_hash_width() {
+    const NAME: &str = "NtCreateThreadEx";
+    let wide = widestring::utf16str!("NtCreateThreadEx").as_slice();
+
+    assert_eq!(
+        hash_as::<u32>(NAME, 0..usize::MAX),
+        hash(NAME, 0..usize::MAX)
+    );
+    assert_eq!(
+        hash_as::<u64>(NAME, 0..usize::MAX),
+        hash64(NAME, 0..usize::MAX)
+    );
+    assert_eq!(nt_hash_as::<u32>(NAME), nt_hash(NAME));
+    assert_eq!(nt_hash_as::<u64>(NAME), nt_hash64(NAME));
+    assert_eq!(
+        wide_hash_as::<u32>(wide, 0..usize::MAX),
+        wide_hash(wide, 0..usize::MAX)
+    );
+    assert_eq!(
+        wide_hash_as::<u64>(wide, 0..usize::MAX),
+        wide_hash64(wide, 0..usize::MAX)
+    );
+    assert_eq!(
+        hash_bytes_as::<u32>(b"MZ", 0..usize::MAX, 1),
+        hash_bytes_impl(b"MZ", 0..usize::MAX, 1)
+    );
+    assert_eq!(
+        hash_bytes_as::<u64>(b"MZ", 0..usize::MAX, 1),
+        HashAlgorithm64::DEFAULT.hash_bytes(b"MZ", 0..usize::MAX, 1)
+    );
+
+    // A lookup written once for both widths
+    fn find<W: HashWidth>(modules: &[&str], hash: W) -> Option<usize> {
+        modules
+            .iter()
+            .position(|module| W::hash_ci(W::DEFAULT, module, 0..usize::MAX) == hash)
+    }
+
+    let modules = ["ntdll.dll", "kernel32.dll"];
+    assert_eq!(
+        find(&modules, hash_ci("KERNEL32.DLL", 0..usize::MAX)),
+        Some(1)
+    );
+    assert_eq!(
+        find(
+            &modules,
+            HashAlgorithm64::DEFAULT.hash_ci("KERNEL32.DLL", 0..usize::MAX)
+        ),
+        Some(1)
+    );
+    assert_eq!(
+        find(
+            &modules,
+            HashAlgorithm::Fnv1a.hash("ntdll.dll", 0..usize::MAX)
+        ),
+        None
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_hash64_collisions() {
+    use crate::util::{all_distinct, catalog_entries};
+
+    let names = || {
+        let exports = include_str!("../data/ntdll_exports.txt")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        catalog_entries().map(|(name, _)| name).chain(exports)
+    };
+    assert!(names().count() > 4000);
+
+    for algorithm in [
+        HashAlgorithm64::Djb2,
+        HashAlgorithm64::Sdbm,
+        HashAlgorithm64::Fnv1a,
+        HashAlgorithm64::Crc64,
+        HashAlgorithm64::Murmur3,
+    ] {
+        assert!(all_distinct(
+            names().map(|name| algorithm.hash(name, 0..usize::MAX))
+        ));
+    }
+}
@@ -3,7 +3,8 @@
     min_specialization,
     const_fn_floating_point_arithmetic,
     const_nonnull_new,
-    const_option_ext
+    const_option_ext,
+    error_in_core
 )]
 
 #[cfg(feature = "alloc")]
@@ -18,14 +19,20 @@ pub mod ntapi_ext;
 pub mod handle;
 
 pub mod ntstatus;
-pub use ntstatus::NtStatus;
+pub use ntstatus::{HResult, IntoNtResult, NtResult, NtStatus, RawNtStatus};
 
-#[cfg(windows)]
-pub mod native_duration;
+pub mod win32_error;
+pub use win32_error::Win32Error;
 
-#[cfg(windows)]
+pub mod native_duration;
 pub use native_duration::NativeDuration;
 
+pub mod nt_time;
+pub use nt_time::{NtTime, NtTimeout};
+
+pub mod sleep_schedule;
+pub use sleep_schedule::{SleepRng, SleepSchedule};
+
 pub mod types {
     #[cfg(windows)]
     pub use ntapi::*;
@@ -1,19 +1,18 @@
-#![cfg(windows)]
-
 use core::time::Duration;
 
+#[cfg(windows)]
 use winapi::um::winnt::LARGE_INTEGER;
 
-#[repr(transparent)]
-#[derive(Clone, Copy)]
-pub struct NativeDuration(LARGE_INTEGER);
+/// A relative NT interval, laid out like a negative `LARGE_INTEGER` of 100ns ticks.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NativeDuration(i64);
 
-impl PartialEq for NativeDuration {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        unsafe { self.0.QuadPart() == other.0.QuadPart() }
-    }
-}
+#[cfg(windows)]
+const _: () = assert!(
+    core::mem::size_of::<NativeDuration>() == core::mem::size_of::<LARGE_INTEGER>()
+        && core::mem::align_of::<NativeDuration>() == core::mem::align_of::<LARGE_INTEGER>()
+);
 
 #[cfg(not(feature = "nosym"))]
 impl core::fmt::Debug for NativeDuration {
@@ -33,40 +32,288 @@ impl core::fmt::Display for NativeDuration {
 impl PartialOrd for NativeDuration {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        let duration: Duration = (*self).into();
-        let other_duration: Duration = (*other).into();
-        duration.partial_cmp(&other_duration)
+        Some(self.cmp(other))
     }
 }
 
+/// Orders by length, the reverse of the negative values stored.
+impl Ord for NativeDuration {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_ticks().cmp(&other.as_ticks())
+    }
+}
+
+pub(crate) const NANOS_PER_TICK: u32 = 100;
+pub(crate) const TICKS_PER_SEC: u64 = 10_000_000;
+
 impl NativeDuration {
+    /// The longest relative interval a `LARGE_INTEGER` holds, `i64::MAX` ticks or about 29,227 years.
+    pub const MAX_TICKS: u64 = i64::MAX as u64;
+
+    pub const ZERO: Self = Self::from_raw(0);
+
+    /// The longest finite interval, [`MAX_TICKS`](Self::MAX_TICKS) long.
+    pub const MAX: Self = Self::from_raw(-i64::MAX);
+
+    /// The `i64::MIN` interval NT waits on forever, ordered after [`MAX`](Self::MAX).
+    pub const INFINITE: Self = Self::from_raw(i64::MIN);
+
+    #[inline(always)]
+    const fn from_raw(raw: i64) -> Self {
+        Self(raw)
+    }
+
+    #[inline(always)]
+    const fn raw(&self) -> i64 {
+        self.0
+    }
+
+    /// A relative interval of `ticks` 100ns units, saturating at [`MAX_TICKS`](Self::MAX_TICKS).
+    #[inline]
+    pub const fn from_ticks(ticks: u64) -> Self {
+        match Self::checked_from_ticks(ticks) {
+            Some(duration) => duration,
+            None => Self::MAX,
+        }
+    }
+
+    /// A relative interval of `ticks` 100ns units, or `None` above [`MAX_TICKS`](Self::MAX_TICKS).
+    #[inline]
+    pub const fn checked_from_ticks(ticks: u64) -> Option<Self> {
+        match ticks <= Self::MAX_TICKS {
+            true => Some(Self::from_raw(-(ticks as i64))),
+            false => None,
+        }
+    }
+
+    #[inline]
+    pub const fn from_millis(millis: u64) -> Self {
+        Self::from_duration(Duration::from_millis(millis))
+    }
+
+    #[inline]
+    pub const fn from_secs(secs: u64) -> Self {
+        Self::from_duration(Duration::from_secs(secs))
+    }
+
+    /// Same as the `From<Duration>` conversion, saturating at [`MAX_TICKS`](Self::MAX_TICKS).
+    #[inline]
+    pub const fn from_duration(duration: Duration) -> Self {
+        Self::saturating_from_duration(duration)
+    }
+
+    /// The length of the interval in 100ns ticks.
+    #[inline]
+    pub const fn as_ticks(&self) -> u64 {
+        self.raw().unsigned_abs()
+    }
+
+    /// Converts exactly, only dropping the nanoseconds below one tick.
+    ///
+    /// `None` for durations longer than [`MAX_TICKS`](Self::MAX_TICKS).
+    #[inline]
+    pub const fn checked_from_duration(duration: Duration) -> Option<Self> {
+        let ticks = duration.as_secs() as u128 * TICKS_PER_SEC as u128
+            + (duration.subsec_nanos() / NANOS_PER_TICK) as u128;
+
+        match ticks <= Self::MAX_TICKS as u128 {
+            true => Some(Self::from_raw(-(ticks as i64))),
+            false => None,
+        }
+    }
+
+    /// Like [`checked_from_duration`](Self::checked_from_duration), saturating at [`MAX_TICKS`](Self::MAX_TICKS).
+    #[inline]
+    pub const fn saturating_from_duration(duration: Duration) -> Self {
+        match Self::checked_from_duration(duration) {
+            Some(native) => native,
+            None => Self::MAX,
+        }
+    }
+
+    /// The interval as a [`Duration`], exact to the tick.
+    #[inline]
+    pub const fn as_duration(&self) -> Duration {
+        let ticks = self.as_ticks();
+        Duration::new(
+            ticks / TICKS_PER_SEC,
+            (ticks % TICKS_PER_SEC) as u32 * NANOS_PER_TICK,
+        )
+    }
+
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.raw() == i64::MIN
+    }
+
+    /// `None` past [`MAX`](Self::MAX), [`INFINITE`](Self::INFINITE) if either side is.
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.is_infinite() || rhs.is_infinite() {
+            return Some(Self::INFINITE);
+        }
+
+        Self::checked_from_ticks(self.as_ticks() + rhs.as_ticks())
+    }
+
+    /// `None` below [`ZERO`](Self::ZERO) or when subtracting [`INFINITE`](Self::INFINITE).
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match (self.is_infinite(), rhs.is_infinite()) {
+            (_, true) => None,
+            (true, false) => Some(Self::INFINITE),
+            (false, false) => match self.as_ticks().checked_sub(rhs.as_ticks()) {
+                Some(ticks) => Some(Self::from_raw(-(ticks as i64))),
+                None => None,
+            },
+        }
+    }
+
+    /// `None` past [`MAX`](Self::MAX), [`INFINITE`](Self::INFINITE) stays infinite unless `rhs` is 0.
+    #[inline]
+    pub const fn checked_mul(self, rhs: u32) -> Option<Self> {
+        if rhs == 0 {
+            return Some(Self::ZERO);
+        }
+
+        if self.is_infinite() {
+            return Some(Self::INFINITE);
+        }
+
+        match self.as_ticks().checked_mul(rhs as u64) {
+            Some(ticks) => Self::checked_from_ticks(ticks),
+            None => None,
+        }
+    }
+
+    #[inline]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => Self::MAX,
+        }
+    }
+
+    #[inline]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            None => Self::ZERO,
+        }
+    }
+
+    #[inline]
+    pub const fn saturating_mul(self, rhs: u32) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => Self::MAX,
+        }
+    }
+
+    #[cfg(windows)]
     #[inline]
     pub fn as_ptr(&self) -> *const LARGE_INTEGER {
-        &self.0 as _
+        (self as *const Self).cast()
     }
 }
 
+#[cfg(windows)]
 impl AsRef<LARGE_INTEGER> for NativeDuration {
     #[inline]
     fn as_ref(&self) -> &LARGE_INTEGER {
-        &self.0
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl core::ops::Add for NativeDuration {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .expect("overflow when adding native durations")
+    }
+}
+
+impl core::ops::AddAssign for NativeDuration {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl core::ops::Sub for NativeDuration {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting native durations")
+    }
+}
+
+impl core::ops::SubAssign for NativeDuration {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl core::ops::Mul<u32> for NativeDuration {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Self {
+        self.checked_mul(rhs)
+            .expect("overflow when multiplying native duration by scalar")
+    }
+}
+
+impl core::ops::MulAssign<u32> for NativeDuration {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+/// Serialized as [`as_ticks`](NativeDuration::as_ticks), the unsigned count of 100ns ticks.
+#[cfg(feature = "serde")]
+impl serde::Serialize for NativeDuration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.as_ticks())
+    }
+}
+
+/// Always yields a relative interval, rejecting tick counts above
+/// [`MAX_TICKS`](NativeDuration::MAX_TICKS) other than the one [`INFINITE`](NativeDuration::INFINITE) has.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NativeDuration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ticks = u64::deserialize(deserializer)?;
+        match Self::checked_from_ticks(ticks) {
+            Some(duration) => Ok(duration),
+            None if ticks == Self::INFINITE.as_ticks() => Ok(Self::INFINITE),
+            None => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(ticks),
+                &"at most NativeDuration::MAX_TICKS ticks",
+            )),
+        }
     }
 }
 
 impl From<NativeDuration> for Duration {
+    #[inline]
     fn from(val: NativeDuration) -> Self {
-        let large_int = unsafe { val.0.QuadPart() };
-        let millis: u128 = (-large_int / 10000) as _;
-        Duration::from_millis(millis as _)
+        val.as_duration()
     }
 }
 
+/// Saturates at [`NativeDuration::MAX_TICKS`], see [`NativeDuration::checked_from_duration`].
 impl From<Duration> for NativeDuration {
     #[inline]
     fn from(x: Duration) -> Self {
-        let mut interval = LARGE_INTEGER::default();
-        unsafe { *interval.QuadPart_mut() = -(x.as_millis() as i64) * 10000 };
-        Self(interval)
+        Self::from_duration(x)
     }
 }
 
@@ -76,6 +323,20 @@ mod tests {
 
     use crate::NativeDuration;
 
+    use super::TICKS_PER_SEC;
+
+    /// A fixed-seed xorshift, so the property tests are reproducible without extra dependencies.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+    }
+
     #[test]
     fn test_duration_conversions() {
         let ms300 = Duration::from_millis(300);
@@ -85,8 +346,265 @@ mod tests {
             ms300
         );
 
-        let native_100ms: NativeDuration = unsafe { core::mem::transmute([-(100 as i64) * 10000]) };
+        let native_100ms: NativeDuration = unsafe { core::mem::transmute([-100i64 * 10000]) };
         let ms100 = NativeDuration::from(Duration::from_millis(100));
         assert!(native_100ms == ms100);
     }
+
+    #[test]
+    fn test_duration_const_constructors() {
+        const SECOND: NativeDuration = NativeDuration::from_secs(1);
+        const MILLIS: NativeDuration = NativeDuration::from_millis(1500);
+        const LONGEST: NativeDuration = NativeDuration::from_duration(Duration::MAX);
+
+        assert_eq!(SECOND.as_ticks(), TICKS_PER_SEC);
+        assert_eq!(MILLIS.as_ticks(), TICKS_PER_SEC * 3 / 2);
+        assert_eq!(LONGEST.as_ticks(), NativeDuration::MAX_TICKS);
+        assert!(NativeDuration::from_secs(u64::MAX) == LONGEST);
+    }
+
+    #[test]
+    fn test_duration_layout() {
+        assert_eq!(core::mem::size_of::<NativeDuration>(), 8);
+        assert_eq!(core::mem::align_of::<NativeDuration>(), 8);
+
+        let raw: i64 = unsafe { core::mem::transmute(NativeDuration::from_millis(1)) };
+        assert_eq!(raw, -10_000);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_duration_large_integer_view() {
+        let duration = NativeDuration::from_millis(1);
+        assert_eq!(unsafe { *duration.as_ref().QuadPart() }, -10_000);
+        assert_eq!(duration.as_ptr(), duration.as_ref() as *const _);
+    }
+
+    #[test]
+    fn test_duration_ticks_round_trip() {
+        let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+        for _ in 0..10_000 {
+            let ticks = rng.next() >> 1;
+            let native = NativeDuration::from_ticks(ticks);
+            assert_eq!(native.as_ticks(), ticks);
+
+            let duration = Duration::from(native);
+            assert_eq!(duration.as_nanos(), ticks as u128 * 100);
+            assert!(NativeDuration::from(duration) == native);
+        }
+    }
+
+    #[test]
+    fn test_duration_truncates_below_one_tick() {
+        let mut rng = XorShift(0xD1B5_4A32_D192_ED03);
+        for _ in 0..10_000 {
+            let secs = rng.next() % (NativeDuration::MAX_TICKS / TICKS_PER_SEC);
+            let nanos = (rng.next() % 1_000_000_000) as u32;
+
+            let native = NativeDuration::from(Duration::new(secs, nanos));
+            assert_eq!(
+                Duration::from(native),
+                Duration::new(secs, nanos - nanos % 100)
+            );
+        }
+
+        for (nanos, ticks) in [(0, 0), (99, 0), (100, 1), (150, 1), (1_000_000, 10_000)] {
+            assert_eq!(
+                NativeDuration::from(Duration::from_nanos(nanos)).as_ticks(),
+                ticks
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_saturation() {
+        let max = NativeDuration::MAX_TICKS;
+        let longest = Duration::new(max / TICKS_PER_SEC, (max % TICKS_PER_SEC) as u32 * 100);
+
+        let native = NativeDuration::checked_from_duration(longest).unwrap();
+        assert_eq!(native.as_ticks(), max);
+        assert_eq!(Duration::from(native), longest);
+
+        let too_long = longest + Duration::from_nanos(100);
+        assert!(NativeDuration::checked_from_duration(too_long).is_none());
+        assert!(NativeDuration::checked_from_duration(Duration::MAX).is_none());
+        assert_eq!(NativeDuration::from(too_long).as_ticks(), max);
+        assert_eq!(NativeDuration::from(Duration::MAX).as_ticks(), max);
+
+        let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+        for _ in 0..10_000 {
+            let ticks = rng.next() | 1 << 63;
+            assert!(NativeDuration::checked_from_ticks(ticks).is_none());
+            assert_eq!(NativeDuration::from_ticks(ticks).as_ticks(), max);
+        }
+    }
+
+    #[test]
+    fn test_duration_order_matches_ticks() {
+        let mut rng = XorShift(0x1234_5678_9ABC_DEF1);
+        for _ in 0..10_000 {
+            let (a, b) = (rng.next() >> 1, rng.next() >> (rng.next() % 64));
+            let (native_a, native_b) = (
+                NativeDuration::from_ticks(a),
+                NativeDuration::from_ticks(b.min(NativeDuration::MAX_TICKS)),
+            );
+
+            assert_eq!(
+                native_a.partial_cmp(&native_b),
+                a.partial_cmp(&b.min(NativeDuration::MAX_TICKS))
+            );
+            assert_eq!(
+                native_a.partial_cmp(&native_b),
+                Duration::from(native_a).partial_cmp(&Duration::from(native_b))
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_arithmetic_matches_ticks() {
+        let mut rng = XorShift(0x6A09_E667_F3BC_C908);
+        for _ in 0..10_000 {
+            let (a, b) = (rng.next() >> 1, rng.next() >> (1 + rng.next() % 63));
+            let factor = (rng.next() >> (rng.next() % 64)) as u32;
+            let (native_a, native_b) =
+                (NativeDuration::from_ticks(a), NativeDuration::from_ticks(b));
+
+            let sum = (a + b <= NativeDuration::MAX_TICKS).then_some(a + b);
+            assert_eq!(native_a.checked_add(native_b).map(|d| d.as_ticks()), sum);
+            assert_eq!(
+                native_a.saturating_add(native_b).as_ticks(),
+                sum.unwrap_or(NativeDuration::MAX_TICKS)
+            );
+
+            assert_eq!(
+                native_a.checked_sub(native_b).map(|d| d.as_ticks()),
+                a.checked_sub(b)
+            );
+            assert_eq!(
+                native_a.saturating_sub(native_b).as_ticks(),
+                a.saturating_sub(b)
+            );
+
+            let product = b
+                .checked_mul(factor as u64)
+                .filter(|&ticks| ticks <= NativeDuration::MAX_TICKS);
+            assert_eq!(native_b.checked_mul(factor).map(|d| d.as_ticks()), product);
+            assert_eq!(
+                native_b.saturating_mul(factor).as_ticks(),
+                product.unwrap_or(NativeDuration::MAX_TICKS)
+            );
+
+            assert_eq!(native_a == native_b, a == b);
+            assert_eq!(native_a.cmp(&native_b), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    fn test_duration_operators() {
+        let mut duration = NativeDuration::from_millis(10);
+        duration += NativeDuration::from_millis(5);
+        duration *= 4;
+        duration -= NativeDuration::from_millis(20);
+        assert!(duration == NativeDuration::from_millis(40));
+        assert!(
+            NativeDuration::from_secs(1) - NativeDuration::from_millis(1)
+                == NativeDuration::from_millis(999)
+        );
+        assert!(NativeDuration::from_millis(250) * 4 == NativeDuration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duration_sub_overflow() {
+        let _ = NativeDuration::ZERO - NativeDuration::from_ticks(1);
+    }
+
+    #[test]
+    fn test_duration_constants() {
+        assert_eq!(NativeDuration::ZERO.as_ticks(), 0);
+        assert_eq!(NativeDuration::MAX.as_ticks(), NativeDuration::MAX_TICKS);
+        assert!(!NativeDuration::MAX.is_infinite());
+        assert!(NativeDuration::INFINITE.is_infinite());
+        assert!(NativeDuration::INFINITE > NativeDuration::MAX);
+        assert!(NativeDuration::INFINITE != NativeDuration::MAX);
+
+        let second = NativeDuration::from_secs(1);
+        assert!(second
+            .checked_add(NativeDuration::INFINITE)
+            .unwrap()
+            .is_infinite());
+        assert!(NativeDuration::INFINITE
+            .checked_sub(second)
+            .unwrap()
+            .is_infinite());
+        assert!(second.checked_sub(NativeDuration::INFINITE).is_none());
+        assert!(NativeDuration::INFINITE
+            .checked_mul(3)
+            .unwrap()
+            .is_infinite());
+        assert!(NativeDuration::INFINITE.checked_mul(0) == Some(NativeDuration::ZERO));
+        assert!(NativeDuration::MAX.checked_add(second).is_none());
+        assert!(NativeDuration::MAX.saturating_mul(2) == NativeDuration::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_duration_hash_matches_eq() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |duration: NativeDuration| {
+            let mut hasher = DefaultHasher::new();
+            duration.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let millisecond = NativeDuration::from_millis(1);
+        for same in [
+            NativeDuration::from_ticks(10_000),
+            NativeDuration::from(Duration::from_micros(1_000)),
+            NativeDuration::from_ticks(5_000) + NativeDuration::from_ticks(5_000),
+        ] {
+            assert!(same == millisecond);
+            assert_eq!(hash(same), hash(millisecond));
+        }
+        assert_ne!(hash(NativeDuration::MAX), hash(NativeDuration::INFINITE));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_duration_serde() {
+        use serde::{
+            de::value::{Error, I64Deserializer, U64Deserializer},
+            Deserialize,
+        };
+        use serde_test::{assert_ser_tokens, Token};
+
+        let duration = NativeDuration::from(Duration::from_millis(100));
+        let bytes = bincode::serialize(&duration).unwrap();
+        assert_eq!(bytes, 1_000_000u64.to_le_bytes());
+        assert!(bincode::deserialize::<NativeDuration>(&bytes).unwrap() == duration);
+
+        assert_ser_tokens(&duration, &[Token::U64(1_000_000)]);
+        assert_ser_tokens(
+            &NativeDuration::MAX,
+            &[Token::U64(NativeDuration::MAX_TICKS)],
+        );
+        assert_ser_tokens(&NativeDuration::INFINITE, &[Token::U64(1 << 63)]);
+
+        let from_ticks = |ticks| NativeDuration::deserialize(U64Deserializer::<Error>::new(ticks));
+        assert!(from_ticks(0) == Ok(NativeDuration::ZERO));
+        assert!(from_ticks(NativeDuration::MAX_TICKS) == Ok(NativeDuration::MAX));
+        assert!(from_ticks(1 << 63).unwrap().is_infinite());
+        assert!(from_ticks((1 << 63) + 1).is_err());
+        assert!(from_ticks(u64::MAX).is_err());
+
+        // Always decodes to a relative interval, passed to NT as a negative LARGE_INTEGER
+        let decoded = from_ticks(1_000_000).unwrap();
+        assert_eq!(
+            unsafe { core::mem::transmute::<_, i64>(decoded) },
+            -1_000_000
+        );
+        assert!(NativeDuration::deserialize(I64Deserializer::<Error>::new(-1)).is_err());
+    }
 }
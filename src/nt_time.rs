@@ -0,0 +1,265 @@
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+use core::time::Duration;
+
+#[cfg(windows)]
+use winapi::um::winnt::LARGE_INTEGER;
+
+use crate::native_duration::TICKS_PER_SEC;
+use crate::NativeDuration;
+
+/// 1970-01-01 in 100ns ticks since 1601-01-01.
+const UNIX_EPOCH_TICKS: i64 = 116_444_736_000_000_000;
+
+/// An absolute point in time, in 100ns ticks since 1601-01-01 UTC, like a `FILETIME`.
+///
+/// Passed to the NT wait APIs as a positive `LARGE_INTEGER`, where [`NativeDuration`] is negative.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NtTime(i64);
+
+impl NtTime {
+    pub const UNIX_EPOCH: NtTime = NtTime::from_raw(UNIX_EPOCH_TICKS);
+
+    #[inline(always)]
+    const fn from_raw(raw: i64) -> Self {
+        Self(raw)
+    }
+
+    #[inline(always)]
+    const fn raw(&self) -> i64 {
+        self.0
+    }
+
+    /// `ticks` 100ns units after 1601-01-01, or `None` above `i64::MAX` ticks.
+    #[inline]
+    pub const fn from_ticks(ticks: u64) -> Option<Self> {
+        match ticks <= i64::MAX as u64 {
+            true => Some(Self::from_raw(ticks as i64)),
+            false => None,
+        }
+    }
+
+    /// The number of 100ns ticks since 1601-01-01.
+    #[inline]
+    pub const fn as_ticks(&self) -> u64 {
+        self.raw() as u64
+    }
+
+    /// `secs` seconds after the UNIX epoch, negative for earlier times.
+    ///
+    /// `None` before 1601-01-01 or past `i64::MAX` ticks.
+    #[inline]
+    pub const fn from_unix_secs(secs: i64) -> Option<Self> {
+        let ticks = match secs.checked_mul(TICKS_PER_SEC as i64) {
+            Some(ticks) => ticks.checked_add(UNIX_EPOCH_TICKS),
+            None => None,
+        };
+
+        match ticks {
+            Some(ticks) if ticks >= 0 => Some(Self::from_raw(ticks)),
+            _ => None,
+        }
+    }
+
+    /// Whole seconds since the UNIX epoch, rounded towards 1601.
+    #[inline]
+    pub const fn as_unix_secs(&self) -> i64 {
+        (self.raw() - UNIX_EPOCH_TICKS).div_euclid(TICKS_PER_SEC as i64)
+    }
+
+    #[cfg(windows)]
+    #[inline]
+    pub fn as_ptr(&self) -> *const LARGE_INTEGER {
+        (self as *const Self).cast()
+    }
+}
+
+#[cfg(not(feature = "nosym"))]
+impl core::fmt::Debug for NtTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NtTime").field(&self.as_ticks()).finish()
+    }
+}
+
+#[cfg(windows)]
+impl AsRef<LARGE_INTEGER> for NtTime {
+    #[inline]
+    fn as_ref(&self) -> &LARGE_INTEGER {
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct NtTimeOutOfRange;
+
+/// Rounds towards 1601 to whole ticks, failing before 1601-01-01 or past `i64::MAX` ticks.
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for NtTime {
+    type Error = NtTimeOutOfRange;
+
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let ticks = match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(after) => NativeDuration::checked_from_duration(after)
+                .and_then(|after| UNIX_EPOCH_TICKS.checked_add(after.as_ticks() as i64)),
+            Err(before) => {
+                let before = before.duration();
+                let partial =
+                    (before.subsec_nanos() % crate::native_duration::NANOS_PER_TICK != 0) as i64;
+                NativeDuration::checked_from_duration(before)
+                    .and_then(|before| UNIX_EPOCH_TICKS.checked_sub(before.as_ticks() as i64))
+                    .and_then(|ticks| ticks.checked_sub(partial))
+            }
+        };
+
+        match ticks {
+            Some(ticks) if ticks >= 0 => Ok(Self::from_raw(ticks)),
+            _ => Err(NtTimeOutOfRange),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<NtTime> for SystemTime {
+    fn from(time: NtTime) -> Self {
+        let offset = time.raw() - UNIX_EPOCH_TICKS;
+        let since_epoch = NativeDuration::from_ticks(offset.unsigned_abs()).as_duration();
+
+        match offset >= 0 {
+            true => SystemTime::UNIX_EPOCH + since_epoch,
+            false => SystemTime::UNIX_EPOCH - since_epoch,
+        }
+    }
+}
+
+/// The timeout argument of the NT wait and delay APIs.
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Clone, Copy, PartialEq)]
+pub enum NtTimeout {
+    /// An interval from now, passed as a negative `LARGE_INTEGER`.
+    Relative(NativeDuration),
+    /// A point in time, passed as a positive `LARGE_INTEGER`.
+    Absolute(NtTime),
+    /// Waits forever, passed as a null pointer.
+    Infinite,
+    /// Polls without waiting, passed as a zero interval.
+    Immediate,
+}
+
+#[cfg(windows)]
+static IMMEDIATE: NativeDuration = NativeDuration::from_ticks(0);
+
+#[cfg(windows)]
+impl NtTimeout {
+    /// The `PLARGE_INTEGER` to pass, valid for as long as `self` is borrowed.
+    #[inline]
+    pub fn as_ptr(&self) -> *const LARGE_INTEGER {
+        match self {
+            Self::Relative(duration) => duration.as_ptr(),
+            Self::Absolute(time) => time.as_ptr(),
+            Self::Infinite => core::ptr::null(),
+            Self::Immediate => IMMEDIATE.as_ptr(),
+        }
+    }
+}
+
+impl From<NativeDuration> for NtTimeout {
+    #[inline]
+    fn from(duration: NativeDuration) -> Self {
+        Self::Relative(duration)
+    }
+}
+
+impl From<Duration> for NtTimeout {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Self::Relative(duration.into())
+    }
+}
+
+impl From<NtTime> for NtTimeout {
+    #[inline]
+    fn from(time: NtTime) -> Self {
+        Self::Absolute(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::{NativeDuration, NtTime, NtTimeout};
+
+    #[test]
+    fn test_nt_time_unix_secs() {
+        assert_eq!(NtTime::UNIX_EPOCH.as_unix_secs(), 0);
+        assert!(NtTime::from_unix_secs(0) == Some(NtTime::UNIX_EPOCH));
+
+        // 2000-01-01T00:00:00Z
+        let time = NtTime::from_unix_secs(946_684_800).unwrap();
+        assert_eq!(time.as_ticks(), 125_911_584_000_000_000);
+        assert_eq!(time.as_unix_secs(), 946_684_800);
+
+        let first = NtTime::from_unix_secs(-11_644_473_600).unwrap();
+        assert_eq!(first.as_ticks(), 0);
+        assert!(NtTime::from_unix_secs(-11_644_473_601).is_none());
+        assert!(NtTime::from_unix_secs(i64::MAX).is_none());
+
+        // partial seconds round towards 1601
+        let time = NtTime::from_ticks(NtTime::UNIX_EPOCH.as_ticks() - 1).unwrap();
+        assert_eq!(time.as_unix_secs(), -1);
+        assert!(NtTime::from_ticks(u64::MAX).is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(feature = "nosym")))]
+    fn test_nt_time_system_time() {
+        use std::time::SystemTime;
+
+        use crate::nt_time::NtTimeOutOfRange;
+
+        let now = SystemTime::now();
+        let time = NtTime::try_from(now).unwrap();
+        let back = SystemTime::from(time);
+        assert!(back <= now && now.duration_since(back).unwrap() < Duration::from_nanos(100));
+
+        let epoch = NtTime::try_from(SystemTime::UNIX_EPOCH).unwrap();
+        assert!(epoch == NtTime::UNIX_EPOCH);
+
+        let before = SystemTime::UNIX_EPOCH - Duration::from_nanos(150);
+        let time = NtTime::try_from(before).unwrap();
+        assert_eq!(time.as_ticks(), NtTime::UNIX_EPOCH.as_ticks() - 2);
+        assert!(SystemTime::from(time) == SystemTime::UNIX_EPOCH - Duration::from_nanos(200));
+
+        let first = SystemTime::from(NtTime::from_ticks(0).unwrap());
+        assert!(NtTime::try_from(first).unwrap().as_ticks() == 0);
+        assert!(NtTime::try_from(first - Duration::from_nanos(100)) == Err(NtTimeOutOfRange));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_nt_timeout_pointers() {
+        assert!(NtTimeout::Infinite.as_ptr().is_null());
+
+        let read = |timeout: &NtTimeout| unsafe { *timeout.as_ptr().cast::<i64>() };
+        assert_eq!(read(&NtTimeout::Immediate), 0);
+        assert_eq!(read(&NtTimeout::from(Duration::from_millis(5))), -50_000);
+        assert_eq!(
+            read(&NtTimeout::from(NtTime::UNIX_EPOCH)),
+            116_444_736_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_nt_timeout_from() {
+        let duration = NativeDuration::from_ticks(42);
+        assert!(NtTimeout::from(duration) == NtTimeout::Relative(duration));
+        assert!(
+            NtTimeout::from(Duration::from_millis(5))
+                == NtTimeout::Relative(NativeDuration::from_millis(5))
+        );
+        assert!(NtTimeout::from(NtTime::UNIX_EPOCH) == NtTimeout::Absolute(NtTime::UNIX_EPOCH));
+    }
+}
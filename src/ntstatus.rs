@@ -1,2410 +1,92 @@
-use num_enum::{IntoPrimitive, TryFromPrimitive};
+use num_enum::{IntoPrimitive, TryFromPrimitive, TryFromPrimitiveError};
+
+use crate::win32_error::{ntstatus_to_win32, Win32Error};
 
 pub type NTSTATUS = i32;
+pub type HRESULT = i32;
 
-// Todo: Determine if important statuses are missing
-#[repr(i32)]
-#[allow(non_camel_case_types)]
-#[allow(non_upper_case_globals)]
-#[cfg_attr(not(feature = "nosym"), derive(Debug))]
-#[cfg_attr(
-    not(feature = "unsafe_conversions"),
-    derive(IntoPrimitive, TryFromPrimitive)
-)]
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
-pub enum NtStatus {
-    STATUS_GUARD_PAGE_VIOLATION = 0x80000001u32 as _,
-    STATUS_DATATYPE_MISALIGNMENT = 0x80000002u32 as _,
-    STATUS_BREAKPOINT = 0x80000003u32 as _,
-    STATUS_SINGLE_STEP = 0x80000004u32 as _,
-    STATUS_BUFFER_OVERFLOW = 0x80000005u32 as _,
-    STATUS_NO_MORE_FILES = 0x80000006u32 as _,
-    STATUS_WAKE_SYSTEM_DEBUGGER = 0x80000007u32 as _,
-    STATUS_HANDLES_CLOSED = 0x8000000Au32 as _,
-    STATUS_NO_INHERITANCE = 0x8000000Bu32 as _,
-    STATUS_GUID_SUBSTITUTION_MADE = 0x8000000Cu32 as _,
-    STATUS_PARTIAL_COPY = 0x8000000Du32 as _,
-    STATUS_DEVICE_PAPER_EMPTY = 0x8000000Eu32 as _,
-    STATUS_DEVICE_POWERED_OFF = 0x8000000Fu32 as _,
-    STATUS_DEVICE_OFF_LINE = 0x80000010u32 as _,
-    STATUS_DEVICE_BUSY = 0x80000011u32 as _,
-    STATUS_NO_MORE_EAS = 0x80000012u32 as _,
-    STATUS_INVALID_EA_NAME = 0x80000013u32 as _,
-    STATUS_EA_LIST_INCONSISTENT = 0x80000014u32 as _,
-    STATUS_INVALID_EA_FLAG = 0x80000015u32 as _,
-    STATUS_VERIFY_REQUIRED = 0x80000016u32 as _,
-    STATUS_EXTRANEOUS_INFORMATION = 0x80000017u32 as _,
-    STATUS_RXACT_COMMIT_NECESSARY = 0x80000018u32 as _,
-    STATUS_NO_MORE_ENTRIES = 0x8000001Au32 as _,
-
-    STATUS_SUCCESS = 0x00000000u32 as _,
-    STATUS_WAIT_1 = 0x00000001u32 as _,
-    STATUS_WAIT_2 = 0x00000002u32 as _,
-    STATUS_WAIT_3 = 0x00000003u32 as _,
-    STATUS_WAIT_63 = 0x0000003Fu32 as _,
-    STATUS_ABANDONED = 0x00000080u32 as _,
-    // STATUS_ABANDONED_WAIT_0 = 0x00000080u32 as _,
-    STATUS_ABANDONED_WAIT_63 = 0x000000BFu32 as _,
-    STATUS_USER_APC = 0x000000C0u32 as _,
-    STATUS_ALREADY_COMPLETE = 0x000000FFu32 as _,
-    STATUS_KERNEL_APC = 0x00000100u32 as _,
-    STATUS_ALERTED = 0x00000101u32 as _,
-    STATUS_TIMEOUT = 0x00000102u32 as _,
-    STATUS_PENDING = 0x00000103u32 as _,
-    STATUS_REPARSE = 0x00000104u32 as _,
-    STATUS_MORE_ENTRIES = 0x00000105u32 as _,
-    STATUS_NOT_ALL_ASSIGNED = 0x00000106u32 as _,
-    STATUS_SOME_NOT_MAPPED = 0x00000107u32 as _,
-    STATUS_OPLOCK_BREAK_IN_PROGRESS = 0x00000108u32 as _,
-    STATUS_VOLUME_MOUNTED = 0x00000109u32 as _,
-    STATUS_RXACT_COMMITTED = 0x0000010Au32 as _,
-    STATUS_NOTIFY_CLEANUP = 0x0000010Bu32 as _,
-    STATUS_NOTIFY_ENUM_DIR = 0x0000010Cu32 as _,
-    STATUS_NO_QUOTAS_FOR_ACCOUNT = 0x0000010Du32 as _,
-    STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED = 0x0000010Eu32 as _,
-    STATUS_PAGE_FAULT_TRANSITION = 0x00000110u32 as _,
-    STATUS_PAGE_FAULT_DEMAND_ZERO = 0x00000111u32 as _,
-    STATUS_PAGE_FAULT_COPY_ON_WRITE = 0x00000112u32 as _,
-    STATUS_PAGE_FAULT_GUARD_PAGE = 0x00000113u32 as _,
-    STATUS_PAGE_FAULT_PAGING_FILE = 0x00000114u32 as _,
-    STATUS_CACHE_PAGE_LOCKED = 0x00000115u32 as _,
-    STATUS_CRASH_DUMP = 0x00000116u32 as _,
-    STATUS_BUFFER_ALL_ZEROS = 0x00000117u32 as _,
-    STATUS_REPARSE_OBJECT = 0x00000118u32 as _,
-    STATUS_RESOURCE_REQUIREMENTS_CHANGED = 0x00000119u32 as _,
-    STATUS_TRANSLATION_COMPLETE = 0x00000120u32 as _,
-    STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY = 0x00000121u32 as _,
-    STATUS_NOTHING_TO_TERMINATE = 0x00000122u32 as _,
-    STATUS_PROCESS_NOT_IN_JOB = 0x00000123u32 as _,
-    STATUS_PROCESS_IN_JOB = 0x00000124u32 as _,
-    STATUS_VOLSNAP_HIBERNATE_READY = 0x00000125u32 as _,
-    STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY = 0x00000126u32 as _,
-    STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED = 0x00000127u32 as _,
-    STATUS_INTERRUPT_STILL_CONNECTED = 0x00000128u32 as _,
-    STATUS_PROCESS_CLONED = 0x00000129u32 as _,
-    STATUS_FILE_LOCKED_WITH_ONLY_READERS = 0x0000012Au32 as _,
-    STATUS_FILE_LOCKED_WITH_WRITERS = 0x0000012Bu32 as _,
-    STATUS_VALID_IMAGE_HASH = 0x0000012Cu32 as _,
-    STATUS_VALID_CATALOG_HASH = 0x0000012Du32 as _,
-    STATUS_VALID_STRONG_CODE_HASH = 0x0000012Eu32 as _,
-    STATUS_GHOSTED = 0x0000012Fu32 as _,
-    STATUS_RESOURCEMANAGER_READ_ONLY = 0x00000202u32 as _,
-    STATUS_RING_PREVIOUSLY_EMPTY = 0x00000210u32 as _,
-    STATUS_RING_PREVIOUSLY_FULL = 0x00000211u32 as _,
-    STATUS_RING_PREVIOUSLY_ABOVE_QUOTA = 0x00000212u32 as _,
-    STATUS_RING_NEWLY_EMPTY = 0x00000213u32 as _,
-    STATUS_RING_SIGNAL_OPPOSITE_ENDPOINT = 0x00000214u32 as _,
-    STATUS_OPLOCK_SWITCHED_TO_NEW_HANDLE = 0x00000215u32 as _,
-    STATUS_OPLOCK_HANDLE_CLOSED = 0x00000216u32 as _,
-    STATUS_WAIT_FOR_OPLOCK = 0x00000367u32 as _,
-    STATUS_REPARSE_GLOBAL = 0x00000368u32 as _,
-    STATUS_UNSUCCESSFUL = 0xC0000001u32 as _,
-    STATUS_NOT_IMPLEMENTED = 0xC0000002u32 as _,
-    STATUS_INVALID_INFO_CLASS = 0xC0000003u32 as _,
-    STATUS_INFO_LENGTH_MISMATCH = 0xC0000004u32 as _,
-    STATUS_ACCESS_VIOLATION = 0xC0000005u32 as _,
-    STATUS_IN_PAGE_ERROR = 0xC0000006u32 as _,
-    STATUS_PAGEFILE_QUOTA = 0xC0000007u32 as _,
-    STATUS_INVALID_HANDLE = 0xC0000008u32 as _,
-    STATUS_BAD_INITIAL_STACK = 0xC0000009u32 as _,
-    STATUS_BAD_INITIAL_PC = 0xC000000Au32 as _,
-    STATUS_INVALID_CID = 0xC000000Bu32 as _,
-    STATUS_TIMER_NOT_CANCELED = 0xC000000Cu32 as _,
-    STATUS_INVALID_PARAMETER = 0xC000000Du32 as _,
-    STATUS_NO_SUCH_DEVICE = 0xC000000Eu32 as _,
-    STATUS_NO_SUCH_FILE = 0xC000000Fu32 as _,
-    STATUS_INVALID_DEVICE_REQUEST = 0xC0000010u32 as _,
-    STATUS_END_OF_FILE = 0xC0000011u32 as _,
-    STATUS_WRONG_VOLUME = 0xC0000012u32 as _,
-    STATUS_NO_MEDIA_IN_DEVICE = 0xC0000013u32 as _,
-    STATUS_UNRECOGNIZED_MEDIA = 0xC0000014u32 as _,
-    STATUS_NONEXISTENT_SECTOR = 0xC0000015u32 as _,
-    STATUS_MORE_PROCESSING_REQUIRED = 0xC0000016u32 as _,
-    STATUS_NO_MEMORY = 0xC0000017u32 as _,
-    STATUS_CONFLICTING_ADDRESSES = 0xC0000018u32 as _,
-    STATUS_NOT_MAPPED_VIEW = 0xC0000019u32 as _,
-    STATUS_UNABLE_TO_FREE_VM = 0xC000001Au32 as _,
-    STATUS_UNABLE_TO_DELETE_SECTION = 0xC000001Bu32 as _,
-    STATUS_INVALID_SYSTEM_SERVICE = 0xC000001Cu32 as _,
-    STATUS_ILLEGAL_INSTRUCTION = 0xC000001Du32 as _,
-    STATUS_INVALID_LOCK_SEQUENCE = 0xC000001Eu32 as _,
-    STATUS_INVALID_VIEW_SIZE = 0xC000001Fu32 as _,
-    STATUS_INVALID_FILE_FOR_SECTION = 0xC0000020u32 as _,
-    STATUS_ALREADY_COMMITTED = 0xC0000021u32 as _,
-    STATUS_ACCESS_DENIED = 0xC0000022u32 as _,
-    STATUS_BUFFER_TOO_SMALL = 0xC0000023u32 as _,
-    STATUS_OBJECT_TYPE_MISMATCH = 0xC0000024u32 as _,
-    STATUS_NONCONTINUABLE_EXCEPTION = 0xC0000025u32 as _,
-    STATUS_INVALID_DISPOSITION = 0xC0000026u32 as _,
-    STATUS_UNWIND = 0xC0000027u32 as _,
-    STATUS_BAD_STACK = 0xC0000028u32 as _,
-    STATUS_INVALID_UNWIND_TARGET = 0xC0000029u32 as _,
-    STATUS_NOT_LOCKED = 0xC000002Au32 as _,
-    STATUS_PARITY_ERROR = 0xC000002Bu32 as _,
-    STATUS_UNABLE_TO_DECOMMIT_VM = 0xC000002Cu32 as _,
-    STATUS_NOT_COMMITTED = 0xC000002Du32 as _,
-    STATUS_INVALID_PORT_ATTRIBUTES = 0xC000002Eu32 as _,
-    STATUS_PORT_MESSAGE_TOO_LONG = 0xC000002Fu32 as _,
-    STATUS_INVALID_PARAMETER_MIX = 0xC0000030u32 as _,
-    STATUS_INVALID_QUOTA_LOWER = 0xC0000031u32 as _,
-    STATUS_DISK_CORRUPT_ERROR = 0xC0000032u32 as _,
-    STATUS_OBJECT_NAME_INVALID = 0xC0000033u32 as _,
-    STATUS_OBJECT_NAME_NOT_FOUND = 0xC0000034u32 as _,
-    STATUS_OBJECT_NAME_COLLISION = 0xC0000035u32 as _,
-    STATUS_PORT_DO_NOT_DISTURB = 0xC0000036u32 as _,
-    STATUS_PORT_DISCONNECTED = 0xC0000037u32 as _,
-    STATUS_DEVICE_ALREADY_ATTACHED = 0xC0000038u32 as _,
-    STATUS_OBJECT_PATH_INVALID = 0xC0000039u32 as _,
-    STATUS_OBJECT_PATH_NOT_FOUND = 0xC000003Au32 as _,
-    STATUS_OBJECT_PATH_SYNTAX_BAD = 0xC000003Bu32 as _,
-    STATUS_DATA_OVERRUN = 0xC000003Cu32 as _,
-    STATUS_DATA_LATE_ERROR = 0xC000003Du32 as _,
-    STATUS_DATA_ERROR = 0xC000003Eu32 as _,
-    STATUS_CRC_ERROR = 0xC000003Fu32 as _,
-    STATUS_SECTION_TOO_BIG = 0xC0000040u32 as _,
-    STATUS_PORT_CONNECTION_REFUSED = 0xC0000041u32 as _,
-    STATUS_INVALID_PORT_HANDLE = 0xC0000042u32 as _,
-    STATUS_SHARING_VIOLATION = 0xC0000043u32 as _,
-    STATUS_QUOTA_EXCEEDED = 0xC0000044u32 as _,
-    STATUS_INVALID_PAGE_PROTECTION = 0xC0000045u32 as _,
-    STATUS_MUTANT_NOT_OWNED = 0xC0000046u32 as _,
-    STATUS_SEMAPHORE_LIMIT_EXCEEDED = 0xC0000047u32 as _,
-    STATUS_PORT_ALREADY_SET = 0xC0000048u32 as _,
-    STATUS_SECTION_NOT_IMAGE = 0xC0000049u32 as _,
-    STATUS_SUSPEND_COUNT_EXCEEDED = 0xC000004Au32 as _,
-    STATUS_THREAD_IS_TERMINATING = 0xC000004Bu32 as _,
-    STATUS_BAD_WORKING_SET_LIMIT = 0xC000004Cu32 as _,
-    STATUS_INCOMPATIBLE_FILE_MAP = 0xC000004Du32 as _,
-    STATUS_SECTION_PROTECTION = 0xC000004Eu32 as _,
-    STATUS_EAS_NOT_SUPPORTED = 0xC000004Fu32 as _,
-    STATUS_EA_TOO_LARGE = 0xC0000050u32 as _,
-    STATUS_NONEXISTENT_EA_ENTRY = 0xC0000051u32 as _,
-    STATUS_NO_EAS_ON_FILE = 0xC0000052u32 as _,
-    STATUS_EA_CORRUPT_ERROR = 0xC0000053u32 as _,
-    STATUS_FILE_LOCK_CONFLICT = 0xC0000054u32 as _,
-    STATUS_LOCK_NOT_GRANTED = 0xC0000055u32 as _,
-    STATUS_DELETE_PENDING = 0xC0000056u32 as _,
-    STATUS_CTL_FILE_NOT_SUPPORTED = 0xC0000057u32 as _,
-    STATUS_UNKNOWN_REVISION = 0xC0000058u32 as _,
-    STATUS_REVISION_MISMATCH = 0xC0000059u32 as _,
-    STATUS_INVALID_OWNER = 0xC000005Au32 as _,
-    STATUS_INVALID_PRIMARY_GROUP = 0xC000005Bu32 as _,
-    STATUS_NO_IMPERSONATION_TOKEN = 0xC000005Cu32 as _,
-    STATUS_CANT_DISABLE_MANDATORY = 0xC000005Du32 as _,
-    STATUS_NO_LOGON_SERVERS = 0xC000005Eu32 as _,
-    STATUS_NO_SUCH_LOGON_SESSION = 0xC000005Fu32 as _,
-    STATUS_NO_SUCH_PRIVILEGE = 0xC0000060u32 as _,
-    STATUS_PRIVILEGE_NOT_HELD = 0xC0000061u32 as _,
-    STATUS_INVALID_ACCOUNT_NAME = 0xC0000062u32 as _,
-    STATUS_USER_EXISTS = 0xC0000063u32 as _,
-    STATUS_NO_SUCH_USER = 0xC0000064u32 as _,
-    STATUS_GROUP_EXISTS = 0xC0000065u32 as _,
-    STATUS_NO_SUCH_GROUP = 0xC0000066u32 as _,
-    STATUS_MEMBER_IN_GROUP = 0xC0000067u32 as _,
-    STATUS_MEMBER_NOT_IN_GROUP = 0xC0000068u32 as _,
-    STATUS_LAST_ADMIN = 0xC0000069u32 as _,
-    STATUS_WRONG_PASSWORD = 0xC000006Au32 as _,
-    STATUS_ILL_FORMED_PASSWORD = 0xC000006Bu32 as _,
-    STATUS_PASSWORD_RESTRICTION = 0xC000006Cu32 as _,
-    STATUS_LOGON_FAILURE = 0xC000006Du32 as _,
-    STATUS_ACCOUNT_RESTRICTION = 0xC000006Eu32 as _,
-    STATUS_INVALID_LOGON_HOURS = 0xC000006Fu32 as _,
-    STATUS_INVALID_WORKSTATION = 0xC0000070u32 as _,
-    STATUS_PASSWORD_EXPIRED = 0xC0000071u32 as _,
-    STATUS_ACCOUNT_DISABLED = 0xC0000072u32 as _,
-    STATUS_NONE_MAPPED = 0xC0000073u32 as _,
-    STATUS_TOO_MANY_LUIDS_REQUESTED = 0xC0000074u32 as _,
-    STATUS_LUIDS_EXHAUSTED = 0xC0000075u32 as _,
-    STATUS_INVALID_SUB_AUTHORITY = 0xC0000076u32 as _,
-    STATUS_INVALID_ACL = 0xC0000077u32 as _,
-    STATUS_INVALID_SID = 0xC0000078u32 as _,
-    STATUS_INVALID_SECURITY_DESCR = 0xC0000079u32 as _,
-    STATUS_PROCEDURE_NOT_FOUND = 0xC000007Au32 as _,
-    STATUS_INVALID_IMAGE_FORMAT = 0xC000007Bu32 as _,
-    STATUS_NO_TOKEN = 0xC000007Cu32 as _,
-    STATUS_BAD_INHERITANCE_ACL = 0xC000007Du32 as _,
-    STATUS_RANGE_NOT_LOCKED = 0xC000007Eu32 as _,
-    STATUS_DISK_FULL = 0xC000007Fu32 as _,
-    STATUS_SERVER_DISABLED = 0xC0000080u32 as _,
-    STATUS_SERVER_NOT_DISABLED = 0xC0000081u32 as _,
-    STATUS_TOO_MANY_GUIDS_REQUESTED = 0xC0000082u32 as _,
-    STATUS_GUIDS_EXHAUSTED = 0xC0000083u32 as _,
-    STATUS_INVALID_ID_AUTHORITY = 0xC0000084u32 as _,
-    STATUS_AGENTS_EXHAUSTED = 0xC0000085u32 as _,
-    STATUS_INVALID_VOLUME_LABEL = 0xC0000086u32 as _,
-    STATUS_SECTION_NOT_EXTENDED = 0xC0000087u32 as _,
-    STATUS_NOT_MAPPED_DATA = 0xC0000088u32 as _,
-    STATUS_RESOURCE_DATA_NOT_FOUND = 0xC0000089u32 as _,
-    STATUS_RESOURCE_TYPE_NOT_FOUND = 0xC000008Au32 as _,
-    STATUS_RESOURCE_NAME_NOT_FOUND = 0xC000008Bu32 as _,
-    STATUS_ARRAY_BOUNDS_EXCEEDED = 0xC000008Cu32 as _,
-    STATUS_FLOAT_DENORMAL_OPERAND = 0xC000008Du32 as _,
-    STATUS_FLOAT_DIVIDE_BY_ZERO = 0xC000008Eu32 as _,
-    STATUS_FLOAT_INEXACT_RESULT = 0xC000008Fu32 as _,
-    STATUS_FLOAT_INVALID_OPERATION = 0xC0000090u32 as _,
-    STATUS_FLOAT_OVERFLOW = 0xC0000091u32 as _,
-    STATUS_FLOAT_STACK_CHECK = 0xC0000092u32 as _,
-    STATUS_FLOAT_UNDERFLOW = 0xC0000093u32 as _,
-    STATUS_INTEGER_DIVIDE_BY_ZERO = 0xC0000094u32 as _,
-    STATUS_INTEGER_OVERFLOW = 0xC0000095u32 as _,
-    STATUS_PRIVILEGED_INSTRUCTION = 0xC0000096u32 as _,
-    STATUS_TOO_MANY_PAGING_FILES = 0xC0000097u32 as _,
-    STATUS_FILE_INVALID = 0xC0000098u32 as _,
-    STATUS_ALLOTTED_SPACE_EXCEEDED = 0xC0000099u32 as _,
-    STATUS_INSUFFICIENT_RESOURCES = 0xC000009Au32 as _,
-    STATUS_DFS_EXIT_PATH_FOUND = 0xC000009Bu32 as _,
-    STATUS_DEVICE_DATA_ERROR = 0xC000009Cu32 as _,
-    STATUS_DEVICE_NOT_CONNECTED = 0xC000009Du32 as _,
-    STATUS_DEVICE_POWER_FAILURE = 0xC000009Eu32 as _,
-    STATUS_FREE_VM_NOT_AT_BASE = 0xC000009Fu32 as _,
-    STATUS_MEMORY_NOT_ALLOCATED = 0xC00000A0u32 as _,
-    STATUS_WORKING_SET_QUOTA = 0xC00000A1u32 as _,
-    STATUS_MEDIA_WRITE_PROTECTED = 0xC00000A2u32 as _,
-    STATUS_DEVICE_NOT_READY = 0xC00000A3u32 as _,
-    STATUS_INVALID_GROUP_ATTRIBUTES = 0xC00000A4u32 as _,
-    STATUS_BAD_IMPERSONATION_LEVEL = 0xC00000A5u32 as _,
-    STATUS_CANT_OPEN_ANONYMOUS = 0xC00000A6u32 as _,
-    STATUS_BAD_VALIDATION_CLASS = 0xC00000A7u32 as _,
-    STATUS_BAD_TOKEN_TYPE = 0xC00000A8u32 as _,
-    STATUS_BAD_MASTER_BOOT_RECORD = 0xC00000A9u32 as _,
-    STATUS_INSTRUCTION_MISALIGNMENT = 0xC00000AAu32 as _,
-    STATUS_INSTANCE_NOT_AVAILABLE = 0xC00000ABu32 as _,
-    STATUS_PIPE_NOT_AVAILABLE = 0xC00000ACu32 as _,
-    STATUS_INVALID_PIPE_STATE = 0xC00000ADu32 as _,
-    STATUS_PIPE_BUSY = 0xC00000AEu32 as _,
-    STATUS_ILLEGAL_FUNCTION = 0xC00000AFu32 as _,
-    STATUS_PIPE_DISCONNECTED = 0xC00000B0u32 as _,
-    STATUS_PIPE_CLOSING = 0xC00000B1u32 as _,
-    STATUS_PIPE_CONNECTED = 0xC00000B2u32 as _,
-    STATUS_PIPE_LISTENING = 0xC00000B3u32 as _,
-    STATUS_INVALID_READ_MODE = 0xC00000B4u32 as _,
-    STATUS_IO_TIMEOUT = 0xC00000B5u32 as _,
-    STATUS_FILE_FORCED_CLOSED = 0xC00000B6u32 as _,
-    STATUS_PROFILING_NOT_STARTED = 0xC00000B7u32 as _,
-    STATUS_PROFILING_NOT_STOPPED = 0xC00000B8u32 as _,
-    STATUS_COULD_NOT_INTERPRET = 0xC00000B9u32 as _,
-    STATUS_FILE_IS_A_DIRECTORY = 0xC00000BAu32 as _,
-    STATUS_NOT_SUPPORTED = 0xC00000BBu32 as _,
-    STATUS_REMOTE_NOT_LISTENING = 0xC00000BCu32 as _,
-    STATUS_DUPLICATE_NAME = 0xC00000BDu32 as _,
-    STATUS_BAD_NETWORK_PATH = 0xC00000BEu32 as _,
-    STATUS_NETWORK_BUSY = 0xC00000BFu32 as _,
-    STATUS_DEVICE_DOES_NOT_EXIST = 0xC00000C0u32 as _,
-    STATUS_TOO_MANY_COMMANDS = 0xC00000C1u32 as _,
-    STATUS_ADAPTER_HARDWARE_ERROR = 0xC00000C2u32 as _,
-    STATUS_INVALID_NETWORK_RESPONSE = 0xC00000C3u32 as _,
-    STATUS_UNEXPECTED_NETWORK_ERROR = 0xC00000C4u32 as _,
-    STATUS_BAD_REMOTE_ADAPTER = 0xC00000C5u32 as _,
-    STATUS_PRINT_QUEUE_FULL = 0xC00000C6u32 as _,
-    STATUS_NO_SPOOL_SPACE = 0xC00000C7u32 as _,
-    STATUS_PRINT_CANCELLED = 0xC00000C8u32 as _,
-    STATUS_NETWORK_NAME_DELETED = 0xC00000C9u32 as _,
-    STATUS_NETWORK_ACCESS_DENIED = 0xC00000CAu32 as _,
-    STATUS_BAD_DEVICE_TYPE = 0xC00000CBu32 as _,
-    STATUS_BAD_NETWORK_NAME = 0xC00000CCu32 as _,
-    STATUS_TOO_MANY_NAMES = 0xC00000CDu32 as _,
-    STATUS_TOO_MANY_SESSIONS = 0xC00000CEu32 as _,
-    STATUS_SHARING_PAUSED = 0xC00000CFu32 as _,
-    STATUS_REQUEST_NOT_ACCEPTED = 0xC00000D0u32 as _,
-    STATUS_REDIRECTOR_PAUSED = 0xC00000D1u32 as _,
-    STATUS_NET_WRITE_FAULT = 0xC00000D2u32 as _,
-    STATUS_PROFILING_AT_LIMIT = 0xC00000D3u32 as _,
-    STATUS_NOT_SAME_DEVICE = 0xC00000D4u32 as _,
-    STATUS_FILE_RENAMED = 0xC00000D5u32 as _,
-    STATUS_VIRTUAL_CIRCUIT_CLOSED = 0xC00000D6u32 as _,
-    STATUS_NO_SECURITY_ON_OBJECT = 0xC00000D7u32 as _,
-    STATUS_CANT_WAIT = 0xC00000D8u32 as _,
-    STATUS_PIPE_EMPTY = 0xC00000D9u32 as _,
-    STATUS_CANT_ACCESS_DOMAIN_INFO = 0xC00000DAu32 as _,
-    STATUS_CANT_TERMINATE_SELF = 0xC00000DBu32 as _,
-    STATUS_INVALID_SERVER_STATE = 0xC00000DCu32 as _,
-    STATUS_INVALID_DOMAIN_STATE = 0xC00000DDu32 as _,
-    STATUS_INVALID_DOMAIN_ROLE = 0xC00000DEu32 as _,
-    STATUS_NO_SUCH_DOMAIN = 0xC00000DFu32 as _,
-    STATUS_DOMAIN_EXISTS = 0xC00000E0u32 as _,
-    STATUS_DOMAIN_LIMIT_EXCEEDED = 0xC00000E1u32 as _,
-    STATUS_OPLOCK_NOT_GRANTED = 0xC00000E2u32 as _,
-    STATUS_INVALID_OPLOCK_PROTOCOL = 0xC00000E3u32 as _,
-    STATUS_INTERNAL_DB_CORRUPTION = 0xC00000E4u32 as _,
-    STATUS_INTERNAL_ERROR = 0xC00000E5u32 as _,
-    STATUS_GENERIC_NOT_MAPPED = 0xC00000E6u32 as _,
-    STATUS_BAD_DESCRIPTOR_FORMAT = 0xC00000E7u32 as _,
-    STATUS_INVALID_USER_BUFFER = 0xC00000E8u32 as _,
-    STATUS_UNEXPECTED_IO_ERROR = 0xC00000E9u32 as _,
-    STATUS_UNEXPECTED_MM_CREATE_ERR = 0xC00000EAu32 as _,
-    STATUS_UNEXPECTED_MM_MAP_ERROR = 0xC00000EBu32 as _,
-    STATUS_UNEXPECTED_MM_EXTEND_ERR = 0xC00000ECu32 as _,
-    STATUS_NOT_LOGON_PROCESS = 0xC00000EDu32 as _,
-    STATUS_LOGON_SESSION_EXISTS = 0xC00000EEu32 as _,
-    STATUS_INVALID_PARAMETER_1 = 0xC00000EFu32 as _,
-    STATUS_INVALID_PARAMETER_2 = 0xC00000F0u32 as _,
-    STATUS_INVALID_PARAMETER_3 = 0xC00000F1u32 as _,
-    STATUS_INVALID_PARAMETER_4 = 0xC00000F2u32 as _,
-    STATUS_INVALID_PARAMETER_5 = 0xC00000F3u32 as _,
-    STATUS_INVALID_PARAMETER_6 = 0xC00000F4u32 as _,
-    STATUS_INVALID_PARAMETER_7 = 0xC00000F5u32 as _,
-    STATUS_INVALID_PARAMETER_8 = 0xC00000F6u32 as _,
-    STATUS_INVALID_PARAMETER_9 = 0xC00000F7u32 as _,
-    STATUS_INVALID_PARAMETER_10 = 0xC00000F8u32 as _,
-    STATUS_INVALID_PARAMETER_11 = 0xC00000F9u32 as _,
-    STATUS_INVALID_PARAMETER_12 = 0xC00000FAu32 as _,
-    STATUS_REDIRECTOR_NOT_STARTED = 0xC00000FBu32 as _,
-    STATUS_REDIRECTOR_STARTED = 0xC00000FCu32 as _,
-    STATUS_STACK_OVERFLOW = 0xC00000FDu32 as _,
-    STATUS_NO_SUCH_PACKAGE = 0xC00000FEu32 as _,
-    STATUS_BAD_FUNCTION_TABLE = 0xC00000FFu32 as _,
-    STATUS_VARIABLE_NOT_FOUND = 0xC0000100u32 as _,
-    STATUS_DIRECTORY_NOT_EMPTY = 0xC0000101u32 as _,
-    STATUS_FILE_CORRUPT_ERROR = 0xC0000102u32 as _,
-    STATUS_NOT_A_DIRECTORY = 0xC0000103u32 as _,
-    STATUS_BAD_LOGON_SESSION_STATE = 0xC0000104u32 as _,
-    STATUS_LOGON_SESSION_COLLISION = 0xC0000105u32 as _,
-    STATUS_NAME_TOO_LONG = 0xC0000106u32 as _,
-    STATUS_FILES_OPEN = 0xC0000107u32 as _,
-    STATUS_CONNECTION_IN_USE = 0xC0000108u32 as _,
-    STATUS_MESSAGE_NOT_FOUND = 0xC0000109u32 as _,
-    STATUS_PROCESS_IS_TERMINATING = 0xC000010Au32 as _,
-    STATUS_INVALID_LOGON_TYPE = 0xC000010Bu32 as _,
-    STATUS_NO_GUID_TRANSLATION = 0xC000010Cu32 as _,
-    STATUS_CANNOT_IMPERSONATE = 0xC000010Du32 as _,
-    STATUS_IMAGE_ALREADY_LOADED = 0xC000010Eu32 as _,
-    STATUS_ABIOS_NOT_PRESENT = 0xC000010Fu32 as _,
-    STATUS_ABIOS_LID_NOT_EXIST = 0xC0000110u32 as _,
-    STATUS_ABIOS_LID_ALREADY_OWNED = 0xC0000111u32 as _,
-    STATUS_ABIOS_NOT_LID_OWNER = 0xC0000112u32 as _,
-    STATUS_ABIOS_INVALID_COMMAND = 0xC0000113u32 as _,
-    STATUS_ABIOS_INVALID_LID = 0xC0000114u32 as _,
-    STATUS_ABIOS_SELECTOR_NOT_AVAILABLE = 0xC0000115u32 as _,
-    STATUS_ABIOS_INVALID_SELECTOR = 0xC0000116u32 as _,
-    STATUS_NO_LDT = 0xC0000117u32 as _,
-    STATUS_INVALID_LDT_SIZE = 0xC0000118u32 as _,
-    STATUS_INVALID_LDT_OFFSET = 0xC0000119u32 as _,
-    STATUS_INVALID_LDT_DESCRIPTOR = 0xC000011Au32 as _,
-    STATUS_INVALID_IMAGE_NE_FORMAT = 0xC000011Bu32 as _,
-    STATUS_RXACT_INVALID_STATE = 0xC000011Cu32 as _,
-    STATUS_RXACT_COMMIT_FAILURE = 0xC000011Du32 as _,
-    STATUS_MAPPED_FILE_SIZE_ZERO = 0xC000011Eu32 as _,
-    STATUS_TOO_MANY_OPENED_FILES = 0xC000011Fu32 as _,
-    STATUS_CANCELLED = 0xC0000120u32 as _,
-    STATUS_CANNOT_DELETE = 0xC0000121u32 as _,
-    STATUS_INVALID_COMPUTER_NAME = 0xC0000122u32 as _,
-    STATUS_FILE_DELETED = 0xC0000123u32 as _,
-    STATUS_SPECIAL_ACCOUNT = 0xC0000124u32 as _,
-    STATUS_SPECIAL_GROUP = 0xC0000125u32 as _,
-    STATUS_SPECIAL_USER = 0xC0000126u32 as _,
-    STATUS_MEMBERS_PRIMARY_GROUP = 0xC0000127u32 as _,
-    STATUS_FILE_CLOSED = 0xC0000128u32 as _,
-    STATUS_TOO_MANY_THREADS = 0xC0000129u32 as _,
-    STATUS_THREAD_NOT_IN_PROCESS = 0xC000012Au32 as _,
-    STATUS_TOKEN_ALREADY_IN_USE = 0xC000012Bu32 as _,
-    STATUS_PAGEFILE_QUOTA_EXCEEDED = 0xC000012Cu32 as _,
-    STATUS_COMMITMENT_LIMIT = 0xC000012Du32 as _,
-    STATUS_INVALID_IMAGE_LE_FORMAT = 0xC000012Eu32 as _,
-    STATUS_INVALID_IMAGE_NOT_MZ = 0xC000012Fu32 as _,
-    STATUS_INVALID_IMAGE_PROTECT = 0xC0000130u32 as _,
-    STATUS_INVALID_IMAGE_WIN_16 = 0xC0000131u32 as _,
-    STATUS_LOGON_SERVER_CONFLICT = 0xC0000132u32 as _,
-    STATUS_TIME_DIFFERENCE_AT_DC = 0xC0000133u32 as _,
-    STATUS_SYNCHRONIZATION_REQUIRED = 0xC0000134u32 as _,
-    STATUS_DLL_NOT_FOUND = 0xC0000135u32 as _,
-    STATUS_OPEN_FAILED = 0xC0000136u32 as _,
-    STATUS_IO_PRIVILEGE_FAILED = 0xC0000137u32 as _,
-    STATUS_ORDINAL_NOT_FOUND = 0xC0000138u32 as _,
-    STATUS_ENTRYPOINT_NOT_FOUND = 0xC0000139u32 as _,
-    STATUS_CONTROL_C_EXIT = 0xC000013Au32 as _,
-    STATUS_LOCAL_DISCONNECT = 0xC000013Bu32 as _,
-    STATUS_REMOTE_DISCONNECT = 0xC000013Cu32 as _,
-    STATUS_REMOTE_RESOURCES = 0xC000013Du32 as _,
-    STATUS_LINK_FAILED = 0xC000013Eu32 as _,
-    STATUS_LINK_TIMEOUT = 0xC000013Fu32 as _,
-    STATUS_INVALID_CONNECTION = 0xC0000140u32 as _,
-    STATUS_INVALID_ADDRESS = 0xC0000141u32 as _,
-    STATUS_DLL_INIT_FAILED = 0xC0000142u32 as _,
-    STATUS_MISSING_SYSTEMFILE = 0xC0000143u32 as _,
-    STATUS_UNHANDLED_EXCEPTION = 0xC0000144u32 as _,
-    STATUS_APP_INIT_FAILURE = 0xC0000145u32 as _,
-    STATUS_PAGEFILE_CREATE_FAILED = 0xC0000146u32 as _,
-    STATUS_NO_PAGEFILE = 0xC0000147u32 as _,
-    STATUS_INVALID_LEVEL = 0xC0000148u32 as _,
-    STATUS_WRONG_PASSWORD_CORE = 0xC0000149u32 as _,
-    STATUS_ILLEGAL_FLOAT_CONTEXT = 0xC000014Au32 as _,
-    STATUS_PIPE_BROKEN = 0xC000014Bu32 as _,
-    STATUS_REGISTRY_CORRUPT = 0xC000014Cu32 as _,
-    STATUS_REGISTRY_IO_FAILED = 0xC000014Du32 as _,
-    STATUS_NO_EVENT_PAIR = 0xC000014Eu32 as _,
-    STATUS_UNRECOGNIZED_VOLUME = 0xC000014Fu32 as _,
-    STATUS_SERIAL_NO_DEVICE_INITED = 0xC0000150u32 as _,
-    STATUS_NO_SUCH_ALIAS = 0xC0000151u32 as _,
-    STATUS_MEMBER_NOT_IN_ALIAS = 0xC0000152u32 as _,
-    STATUS_MEMBER_IN_ALIAS = 0xC0000153u32 as _,
-    STATUS_ALIAS_EXISTS = 0xC0000154u32 as _,
-    STATUS_LOGON_NOT_GRANTED = 0xC0000155u32 as _,
-    STATUS_TOO_MANY_SECRETS = 0xC0000156u32 as _,
-    STATUS_SECRET_TOO_LONG = 0xC0000157u32 as _,
-    STATUS_INTERNAL_DB_ERROR = 0xC0000158u32 as _,
-    STATUS_FULLSCREEN_MODE = 0xC0000159u32 as _,
-    STATUS_TOO_MANY_CONTEXT_IDS = 0xC000015Au32 as _,
-    STATUS_LOGON_TYPE_NOT_GRANTED = 0xC000015Bu32 as _,
-    STATUS_NOT_REGISTRY_FILE = 0xC000015Cu32 as _,
-    STATUS_NT_CROSS_ENCRYPTION_REQUIRED = 0xC000015Du32 as _,
-    STATUS_DOMAIN_CTRLR_CONFIG_ERROR = 0xC000015Eu32 as _,
-    STATUS_FT_MISSING_MEMBER = 0xC000015Fu32 as _,
-    STATUS_ILL_FORMED_SERVICE_ENTRY = 0xC0000160u32 as _,
-    STATUS_ILLEGAL_CHARACTER = 0xC0000161u32 as _,
-    STATUS_UNMAPPABLE_CHARACTER = 0xC0000162u32 as _,
-    STATUS_UNDEFINED_CHARACTER = 0xC0000163u32 as _,
-    STATUS_FLOPPY_VOLUME = 0xC0000164u32 as _,
-    STATUS_FLOPPY_ID_MARK_NOT_FOUND = 0xC0000165u32 as _,
-    STATUS_FLOPPY_WRONG_CYLINDER = 0xC0000166u32 as _,
-    STATUS_FLOPPY_UNKNOWN_ERROR = 0xC0000167u32 as _,
-    STATUS_FLOPPY_BAD_REGISTERS = 0xC0000168u32 as _,
-    STATUS_DISK_RECALIBRATE_FAILED = 0xC0000169u32 as _,
-    STATUS_DISK_OPERATION_FAILED = 0xC000016Au32 as _,
-    STATUS_DISK_RESET_FAILED = 0xC000016Bu32 as _,
-    STATUS_SHARED_IRQ_BUSY = 0xC000016Cu32 as _,
-    STATUS_FT_ORPHANING = 0xC000016Du32 as _,
-    STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT = 0xC000016Eu32 as _,
-    STATUS_PARTITION_FAILURE = 0xC0000172u32 as _,
-    STATUS_INVALID_BLOCK_LENGTH = 0xC0000173u32 as _,
-    STATUS_DEVICE_NOT_PARTITIONED = 0xC0000174u32 as _,
-    STATUS_UNABLE_TO_LOCK_MEDIA = 0xC0000175u32 as _,
-    STATUS_UNABLE_TO_UNLOAD_MEDIA = 0xC0000176u32 as _,
-    STATUS_EOM_OVERFLOW = 0xC0000177u32 as _,
-    STATUS_NO_MEDIA = 0xC0000178u32 as _,
-    STATUS_NO_SUCH_MEMBER = 0xC000017Au32 as _,
-    STATUS_INVALID_MEMBER = 0xC000017Bu32 as _,
-    STATUS_KEY_DELETED = 0xC000017Cu32 as _,
-    STATUS_NO_LOG_SPACE = 0xC000017Du32 as _,
-    STATUS_TOO_MANY_SIDS = 0xC000017Eu32 as _,
-    STATUS_LM_CROSS_ENCRYPTION_REQUIRED = 0xC000017Fu32 as _,
-    STATUS_KEY_HAS_CHILDREN = 0xC0000180u32 as _,
-    STATUS_CHILD_MUST_BE_VOLATILE = 0xC0000181u32 as _,
-    STATUS_DEVICE_CONFIGURATION_ERROR = 0xC0000182u32 as _,
-    STATUS_DRIVER_INTERNAL_ERROR = 0xC0000183u32 as _,
-    STATUS_INVALID_DEVICE_STATE = 0xC0000184u32 as _,
-    STATUS_IO_DEVICE_ERROR = 0xC0000185u32 as _,
-    STATUS_DEVICE_PROTOCOL_ERROR = 0xC0000186u32 as _,
-    STATUS_BACKUP_CONTROLLER = 0xC0000187u32 as _,
-    STATUS_LOG_FILE_FULL = 0xC0000188u32 as _,
-    STATUS_TOO_LATE = 0xC0000189u32 as _,
-    STATUS_NO_TRUST_LSA_SECRET = 0xC000018Au32 as _,
-    STATUS_NO_TRUST_SAM_ACCOUNT = 0xC000018Bu32 as _,
-    STATUS_TRUSTED_DOMAIN_FAILURE = 0xC000018Cu32 as _,
-    STATUS_TRUSTED_RELATIONSHIP_FAILURE = 0xC000018Du32 as _,
-    STATUS_EVENTLOG_FILE_CORRUPT = 0xC000018Eu32 as _,
-    STATUS_EVENTLOG_CANT_START = 0xC000018Fu32 as _,
-    STATUS_TRUST_FAILURE = 0xC0000190u32 as _,
-    STATUS_MUTANT_LIMIT_EXCEEDED = 0xC0000191u32 as _,
-    STATUS_NETLOGON_NOT_STARTED = 0xC0000192u32 as _,
-    STATUS_ACCOUNT_EXPIRED = 0xC0000193u32 as _,
-    STATUS_POSSIBLE_DEADLOCK = 0xC0000194u32 as _,
-    STATUS_NETWORK_CREDENTIAL_CONFLICT = 0xC0000195u32 as _,
-    STATUS_REMOTE_SESSION_LIMIT = 0xC0000196u32 as _,
-    STATUS_EVENTLOG_FILE_CHANGED = 0xC0000197u32 as _,
-    STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT = 0xC0000198u32 as _,
-    STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT = 0xC0000199u32 as _,
-    STATUS_NOLOGON_SERVER_TRUST_ACCOUNT = 0xC000019Au32 as _,
-    STATUS_DOMAIN_TRUST_INCONSISTENT = 0xC000019Bu32 as _,
-    STATUS_FS_DRIVER_REQUIRED = 0xC000019Cu32 as _,
-    STATUS_IMAGE_ALREADY_LOADED_AS_DLL = 0xC000019Du32 as _,
-    STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING = 0xC000019Eu32 as _,
-    STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME = 0xC000019Fu32 as _,
-    STATUS_SECURITY_STREAM_IS_INCONSISTENT = 0xC00001A0u32 as _,
-    STATUS_INVALID_LOCK_RANGE = 0xC00001A1u32 as _,
-    STATUS_INVALID_ACE_CONDITION = 0xC00001A2u32 as _,
-    STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT = 0xC00001A3u32 as _,
-    STATUS_NOTIFICATION_GUID_ALREADY_DEFINED = 0xC00001A4u32 as _,
-    STATUS_INVALID_EXCEPTION_HANDLER = 0xC00001A5u32 as _,
-    STATUS_DUPLICATE_PRIVILEGES = 0xC00001A6u32 as _,
-    STATUS_NOT_ALLOWED_ON_SYSTEM_FILE = 0xC00001A7u32 as _,
-    STATUS_REPAIR_NEEDED = 0xC00001A8u32 as _,
-    STATUS_QUOTA_NOT_ENABLED = 0xC00001A9u32 as _,
-    STATUS_NO_APPLICATION_PACKAGE = 0xC00001AAu32 as _,
-    STATUS_FILE_METADATA_OPTIMIZATION_IN_PROGRESS = 0xC00001ABu32 as _,
-    STATUS_NOT_SAME_OBJECT = 0xC00001ACu32 as _,
-    STATUS_FATAL_MEMORY_EXHAUSTION = 0xC00001ADu32 as _,
-    STATUS_ERROR_PROCESS_NOT_IN_JOB = 0xC00001AEu32 as _,
-    STATUS_CPU_SET_INVALID = 0xC00001AFu32 as _,
-    STATUS_NETWORK_OPEN_RESTRICTION = 0xC0000201u32 as _,
-    STATUS_NO_USER_SESSION_KEY = 0xC0000202u32 as _,
-    STATUS_USER_SESSION_DELETED = 0xC0000203u32 as _,
-    STATUS_RESOURCE_LANG_NOT_FOUND = 0xC0000204u32 as _,
-    STATUS_INSUFF_SERVER_RESOURCES = 0xC0000205u32 as _,
-    STATUS_INVALID_BUFFER_SIZE = 0xC0000206u32 as _,
-    STATUS_INVALID_ADDRESS_COMPONENT = 0xC0000207u32 as _,
-    STATUS_INVALID_ADDRESS_WILDCARD = 0xC0000208u32 as _,
-    STATUS_TOO_MANY_ADDRESSES = 0xC0000209u32 as _,
-    STATUS_ADDRESS_ALREADY_EXISTS = 0xC000020Au32 as _,
-    STATUS_ADDRESS_CLOSED = 0xC000020Bu32 as _,
-    STATUS_CONNECTION_DISCONNECTED = 0xC000020Cu32 as _,
-    STATUS_CONNECTION_RESET = 0xC000020Du32 as _,
-    STATUS_TOO_MANY_NODES = 0xC000020Eu32 as _,
-    STATUS_TRANSACTION_ABORTED = 0xC000020Fu32 as _,
-    STATUS_TRANSACTION_TIMED_OUT = 0xC0000210u32 as _,
-    STATUS_TRANSACTION_NO_RELEASE = 0xC0000211u32 as _,
-    STATUS_TRANSACTION_NO_MATCH = 0xC0000212u32 as _,
-    STATUS_TRANSACTION_RESPONDED = 0xC0000213u32 as _,
-    STATUS_TRANSACTION_INVALID_ID = 0xC0000214u32 as _,
-    STATUS_TRANSACTION_INVALID_TYPE = 0xC0000215u32 as _,
-    STATUS_NOT_SERVER_SESSION = 0xC0000216u32 as _,
-    STATUS_NOT_CLIENT_SESSION = 0xC0000217u32 as _,
-    STATUS_CANNOT_LOAD_REGISTRY_FILE = 0xC0000218u32 as _,
-    STATUS_DEBUG_ATTACH_FAILED = 0xC0000219u32 as _,
-    STATUS_SYSTEM_PROCESS_TERMINATED = 0xC000021Au32 as _,
-    STATUS_DATA_NOT_ACCEPTED = 0xC000021Bu32 as _,
-    STATUS_NO_BROWSER_SERVERS_FOUND = 0xC000021Cu32 as _,
-    STATUS_VDM_HARD_ERROR = 0xC000021Du32 as _,
-    STATUS_DRIVER_CANCEL_TIMEOUT = 0xC000021Eu32 as _,
-    STATUS_REPLY_MESSAGE_MISMATCH = 0xC000021Fu32 as _,
-    STATUS_MAPPED_ALIGNMENT = 0xC0000220u32 as _,
-    STATUS_IMAGE_CHECKSUM_MISMATCH = 0xC0000221u32 as _,
-    STATUS_LOST_WRITEBEHIND_DATA = 0xC0000222u32 as _,
-    STATUS_CLIENT_SERVER_PARAMETERS_INVALID = 0xC0000223u32 as _,
-    STATUS_PASSWORD_MUST_CHANGE = 0xC0000224u32 as _,
-    STATUS_NOT_FOUND = 0xC0000225u32 as _,
-    STATUS_NOT_TINY_STREAM = 0xC0000226u32 as _,
-    STATUS_RECOVERY_FAILURE = 0xC0000227u32 as _,
-    STATUS_STACK_OVERFLOW_READ = 0xC0000228u32 as _,
-    STATUS_FAIL_CHECK = 0xC0000229u32 as _,
-    STATUS_DUPLICATE_OBJECTID = 0xC000022Au32 as _,
-    STATUS_OBJECTID_EXISTS = 0xC000022Bu32 as _,
-    STATUS_CONVERT_TO_LARGE = 0xC000022Cu32 as _,
-    STATUS_RETRY = 0xC000022Du32 as _,
-    STATUS_FOUND_OUT_OF_SCOPE = 0xC000022Eu32 as _,
-    STATUS_ALLOCATE_BUCKET = 0xC000022Fu32 as _,
-    STATUS_PROPSET_NOT_FOUND = 0xC0000230u32 as _,
-    STATUS_MARSHALL_OVERFLOW = 0xC0000231u32 as _,
-    STATUS_INVALID_VARIANT = 0xC0000232u32 as _,
-    STATUS_DOMAIN_CONTROLLER_NOT_FOUND = 0xC0000233u32 as _,
-    STATUS_ACCOUNT_LOCKED_OUT = 0xC0000234u32 as _,
-    STATUS_HANDLE_NOT_CLOSABLE = 0xC0000235u32 as _,
-    STATUS_CONNECTION_REFUSED = 0xC0000236u32 as _,
-    STATUS_GRACEFUL_DISCONNECT = 0xC0000237u32 as _,
-    STATUS_ADDRESS_ALREADY_ASSOCIATED = 0xC0000238u32 as _,
-    STATUS_ADDRESS_NOT_ASSOCIATED = 0xC0000239u32 as _,
-    STATUS_CONNECTION_INVALID = 0xC000023Au32 as _,
-    STATUS_CONNECTION_ACTIVE = 0xC000023Bu32 as _,
-    STATUS_NETWORK_UNREACHABLE = 0xC000023Cu32 as _,
-    STATUS_HOST_UNREACHABLE = 0xC000023Du32 as _,
-    STATUS_PROTOCOL_UNREACHABLE = 0xC000023Eu32 as _,
-    STATUS_PORT_UNREACHABLE = 0xC000023Fu32 as _,
-    STATUS_REQUEST_ABORTED = 0xC0000240u32 as _,
-    STATUS_CONNECTION_ABORTED = 0xC0000241u32 as _,
-    STATUS_BAD_COMPRESSION_BUFFER = 0xC0000242u32 as _,
-    STATUS_USER_MAPPED_FILE = 0xC0000243u32 as _,
-    STATUS_AUDIT_FAILED = 0xC0000244u32 as _,
-    STATUS_TIMER_RESOLUTION_NOT_SET = 0xC0000245u32 as _,
-    STATUS_CONNECTION_COUNT_LIMIT = 0xC0000246u32 as _,
-    STATUS_LOGIN_TIME_RESTRICTION = 0xC0000247u32 as _,
-    STATUS_LOGIN_WKSTA_RESTRICTION = 0xC0000248u32 as _,
-    STATUS_IMAGE_MP_UP_MISMATCH = 0xC0000249u32 as _,
-    STATUS_INSUFFICIENT_LOGON_INFO = 0xC0000250u32 as _,
-    STATUS_BAD_DLL_ENTRYPOINT = 0xC0000251u32 as _,
-    STATUS_BAD_SERVICE_ENTRYPOINT = 0xC0000252u32 as _,
-    STATUS_LPC_REPLY_LOST = 0xC0000253u32 as _,
-    STATUS_IP_ADDRESS_CONFLICT1 = 0xC0000254u32 as _,
-    STATUS_IP_ADDRESS_CONFLICT2 = 0xC0000255u32 as _,
-    STATUS_REGISTRY_QUOTA_LIMIT = 0xC0000256u32 as _,
-    STATUS_PATH_NOT_COVERED = 0xC0000257u32 as _,
-    STATUS_NO_CALLBACK_ACTIVE = 0xC0000258u32 as _,
-    STATUS_LICENSE_QUOTA_EXCEEDED = 0xC0000259u32 as _,
-    STATUS_PWD_TOO_SHORT = 0xC000025Au32 as _,
-    STATUS_PWD_TOO_RECENT = 0xC000025Bu32 as _,
-    STATUS_PWD_HISTORY_CONFLICT = 0xC000025Cu32 as _,
-    STATUS_PLUGPLAY_NO_DEVICE = 0xC000025Eu32 as _,
-    STATUS_UNSUPPORTED_COMPRESSION = 0xC000025Fu32 as _,
-    STATUS_INVALID_HW_PROFILE = 0xC0000260u32 as _,
-    STATUS_INVALID_PLUGPLAY_DEVICE_PATH = 0xC0000261u32 as _,
-    STATUS_DRIVER_ORDINAL_NOT_FOUND = 0xC0000262u32 as _,
-    STATUS_DRIVER_ENTRYPOINT_NOT_FOUND = 0xC0000263u32 as _,
-    STATUS_RESOURCE_NOT_OWNED = 0xC0000264u32 as _,
-    STATUS_TOO_MANY_LINKS = 0xC0000265u32 as _,
-    STATUS_QUOTA_LIST_INCONSISTENT = 0xC0000266u32 as _,
-    STATUS_FILE_IS_OFFLINE = 0xC0000267u32 as _,
-    STATUS_EVALUATION_EXPIRATION = 0xC0000268u32 as _,
-    STATUS_ILLEGAL_DLL_RELOCATION = 0xC0000269u32 as _,
-    STATUS_LICENSE_VIOLATION = 0xC000026Au32 as _,
-    STATUS_DLL_INIT_FAILED_LOGOFF = 0xC000026Bu32 as _,
-    STATUS_DRIVER_UNABLE_TO_LOAD = 0xC000026Cu32 as _,
-    STATUS_DFS_UNAVAILABLE = 0xC000026Du32 as _,
-    STATUS_VOLUME_DISMOUNTED = 0xC000026Eu32 as _,
-    STATUS_WX86_INTERNAL_ERROR = 0xC000026Fu32 as _,
-    STATUS_WX86_FLOAT_STACK_CHECK = 0xC0000270u32 as _,
-    STATUS_VALIDATE_CONTINUE = 0xC0000271u32 as _,
-    STATUS_NO_MATCH = 0xC0000272u32 as _,
-    STATUS_NO_MORE_MATCHES = 0xC0000273u32 as _,
-    STATUS_NOT_A_REPARSE_POINT = 0xC0000275u32 as _,
-    STATUS_IO_REPARSE_TAG_INVALID = 0xC0000276u32 as _,
-    STATUS_IO_REPARSE_TAG_MISMATCH = 0xC0000277u32 as _,
-    STATUS_IO_REPARSE_DATA_INVALID = 0xC0000278u32 as _,
-    STATUS_IO_REPARSE_TAG_NOT_HANDLED = 0xC0000279u32 as _,
-    STATUS_PWD_TOO_LONG = 0xC000027Au32 as _,
-    STATUS_STOWED_EXCEPTION = 0xC000027Bu32 as _,
-    STATUS_REPARSE_POINT_NOT_RESOLVED = 0xC0000280u32 as _,
-    STATUS_DIRECTORY_IS_A_REPARSE_POINT = 0xC0000281u32 as _,
-    STATUS_RANGE_LIST_CONFLICT = 0xC0000282u32 as _,
-    STATUS_SOURCE_ELEMENT_EMPTY = 0xC0000283u32 as _,
-    STATUS_DESTINATION_ELEMENT_FULL = 0xC0000284u32 as _,
-    STATUS_ILLEGAL_ELEMENT_ADDRESS = 0xC0000285u32 as _,
-    STATUS_MAGAZINE_NOT_PRESENT = 0xC0000286u32 as _,
-    STATUS_REINITIALIZATION_NEEDED = 0xC0000287u32 as _,
-    STATUS_DEVICE_REQUIRES_CLEANING = 0x80000288u32 as _,
-    STATUS_DEVICE_DOOR_OPEN = 0x80000289u32 as _,
-    STATUS_ENCRYPTION_FAILED = 0xC000028Au32 as _,
-    STATUS_DECRYPTION_FAILED = 0xC000028Bu32 as _,
-    STATUS_RANGE_NOT_FOUND = 0xC000028Cu32 as _,
-    STATUS_NO_RECOVERY_POLICY = 0xC000028Du32 as _,
-    STATUS_NO_EFS = 0xC000028Eu32 as _,
-    STATUS_WRONG_EFS = 0xC000028Fu32 as _,
-    STATUS_NO_USER_KEYS = 0xC0000290u32 as _,
-    STATUS_FILE_NOT_ENCRYPTED = 0xC0000291u32 as _,
-    STATUS_NOT_EXPORT_FORMAT = 0xC0000292u32 as _,
-    STATUS_FILE_ENCRYPTED = 0xC0000293u32 as _,
-    STATUS_WAKE_SYSTEM = 0x40000294u32 as _,
-    STATUS_WMI_GUID_NOT_FOUND = 0xC0000295u32 as _,
-    STATUS_WMI_INSTANCE_NOT_FOUND = 0xC0000296u32 as _,
-    STATUS_WMI_ITEMID_NOT_FOUND = 0xC0000297u32 as _,
-    STATUS_WMI_TRY_AGAIN = 0xC0000298u32 as _,
-    STATUS_SHARED_POLICY = 0xC0000299u32 as _,
-    STATUS_POLICY_OBJECT_NOT_FOUND = 0xC000029Au32 as _,
-    STATUS_POLICY_ONLY_IN_DS = 0xC000029Bu32 as _,
-    STATUS_VOLUME_NOT_UPGRADED = 0xC000029Cu32 as _,
-    STATUS_REMOTE_STORAGE_NOT_ACTIVE = 0xC000029Du32 as _,
-    STATUS_REMOTE_STORAGE_MEDIA_ERROR = 0xC000029Eu32 as _,
-    STATUS_NO_TRACKING_SERVICE = 0xC000029Fu32 as _,
-    STATUS_SERVER_SID_MISMATCH = 0xC00002A0u32 as _,
-    STATUS_DS_NO_ATTRIBUTE_OR_VALUE = 0xC00002A1u32 as _,
-    STATUS_DS_INVALID_ATTRIBUTE_SYNTAX = 0xC00002A2u32 as _,
-    STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED = 0xC00002A3u32 as _,
-    STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS = 0xC00002A4u32 as _,
-    STATUS_DS_BUSY = 0xC00002A5u32 as _,
-    STATUS_DS_UNAVAILABLE = 0xC00002A6u32 as _,
-    STATUS_DS_NO_RIDS_ALLOCATED = 0xC00002A7u32 as _,
-    STATUS_DS_NO_MORE_RIDS = 0xC00002A8u32 as _,
-    STATUS_DS_INCORRECT_ROLE_OWNER = 0xC00002A9u32 as _,
-    STATUS_DS_RIDMGR_INIT_ERROR = 0xC00002AAu32 as _,
-    STATUS_DS_OBJ_CLASS_VIOLATION = 0xC00002ABu32 as _,
-    STATUS_DS_CANT_ON_NON_LEAF = 0xC00002ACu32 as _,
-    STATUS_DS_CANT_ON_RDN = 0xC00002ADu32 as _,
-    STATUS_DS_CANT_MOD_OBJ_CLASS = 0xC00002AEu32 as _,
-    STATUS_DS_CROSS_DOM_MOVE_FAILED = 0xC00002AFu32 as _,
-    STATUS_DS_GC_NOT_AVAILABLE = 0xC00002B0u32 as _,
-    STATUS_DIRECTORY_SERVICE_REQUIRED = 0xC00002B1u32 as _,
-    STATUS_REPARSE_ATTRIBUTE_CONFLICT = 0xC00002B2u32 as _,
-    STATUS_CANT_ENABLE_DENY_ONLY = 0xC00002B3u32 as _,
-    STATUS_FLOAT_MULTIPLE_FAULTS = 0xC00002B4u32 as _,
-    STATUS_FLOAT_MULTIPLE_TRAPS = 0xC00002B5u32 as _,
-    STATUS_DEVICE_REMOVED = 0xC00002B6u32 as _,
-    STATUS_JOURNAL_DELETE_IN_PROGRESS = 0xC00002B7u32 as _,
-    STATUS_JOURNAL_NOT_ACTIVE = 0xC00002B8u32 as _,
-    STATUS_NOINTERFACE = 0xC00002B9u32 as _,
-    STATUS_DS_RIDMGR_DISABLED = 0xC00002BAu32 as _,
-    STATUS_DS_ADMIN_LIMIT_EXCEEDED = 0xC00002C1u32 as _,
-    STATUS_DRIVER_FAILED_SLEEP = 0xC00002C2u32 as _,
-    STATUS_MUTUAL_AUTHENTICATION_FAILED = 0xC00002C3u32 as _,
-    STATUS_CORRUPT_SYSTEM_FILE = 0xC00002C4u32 as _,
-    STATUS_DATATYPE_MISALIGNMENT_ERROR = 0xC00002C5u32 as _,
-    STATUS_WMI_READ_ONLY = 0xC00002C6u32 as _,
-    STATUS_WMI_SET_FAILURE = 0xC00002C7u32 as _,
-    STATUS_COMMITMENT_MINIMUM = 0xC00002C8u32 as _,
-    STATUS_REG_NAT_CONSUMPTION = 0xC00002C9u32 as _,
-    STATUS_TRANSPORT_FULL = 0xC00002CAu32 as _,
-    STATUS_DS_SAM_INIT_FAILURE = 0xC00002CBu32 as _,
-    STATUS_ONLY_IF_CONNECTED = 0xC00002CCu32 as _,
-    STATUS_DS_SENSITIVE_GROUP_VIOLATION = 0xC00002CDu32 as _,
-    STATUS_PNP_RESTART_ENUMERATION = 0xC00002CEu32 as _,
-    STATUS_JOURNAL_ENTRY_DELETED = 0xC00002CFu32 as _,
-    STATUS_DS_CANT_MOD_PRIMARYGROUPID = 0xC00002D0u32 as _,
-    STATUS_SYSTEM_IMAGE_BAD_SIGNATURE = 0xC00002D1u32 as _,
-    STATUS_PNP_REBOOT_REQUIRED = 0xC00002D2u32 as _,
-    STATUS_POWER_STATE_INVALID = 0xC00002D3u32 as _,
-    STATUS_DS_INVALID_GROUP_TYPE = 0xC00002D4u32 as _,
-    STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN = 0xC00002D5u32 as _,
-    STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN = 0xC00002D6u32 as _,
-    STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER = 0xC00002D7u32 as _,
-    STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER = 0xC00002D8u32 as _,
-    STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER = 0xC00002D9u32 as _,
-    STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER = 0xC00002DAu32 as _,
-    STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER = 0xC00002DBu32 as _,
-    STATUS_DS_HAVE_PRIMARY_MEMBERS = 0xC00002DCu32 as _,
-    STATUS_WMI_NOT_SUPPORTED = 0xC00002DDu32 as _,
-    STATUS_INSUFFICIENT_POWER = 0xC00002DEu32 as _,
-    STATUS_SAM_NEED_BOOTKEY_PASSWORD = 0xC00002DFu32 as _,
-    STATUS_SAM_NEED_BOOTKEY_FLOPPY = 0xC00002E0u32 as _,
-    STATUS_DS_CANT_START = 0xC00002E1u32 as _,
-    STATUS_DS_INIT_FAILURE = 0xC00002E2u32 as _,
-    STATUS_SAM_INIT_FAILURE = 0xC00002E3u32 as _,
-    STATUS_DS_GC_REQUIRED = 0xC00002E4u32 as _,
-    STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY = 0xC00002E5u32 as _,
-    STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS = 0xC00002E6u32 as _,
-    STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED = 0xC00002E7u32 as _,
-    STATUS_MULTIPLE_FAULT_VIOLATION = 0xC00002E8u32 as _,
-    STATUS_CURRENT_DOMAIN_NOT_ALLOWED = 0xC00002E9u32 as _,
-    STATUS_CANNOT_MAKE = 0xC00002EAu32 as _,
-    STATUS_SYSTEM_SHUTDOWN = 0xC00002EBu32 as _,
-    STATUS_DS_INIT_FAILURE_CONSOLE = 0xC00002ECu32 as _,
-    STATUS_DS_SAM_INIT_FAILURE_CONSOLE = 0xC00002EDu32 as _,
-    STATUS_UNFINISHED_CONTEXT_DELETED = 0xC00002EEu32 as _,
-    STATUS_NO_TGT_REPLY = 0xC00002EFu32 as _,
-    STATUS_OBJECTID_NOT_FOUND = 0xC00002F0u32 as _,
-    STATUS_NO_IP_ADDRESSES = 0xC00002F1u32 as _,
-    STATUS_WRONG_CREDENTIAL_HANDLE = 0xC00002F2u32 as _,
-    STATUS_CRYPTO_SYSTEM_INVALID = 0xC00002F3u32 as _,
-    STATUS_MAX_REFERRALS_EXCEEDED = 0xC00002F4u32 as _,
-    STATUS_MUST_BE_KDC = 0xC00002F5u32 as _,
-    STATUS_STRONG_CRYPTO_NOT_SUPPORTED = 0xC00002F6u32 as _,
-    STATUS_TOO_MANY_PRINCIPALS = 0xC00002F7u32 as _,
-    STATUS_NO_PA_DATA = 0xC00002F8u32 as _,
-    STATUS_PKINIT_NAME_MISMATCH = 0xC00002F9u32 as _,
-    STATUS_SMARTCARD_LOGON_REQUIRED = 0xC00002FAu32 as _,
-    STATUS_KDC_INVALID_REQUEST = 0xC00002FBu32 as _,
-    STATUS_KDC_UNABLE_TO_REFER = 0xC00002FCu32 as _,
-    STATUS_KDC_UNKNOWN_ETYPE = 0xC00002FDu32 as _,
-    STATUS_SHUTDOWN_IN_PROGRESS = 0xC00002FEu32 as _,
-    STATUS_SERVER_SHUTDOWN_IN_PROGRESS = 0xC00002FFu32 as _,
-    STATUS_NOT_SUPPORTED_ON_SBS = 0xC0000300u32 as _,
-    STATUS_WMI_GUID_DISCONNECTED = 0xC0000301u32 as _,
-    STATUS_WMI_ALREADY_DISABLED = 0xC0000302u32 as _,
-    STATUS_WMI_ALREADY_ENABLED = 0xC0000303u32 as _,
-    STATUS_MFT_TOO_FRAGMENTED = 0xC0000304u32 as _,
-    STATUS_COPY_PROTECTION_FAILURE = 0xC0000305u32 as _,
-    STATUS_CSS_AUTHENTICATION_FAILURE = 0xC0000306u32 as _,
-    STATUS_CSS_KEY_NOT_PRESENT = 0xC0000307u32 as _,
-    STATUS_CSS_KEY_NOT_ESTABLISHED = 0xC0000308u32 as _,
-    STATUS_CSS_SCRAMBLED_SECTOR = 0xC0000309u32 as _,
-    STATUS_CSS_REGION_MISMATCH = 0xC000030Au32 as _,
-    STATUS_CSS_RESETS_EXHAUSTED = 0xC000030Bu32 as _,
-    STATUS_PASSWORD_CHANGE_REQUIRED = 0xC000030Cu32 as _,
-    STATUS_PKINIT_FAILURE = 0xC0000320u32 as _,
-    STATUS_SMARTCARD_SUBSYSTEM_FAILURE = 0xC0000321u32 as _,
-    STATUS_NO_KERB_KEY = 0xC0000322u32 as _,
-    STATUS_HOST_DOWN = 0xC0000350u32 as _,
-    STATUS_UNSUPPORTED_PREAUTH = 0xC0000351u32 as _,
-    STATUS_EFS_ALG_BLOB_TOO_BIG = 0xC0000352u32 as _,
-    STATUS_PORT_NOT_SET = 0xC0000353u32 as _,
-    STATUS_DEBUGGER_INACTIVE = 0xC0000354u32 as _,
-    STATUS_DS_VERSION_CHECK_FAILURE = 0xC0000355u32 as _,
-    STATUS_AUDITING_DISABLED = 0xC0000356u32 as _,
-    STATUS_PRENT4_MACHINE_ACCOUNT = 0xC0000357u32 as _,
-    STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER = 0xC0000358u32 as _,
-    STATUS_INVALID_IMAGE_WIN_32 = 0xC0000359u32 as _,
-    STATUS_INVALID_IMAGE_WIN_64 = 0xC000035Au32 as _,
-    STATUS_BAD_BINDINGS = 0xC000035Bu32 as _,
-    STATUS_NETWORK_SESSION_EXPIRED = 0xC000035Cu32 as _,
-    STATUS_APPHELP_BLOCK = 0xC000035Du32 as _,
-    STATUS_ALL_SIDS_FILTERED = 0xC000035Eu32 as _,
-    STATUS_NOT_SAFE_MODE_DRIVER = 0xC000035Fu32 as _,
-    STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT = 0xC0000361u32 as _,
-    STATUS_ACCESS_DISABLED_BY_POLICY_PATH = 0xC0000362u32 as _,
-    STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER = 0xC0000363u32 as _,
-    STATUS_ACCESS_DISABLED_BY_POLICY_OTHER = 0xC0000364u32 as _,
-    STATUS_FAILED_DRIVER_ENTRY = 0xC0000365u32 as _,
-    STATUS_DEVICE_ENUMERATION_ERROR = 0xC0000366u32 as _,
-    STATUS_MOUNT_POINT_NOT_RESOLVED = 0xC0000368u32 as _,
-    STATUS_INVALID_DEVICE_OBJECT_PARAMETER = 0xC0000369u32 as _,
-    STATUS_MCA_OCCURED = 0xC000036Au32 as _,
-    STATUS_DRIVER_BLOCKED_CRITICAL = 0xC000036Bu32 as _,
-    STATUS_DRIVER_BLOCKED = 0xC000036Cu32 as _,
-    STATUS_DRIVER_DATABASE_ERROR = 0xC000036Du32 as _,
-    STATUS_SYSTEM_HIVE_TOO_LARGE = 0xC000036Eu32 as _,
-    STATUS_INVALID_IMPORT_OF_NON_DLL = 0xC000036Fu32 as _,
-    STATUS_DS_SHUTTING_DOWN = 0x40000370u32 as _,
-    STATUS_NO_SECRETS = 0xC0000371u32 as _,
-    STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY = 0xC0000372u32 as _,
-    STATUS_FAILED_STACK_SWITCH = 0xC0000373u32 as _,
-    STATUS_HEAP_CORRUPTION = 0xC0000374u32 as _,
-    STATUS_SMARTCARD_WRONG_PIN = 0xC0000380u32 as _,
-    STATUS_SMARTCARD_CARD_BLOCKED = 0xC0000381u32 as _,
-    STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED = 0xC0000382u32 as _,
-    STATUS_SMARTCARD_NO_CARD = 0xC0000383u32 as _,
-    STATUS_SMARTCARD_NO_KEY_CONTAINER = 0xC0000384u32 as _,
-    STATUS_SMARTCARD_NO_CERTIFICATE = 0xC0000385u32 as _,
-    STATUS_SMARTCARD_NO_KEYSET = 0xC0000386u32 as _,
-    STATUS_SMARTCARD_IO_ERROR = 0xC0000387u32 as _,
-    STATUS_DOWNGRADE_DETECTED = 0xC0000388u32 as _,
-    STATUS_SMARTCARD_CERT_REVOKED = 0xC0000389u32 as _,
-    STATUS_ISSUING_CA_UNTRUSTED = 0xC000038Au32 as _,
-    STATUS_REVOCATION_OFFLINE_C = 0xC000038Bu32 as _,
-    STATUS_PKINIT_CLIENT_FAILURE = 0xC000038Cu32 as _,
-    STATUS_SMARTCARD_CERT_EXPIRED = 0xC000038Du32 as _,
-    STATUS_DRIVER_FAILED_PRIOR_UNLOAD = 0xC000038Eu32 as _,
-    STATUS_SMARTCARD_SILENT_CONTEXT = 0xC000038Fu32 as _,
-    STATUS_PER_USER_TRUST_QUOTA_EXCEEDED = 0xC0000401u32 as _,
-    STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED = 0xC0000402u32 as _,
-    STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED = 0xC0000403u32 as _,
-    STATUS_DS_NAME_NOT_UNIQUE = 0xC0000404u32 as _,
-    STATUS_DS_DUPLICATE_ID_FOUND = 0xC0000405u32 as _,
-    STATUS_DS_GROUP_CONVERSION_ERROR = 0xC0000406u32 as _,
-    STATUS_VOLSNAP_PREPARE_HIBERNATE = 0xC0000407u32 as _,
-    STATUS_USER2USER_REQUIRED = 0xC0000408u32 as _,
-    STATUS_STACK_BUFFER_OVERRUN = 0xC0000409u32 as _,
-    STATUS_NO_S4U_PROT_SUPPORT = 0xC000040Au32 as _,
-    STATUS_CROSSREALM_DELEGATION_FAILURE = 0xC000040Bu32 as _,
-    STATUS_REVOCATION_OFFLINE_KDC = 0xC000040Cu32 as _,
-    STATUS_ISSUING_CA_UNTRUSTED_KDC = 0xC000040Du32 as _,
-    STATUS_KDC_CERT_EXPIRED = 0xC000040Eu32 as _,
-    STATUS_KDC_CERT_REVOKED = 0xC000040Fu32 as _,
-    STATUS_PARAMETER_QUOTA_EXCEEDED = 0xC0000410u32 as _,
-    STATUS_HIBERNATION_FAILURE = 0xC0000411u32 as _,
-    STATUS_DELAY_LOAD_FAILED = 0xC0000412u32 as _,
-    STATUS_AUTHENTICATION_FIREWALL_FAILED = 0xC0000413u32 as _,
-    STATUS_VDM_DISALLOWED = 0xC0000414u32 as _,
-    STATUS_HUNG_DISPLAY_DRIVER_THREAD = 0xC0000415u32 as _,
-    STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE = 0xC0000416u32 as _,
-    STATUS_INVALID_CRUNTIME_PARAMETER = 0xC0000417u32 as _,
-    STATUS_NTLM_BLOCKED = 0xC0000418u32 as _,
-    STATUS_DS_SRC_SID_EXISTS_IN_FOREST = 0xC0000419u32 as _,
-    STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST = 0xC000041Au32 as _,
-    STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST = 0xC000041Bu32 as _,
-    STATUS_INVALID_USER_PRINCIPAL_NAME = 0xC000041Cu32 as _,
-    STATUS_FATAL_USER_CALLBACK_EXCEPTION = 0xC000041Du32 as _,
-    STATUS_ASSERTION_FAILURE = 0xC0000420u32 as _,
-    STATUS_VERIFIER_STOP = 0xC0000421u32 as _,
-    STATUS_CALLBACK_POP_STACK = 0xC0000423u32 as _,
-    STATUS_INCOMPATIBLE_DRIVER_BLOCKED = 0xC0000424u32 as _,
-    STATUS_HIVE_UNLOADED = 0xC0000425u32 as _,
-    STATUS_COMPRESSION_DISABLED = 0xC0000426u32 as _,
-    STATUS_FILE_SYSTEM_LIMITATION = 0xC0000427u32 as _,
-    STATUS_INVALID_IMAGE_HASH = 0xC0000428u32 as _,
-    STATUS_NOT_CAPABLE = 0xC0000429u32 as _,
-    STATUS_REQUEST_OUT_OF_SEQUENCE = 0xC000042Au32 as _,
-    STATUS_IMPLEMENTATION_LIMIT = 0xC000042Bu32 as _,
-    STATUS_ELEVATION_REQUIRED = 0xC000042Cu32 as _,
-    STATUS_NO_SECURITY_CONTEXT = 0xC000042Du32 as _,
-    STATUS_PKU2U_CERT_FAILURE = 0xC000042Fu32 as _,
-    STATUS_BEYOND_VDL = 0xC0000432u32 as _,
-    STATUS_ENCOUNTERED_WRITE_IN_PROGRESS = 0xC0000433u32 as _,
-    STATUS_PTE_CHANGED = 0xC0000434u32 as _,
-    STATUS_PURGE_FAILED = 0xC0000435u32 as _,
-    STATUS_CRED_REQUIRES_CONFIRMATION = 0xC0000440u32 as _,
-    STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE = 0xC0000441u32 as _,
-    STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER = 0xC0000442u32 as _,
-    STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE = 0xC0000443u32 as _,
-    STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE = 0xC0000444u32 as _,
-    STATUS_CS_ENCRYPTION_FILE_NOT_CSE = 0xC0000445u32 as _,
-    STATUS_INVALID_LABEL = 0xC0000446u32 as _,
-    STATUS_DRIVER_PROCESS_TERMINATED = 0xC0000450u32 as _,
-    STATUS_AMBIGUOUS_SYSTEM_DEVICE = 0xC0000451u32 as _,
-    STATUS_SYSTEM_DEVICE_NOT_FOUND = 0xC0000452u32 as _,
-    STATUS_RESTART_BOOT_APPLICATION = 0xC0000453u32 as _,
-    STATUS_INSUFFICIENT_NVRAM_RESOURCES = 0xC0000454u32 as _,
-    STATUS_INVALID_SESSION = 0xC0000455u32 as _,
-    STATUS_THREAD_ALREADY_IN_SESSION = 0xC0000456u32 as _,
-    STATUS_THREAD_NOT_IN_SESSION = 0xC0000457u32 as _,
-    STATUS_INVALID_WEIGHT = 0xC0000458u32 as _,
-    STATUS_REQUEST_PAUSED = 0xC0000459u32 as _,
-    STATUS_NO_RANGES_PROCESSED = 0xC0000460u32 as _,
-    STATUS_DISK_RESOURCES_EXHAUSTED = 0xC0000461u32 as _,
-    STATUS_NEEDS_REMEDIATION = 0xC0000462u32 as _,
-    STATUS_DEVICE_FEATURE_NOT_SUPPORTED = 0xC0000463u32 as _,
-    STATUS_DEVICE_UNREACHABLE = 0xC0000464u32 as _,
-    STATUS_INVALID_TOKEN = 0xC0000465u32 as _,
-    STATUS_SERVER_UNAVAILABLE = 0xC0000466u32 as _,
-    STATUS_FILE_NOT_AVAILABLE = 0xC0000467u32 as _,
-    STATUS_DEVICE_INSUFFICIENT_RESOURCES = 0xC0000468u32 as _,
-    STATUS_PACKAGE_UPDATING = 0xC0000469u32 as _,
-    STATUS_NOT_READ_FROM_COPY = 0xC000046Au32 as _,
-    STATUS_FT_WRITE_FAILURE = 0xC000046Bu32 as _,
-    STATUS_FT_DI_SCAN_REQUIRED = 0xC000046Cu32 as _,
-    STATUS_OBJECT_NOT_EXTERNALLY_BACKED = 0xC000046Du32 as _,
-    STATUS_EXTERNAL_BACKING_PROVIDER_UNKNOWN = 0xC000046Eu32 as _,
-    STATUS_COMPRESSION_NOT_BENEFICIAL = 0xC000046Fu32 as _,
-    STATUS_DATA_CHECKSUM_ERROR = 0xC0000470u32 as _,
-    STATUS_INTERMIXED_KERNEL_EA_OPERATION = 0xC0000471u32 as _,
-    STATUS_TRIM_READ_ZERO_NOT_SUPPORTED = 0xC0000472u32 as _,
-    STATUS_TOO_MANY_SEGMENT_DESCRIPTORS = 0xC0000473u32 as _,
-    STATUS_INVALID_OFFSET_ALIGNMENT = 0xC0000474u32 as _,
-    STATUS_INVALID_FIELD_IN_PARAMETER_LIST = 0xC0000475u32 as _,
-    STATUS_OPERATION_IN_PROGRESS = 0xC0000476u32 as _,
-    STATUS_INVALID_INITIATOR_TARGET_PATH = 0xC0000477u32 as _,
-    STATUS_SCRUB_DATA_DISABLED = 0xC0000478u32 as _,
-    STATUS_NOT_REDUNDANT_STORAGE = 0xC0000479u32 as _,
-    STATUS_RESIDENT_FILE_NOT_SUPPORTED = 0xC000047Au32 as _,
-    STATUS_COMPRESSED_FILE_NOT_SUPPORTED = 0xC000047Bu32 as _,
-    STATUS_DIRECTORY_NOT_SUPPORTED = 0xC000047Cu32 as _,
-    STATUS_IO_OPERATION_TIMEOUT = 0xC000047Du32 as _,
-    STATUS_SYSTEM_NEEDS_REMEDIATION = 0xC000047Eu32 as _,
-    STATUS_APPX_INTEGRITY_FAILURE_CLR_NGEN = 0xC000047Fu32 as _,
-    STATUS_SHARE_UNAVAILABLE = 0xC0000480u32 as _,
-    STATUS_APISET_NOT_HOSTED = 0xC0000481u32 as _,
-    STATUS_APISET_NOT_PRESENT = 0xC0000482u32 as _,
-    STATUS_DEVICE_HARDWARE_ERROR = 0xC0000483u32 as _,
-    STATUS_FIRMWARE_SLOT_INVALID = 0xC0000484u32 as _,
-    STATUS_FIRMWARE_IMAGE_INVALID = 0xC0000485u32 as _,
-    STATUS_STORAGE_TOPOLOGY_ID_MISMATCH = 0xC0000486u32 as _,
-    STATUS_WIM_NOT_BOOTABLE = 0xC0000487u32 as _,
-    STATUS_BLOCKED_BY_PARENTAL_CONTROLS = 0xC0000488u32 as _,
-    STATUS_NEEDS_REGISTRATION = 0xC0000489u32 as _,
-    STATUS_QUOTA_ACTIVITY = 0xC000048Au32 as _,
-    STATUS_CALLBACK_INVOKE_INLINE = 0xC000048Bu32 as _,
-    STATUS_BLOCK_TOO_MANY_REFERENCES = 0xC000048Cu32 as _,
-    STATUS_MARKED_TO_DISALLOW_WRITES = 0xC000048Du32 as _,
-    STATUS_NETWORK_ACCESS_DENIED_EDP = 0xC000048Eu32 as _,
-    STATUS_ENCLAVE_FAILURE = 0xC000048Fu32 as _,
-    STATUS_PNP_NO_COMPAT_DRIVERS = 0xC0000490u32 as _,
-    STATUS_PNP_DRIVER_PACKAGE_NOT_FOUND = 0xC0000491u32 as _,
-    STATUS_PNP_DRIVER_CONFIGURATION_NOT_FOUND = 0xC0000492u32 as _,
-    STATUS_PNP_DRIVER_CONFIGURATION_INCOMPLETE = 0xC0000493u32 as _,
-    STATUS_PNP_FUNCTION_DRIVER_REQUIRED = 0xC0000494u32 as _,
-    STATUS_PNP_DEVICE_CONFIGURATION_PENDING = 0xC0000495u32 as _,
-    STATUS_DEVICE_HINT_NAME_BUFFER_TOO_SMALL = 0xC0000496u32 as _,
-    STATUS_PACKAGE_NOT_AVAILABLE = 0xC0000497u32 as _,
-    STATUS_DEVICE_IN_MAINTENANCE = 0xC0000499u32 as _,
-    STATUS_NOT_SUPPORTED_ON_DAX = 0xC000049Au32 as _,
-    STATUS_FREE_SPACE_TOO_FRAGMENTED = 0xC000049Bu32 as _,
-    STATUS_DAX_MAPPING_EXISTS = 0xC000049Cu32 as _,
-    STATUS_CHILD_PROCESS_BLOCKED = 0xC000049Du32 as _,
-    STATUS_STORAGE_LOST_DATA_PERSISTENCE = 0xC000049Eu32 as _,
-    STATUS_INVALID_TASK_NAME = 0xC0000500u32 as _,
-    STATUS_INVALID_TASK_INDEX = 0xC0000501u32 as _,
-    STATUS_THREAD_ALREADY_IN_TASK = 0xC0000502u32 as _,
-    STATUS_CALLBACK_BYPASS = 0xC0000503u32 as _,
-    STATUS_UNDEFINED_SCOPE = 0xC0000504u32 as _,
-    STATUS_INVALID_CAP = 0xC0000505u32 as _,
-    STATUS_NOT_GUI_PROCESS = 0xC0000506u32 as _,
-    STATUS_DEVICE_HUNG = 0xC0000507u32 as _,
-    STATUS_CONTAINER_ASSIGNED = 0xC0000508u32 as _,
-    STATUS_JOB_NO_CONTAINER = 0xC0000509u32 as _,
-    STATUS_DEVICE_UNRESPONSIVE = 0xC000050Au32 as _,
-    STATUS_REPARSE_POINT_ENCOUNTERED = 0xC000050Bu32 as _,
-    STATUS_FAIL_FAST_EXCEPTION = 0xC0000602u32 as _,
-    STATUS_IMAGE_CERT_REVOKED = 0xC0000603u32 as _,
-    STATUS_DYNAMIC_CODE_BLOCKED = 0xC0000604u32 as _,
-    STATUS_IMAGE_CERT_EXPIRED = 0xC0000605u32 as _,
-    STATUS_PORT_CLOSED = 0xC0000700u32 as _,
-    STATUS_MESSAGE_LOST = 0xC0000701u32 as _,
-    STATUS_INVALID_MESSAGE = 0xC0000702u32 as _,
-    STATUS_REQUEST_CANCELED = 0xC0000703u32 as _,
-    STATUS_RECURSIVE_DISPATCH = 0xC0000704u32 as _,
-    STATUS_LPC_RECEIVE_BUFFER_EXPECTED = 0xC0000705u32 as _,
-    STATUS_LPC_INVALID_CONNECTION_USAGE = 0xC0000706u32 as _,
-    STATUS_LPC_REQUESTS_NOT_ALLOWED = 0xC0000707u32 as _,
-    STATUS_RESOURCE_IN_USE = 0xC0000708u32 as _,
-    STATUS_HARDWARE_MEMORY_ERROR = 0xC0000709u32 as _,
-    STATUS_THREADPOOL_HANDLE_EXCEPTION = 0xC000070Au32 as _,
-    STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED = 0xC000070Bu32 as _,
-    STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED = 0xC000070Cu32 as _,
-    STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED = 0xC000070Du32 as _,
-    STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED = 0xC000070Eu32 as _,
-    STATUS_THREADPOOL_RELEASED_DURING_OPERATION = 0xC000070Fu32 as _,
-    STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING = 0xC0000710u32 as _,
-    STATUS_APC_RETURNED_WHILE_IMPERSONATING = 0xC0000711u32 as _,
-    STATUS_PROCESS_IS_PROTECTED = 0xC0000712u32 as _,
-    STATUS_MCA_EXCEPTION = 0xC0000713u32 as _,
-    STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE = 0xC0000714u32 as _,
-    STATUS_SYMLINK_CLASS_DISABLED = 0xC0000715u32 as _,
-    STATUS_INVALID_IDN_NORMALIZATION = 0xC0000716u32 as _,
-    STATUS_NO_UNICODE_TRANSLATION = 0xC0000717u32 as _,
-    STATUS_ALREADY_REGISTERED = 0xC0000718u32 as _,
-    STATUS_CONTEXT_MISMATCH = 0xC0000719u32 as _,
-    STATUS_PORT_ALREADY_HAS_COMPLETION_LIST = 0xC000071Au32 as _,
-    STATUS_CALLBACK_RETURNED_THREAD_PRIORITY = 0xC000071Bu32 as _,
-    STATUS_INVALID_THREAD = 0xC000071Cu32 as _,
-    STATUS_CALLBACK_RETURNED_TRANSACTION = 0xC000071Du32 as _,
-    STATUS_CALLBACK_RETURNED_LDR_LOCK = 0xC000071Eu32 as _,
-    STATUS_CALLBACK_RETURNED_LANG = 0xC000071Fu32 as _,
-    STATUS_CALLBACK_RETURNED_PRI_BACK = 0xC0000720u32 as _,
-    STATUS_CALLBACK_RETURNED_THREAD_AFFINITY = 0xC0000721u32 as _,
-    STATUS_LPC_HANDLE_COUNT_EXCEEDED = 0xC0000722u32 as _,
-    STATUS_DISK_REPAIR_DISABLED = 0xC0000800u32 as _,
-    STATUS_DS_DOMAIN_RENAME_IN_PROGRESS = 0xC0000801u32 as _,
-    STATUS_DISK_QUOTA_EXCEEDED = 0xC0000802u32 as _,
-    STATUS_DATA_LOST_REPAIR = 0x80000803u32 as _,
-    STATUS_CONTENT_BLOCKED = 0xC0000804u32 as _,
-    STATUS_BAD_CLUSTERS = 0xC0000805u32 as _,
-    STATUS_VOLUME_DIRTY = 0xC0000806u32 as _,
-    STATUS_DISK_REPAIR_REDIRECTED = 0x40000807u32 as _,
-    STATUS_DISK_REPAIR_UNSUCCESSFUL = 0xC0000808u32 as _,
-    STATUS_CORRUPT_LOG_OVERFULL = 0xC0000809u32 as _,
-    STATUS_CORRUPT_LOG_CORRUPTED = 0xC000080Au32 as _,
-    STATUS_CORRUPT_LOG_UNAVAILABLE = 0xC000080Bu32 as _,
-    STATUS_CORRUPT_LOG_DELETED_FULL = 0xC000080Cu32 as _,
-    STATUS_CORRUPT_LOG_CLEARED = 0xC000080Du32 as _,
-    STATUS_ORPHAN_NAME_EXHAUSTED = 0xC000080Eu32 as _,
-    STATUS_PROACTIVE_SCAN_IN_PROGRESS = 0xC000080Fu32 as _,
-    STATUS_ENCRYPTED_IO_NOT_POSSIBLE = 0xC0000810u32 as _,
-    STATUS_CORRUPT_LOG_UPLEVEL_RECORDS = 0xC0000811u32 as _,
-    STATUS_FILE_CHECKED_OUT = 0xC0000901u32 as _,
-    STATUS_CHECKOUT_REQUIRED = 0xC0000902u32 as _,
-    STATUS_BAD_FILE_TYPE = 0xC0000903u32 as _,
-    STATUS_FILE_TOO_LARGE = 0xC0000904u32 as _,
-    STATUS_FORMS_AUTH_REQUIRED = 0xC0000905u32 as _,
-    STATUS_VIRUS_INFECTED = 0xC0000906u32 as _,
-    STATUS_VIRUS_DELETED = 0xC0000907u32 as _,
-    STATUS_BAD_MCFG_TABLE = 0xC0000908u32 as _,
-    STATUS_CANNOT_BREAK_OPLOCK = 0xC0000909u32 as _,
-    STATUS_BAD_KEY = 0xC000090Au32 as _,
-    STATUS_BAD_DATA = 0xC000090Bu32 as _,
-    STATUS_NO_KEY = 0xC000090Cu32 as _,
-    STATUS_FILE_HANDLE_REVOKED = 0xC0000910u32 as _,
-    STATUS_WOW_ASSERTION = 0xC0009898u32 as _,
-    STATUS_INVALID_SIGNATURE = 0xC000A000u32 as _,
-    STATUS_HMAC_NOT_SUPPORTED = 0xC000A001u32 as _,
-    STATUS_AUTH_TAG_MISMATCH = 0xC000A002u32 as _,
-    STATUS_INVALID_STATE_TRANSITION = 0xC000A003u32 as _,
-    STATUS_INVALID_KERNEL_INFO_VERSION = 0xC000A004u32 as _,
-    STATUS_INVALID_PEP_INFO_VERSION = 0xC000A005u32 as _,
-    STATUS_HANDLE_REVOKED = 0xC000A006u32 as _,
-    STATUS_EOF_ON_GHOSTED_RANGE = 0xC000A007u32 as _,
-    STATUS_IPSEC_QUEUE_OVERFLOW = 0xC000A010u32 as _,
-    STATUS_ND_QUEUE_OVERFLOW = 0xC000A011u32 as _,
-    STATUS_HOPLIMIT_EXCEEDED = 0xC000A012u32 as _,
-    STATUS_PROTOCOL_NOT_SUPPORTED = 0xC000A013u32 as _,
-    STATUS_FASTPATH_REJECTED = 0xC000A014u32 as _,
-    STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED = 0xC000A080u32 as _,
-    STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR = 0xC000A081u32 as _,
-    STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR = 0xC000A082u32 as _,
-    STATUS_XML_PARSE_ERROR = 0xC000A083u32 as _,
-    STATUS_XMLDSIG_ERROR = 0xC000A084u32 as _,
-    STATUS_WRONG_COMPARTMENT = 0xC000A085u32 as _,
-    STATUS_AUTHIP_FAILURE = 0xC000A086u32 as _,
-    STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS = 0xC000A087u32 as _,
-    STATUS_DS_OID_NOT_FOUND = 0xC000A088u32 as _,
-    STATUS_INCORRECT_ACCOUNT_TYPE = 0xC000A089u32 as _,
-    STATUS_HASH_NOT_SUPPORTED = 0xC000A100u32 as _,
-    STATUS_HASH_NOT_PRESENT = 0xC000A101u32 as _,
-    STATUS_SECONDARY_IC_PROVIDER_NOT_REGISTERED = 0xC000A121u32 as _,
-    STATUS_GPIO_CLIENT_INFORMATION_INVALID = 0xC000A122u32 as _,
-    STATUS_GPIO_VERSION_NOT_SUPPORTED = 0xC000A123u32 as _,
-    STATUS_GPIO_INVALID_REGISTRATION_PACKET = 0xC000A124u32 as _,
-    STATUS_GPIO_OPERATION_DENIED = 0xC000A125u32 as _,
-    STATUS_GPIO_INCOMPATIBLE_CONNECT_MODE = 0xC000A126u32 as _,
-    STATUS_GPIO_INTERRUPT_ALREADY_UNMASKED = 0x8000A127u32 as _,
-    STATUS_CANNOT_SWITCH_RUNLEVEL = 0xC000A141u32 as _,
-    STATUS_INVALID_RUNLEVEL_SETTING = 0xC000A142u32 as _,
-    STATUS_RUNLEVEL_SWITCH_TIMEOUT = 0xC000A143u32 as _,
-    STATUS_SERVICES_FAILED_AUTOSTART = 0x4000A144u32 as _,
-    STATUS_RUNLEVEL_SWITCH_AGENT_TIMEOUT = 0xC000A145u32 as _,
-    STATUS_RUNLEVEL_SWITCH_IN_PROGRESS = 0xC000A146u32 as _,
-    STATUS_NOT_APPCONTAINER = 0xC000A200u32 as _,
-    STATUS_NOT_SUPPORTED_IN_APPCONTAINER = 0xC000A201u32 as _,
-    STATUS_INVALID_PACKAGE_SID_LENGTH = 0xC000A202u32 as _,
-    STATUS_APP_DATA_NOT_FOUND = 0xC000A281u32 as _,
-    STATUS_APP_DATA_EXPIRED = 0xC000A282u32 as _,
-    STATUS_APP_DATA_CORRUPT = 0xC000A283u32 as _,
-    STATUS_APP_DATA_LIMIT_EXCEEDED = 0xC000A284u32 as _,
-    STATUS_APP_DATA_REBOOT_REQUIRED = 0xC000A285u32 as _,
-    STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED = 0xC000A2A1u32 as _,
-    STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED = 0xC000A2A2u32 as _,
-    STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED = 0xC000A2A3u32 as _,
-    STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED = 0xC000A2A4u32 as _,
-    STATUS_CLOUD_FILE_PROVIDER_UNKNOWN = 0xC000CF00u32 as _,
-    STATUS_CLOUD_FILE_PROVIDER_NOT_RUNNING = 0xC000CF01u32 as _,
-    STATUS_CLOUD_FILE_METADATA_CORRUPT = 0xC000CF02u32 as _,
-    STATUS_CLOUD_FILE_METADATA_TOO_LARGE = 0xC000CF03u32 as _,
-    STATUS_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE = 0x8000CF04u32 as _,
-    DBG_NO_STATE_CHANGE = 0xC0010001u32 as _,
-    DBG_APP_NOT_IDLE = 0xC0010002u32 as _,
-    RPC_NT_INVALID_STRING_BINDING = 0xC0020001u32 as _,
-    RPC_NT_WRONG_KIND_OF_BINDING = 0xC0020002u32 as _,
-    RPC_NT_INVALID_BINDING = 0xC0020003u32 as _,
-    RPC_NT_PROTSEQ_NOT_SUPPORTED = 0xC0020004u32 as _,
-    RPC_NT_INVALID_RPC_PROTSEQ = 0xC0020005u32 as _,
-    RPC_NT_INVALID_STRING_UUID = 0xC0020006u32 as _,
-    RPC_NT_INVALID_ENDPOINT_FORMAT = 0xC0020007u32 as _,
-    RPC_NT_INVALID_NET_ADDR = 0xC0020008u32 as _,
-    RPC_NT_NO_ENDPOINT_FOUND = 0xC0020009u32 as _,
-    RPC_NT_INVALID_TIMEOUT = 0xC002000Au32 as _,
-    RPC_NT_OBJECT_NOT_FOUND = 0xC002000Bu32 as _,
-    RPC_NT_ALREADY_REGISTERED = 0xC002000Cu32 as _,
-    RPC_NT_TYPE_ALREADY_REGISTERED = 0xC002000Du32 as _,
-    RPC_NT_ALREADY_LISTENING = 0xC002000Eu32 as _,
-    RPC_NT_NO_PROTSEQS_REGISTERED = 0xC002000Fu32 as _,
-    RPC_NT_NOT_LISTENING = 0xC0020010u32 as _,
-    RPC_NT_UNKNOWN_MGR_TYPE = 0xC0020011u32 as _,
-    RPC_NT_UNKNOWN_IF = 0xC0020012u32 as _,
-    RPC_NT_NO_BINDINGS = 0xC0020013u32 as _,
-    RPC_NT_NO_PROTSEQS = 0xC0020014u32 as _,
-    RPC_NT_CANT_CREATE_ENDPOINT = 0xC0020015u32 as _,
-    RPC_NT_OUT_OF_RESOURCES = 0xC0020016u32 as _,
-    RPC_NT_SERVER_UNAVAILABLE = 0xC0020017u32 as _,
-    RPC_NT_SERVER_TOO_BUSY = 0xC0020018u32 as _,
-    RPC_NT_INVALID_NETWORK_OPTIONS = 0xC0020019u32 as _,
-    RPC_NT_NO_CALL_ACTIVE = 0xC002001Au32 as _,
-    RPC_NT_CALL_FAILED = 0xC002001Bu32 as _,
-    RPC_NT_CALL_FAILED_DNE = 0xC002001Cu32 as _,
-    RPC_NT_PROTOCOL_ERROR = 0xC002001Du32 as _,
-    RPC_NT_UNSUPPORTED_TRANS_SYN = 0xC002001Fu32 as _,
-    RPC_NT_UNSUPPORTED_TYPE = 0xC0020021u32 as _,
-    RPC_NT_INVALID_TAG = 0xC0020022u32 as _,
-    RPC_NT_INVALID_BOUND = 0xC0020023u32 as _,
-    RPC_NT_NO_ENTRY_NAME = 0xC0020024u32 as _,
-    RPC_NT_INVALID_NAME_SYNTAX = 0xC0020025u32 as _,
-    RPC_NT_UNSUPPORTED_NAME_SYNTAX = 0xC0020026u32 as _,
-    RPC_NT_UUID_NO_ADDRESS = 0xC0020028u32 as _,
-    RPC_NT_DUPLICATE_ENDPOINT = 0xC0020029u32 as _,
-    RPC_NT_UNKNOWN_AUTHN_TYPE = 0xC002002Au32 as _,
-    RPC_NT_MAX_CALLS_TOO_SMALL = 0xC002002Bu32 as _,
-    RPC_NT_STRING_TOO_LONG = 0xC002002Cu32 as _,
-    RPC_NT_PROTSEQ_NOT_FOUND = 0xC002002Du32 as _,
-    RPC_NT_PROCNUM_OUT_OF_RANGE = 0xC002002Eu32 as _,
-    RPC_NT_BINDING_HAS_NO_AUTH = 0xC002002Fu32 as _,
-    RPC_NT_UNKNOWN_AUTHN_SERVICE = 0xC0020030u32 as _,
-    RPC_NT_UNKNOWN_AUTHN_LEVEL = 0xC0020031u32 as _,
-    RPC_NT_INVALID_AUTH_IDENTITY = 0xC0020032u32 as _,
-    RPC_NT_UNKNOWN_AUTHZ_SERVICE = 0xC0020033u32 as _,
-    EPT_NT_INVALID_ENTRY = 0xC0020034u32 as _,
-    EPT_NT_CANT_PERFORM_OP = 0xC0020035u32 as _,
-    EPT_NT_NOT_REGISTERED = 0xC0020036u32 as _,
-    RPC_NT_NOTHING_TO_EXPORT = 0xC0020037u32 as _,
-    RPC_NT_INCOMPLETE_NAME = 0xC0020038u32 as _,
-    RPC_NT_INVALID_VERS_OPTION = 0xC0020039u32 as _,
-    RPC_NT_NO_MORE_MEMBERS = 0xC002003Au32 as _,
-    RPC_NT_NOT_ALL_OBJS_UNEXPORTED = 0xC002003Bu32 as _,
-    RPC_NT_INTERFACE_NOT_FOUND = 0xC002003Cu32 as _,
-    RPC_NT_ENTRY_ALREADY_EXISTS = 0xC002003Du32 as _,
-    RPC_NT_ENTRY_NOT_FOUND = 0xC002003Eu32 as _,
-    RPC_NT_NAME_SERVICE_UNAVAILABLE = 0xC002003Fu32 as _,
-    RPC_NT_INVALID_NAF_ID = 0xC0020040u32 as _,
-    RPC_NT_CANNOT_SUPPORT = 0xC0020041u32 as _,
-    RPC_NT_NO_CONTEXT_AVAILABLE = 0xC0020042u32 as _,
-    RPC_NT_INTERNAL_ERROR = 0xC0020043u32 as _,
-    RPC_NT_ZERO_DIVIDE = 0xC0020044u32 as _,
-    RPC_NT_ADDRESS_ERROR = 0xC0020045u32 as _,
-    RPC_NT_FP_DIV_ZERO = 0xC0020046u32 as _,
-    RPC_NT_FP_UNDERFLOW = 0xC0020047u32 as _,
-    RPC_NT_FP_OVERFLOW = 0xC0020048u32 as _,
-    RPC_NT_NO_MORE_ENTRIES = 0xC0030001u32 as _,
-    RPC_NT_SS_CHAR_TRANS_OPEN_FAIL = 0xC0030002u32 as _,
-    RPC_NT_SS_CHAR_TRANS_SHORT_FILE = 0xC0030003u32 as _,
-    RPC_NT_SS_IN_NULL_CONTEXT = 0xC0030004u32 as _,
-    RPC_NT_SS_CONTEXT_MISMATCH = 0xC0030005u32 as _,
-    RPC_NT_SS_CONTEXT_DAMAGED = 0xC0030006u32 as _,
-    RPC_NT_SS_HANDLES_MISMATCH = 0xC0030007u32 as _,
-    RPC_NT_SS_CANNOT_GET_CALL_HANDLE = 0xC0030008u32 as _,
-    RPC_NT_NULL_REF_POINTER = 0xC0030009u32 as _,
-    RPC_NT_ENUM_VALUE_OUT_OF_RANGE = 0xC003000Au32 as _,
-    RPC_NT_BYTE_COUNT_TOO_SMALL = 0xC003000Bu32 as _,
-    RPC_NT_BAD_STUB_DATA = 0xC003000Cu32 as _,
-    RPC_NT_CALL_IN_PROGRESS = 0xC0020049u32 as _,
-    RPC_NT_NO_MORE_BINDINGS = 0xC002004Au32 as _,
-    RPC_NT_GROUP_MEMBER_NOT_FOUND = 0xC002004Bu32 as _,
-    EPT_NT_CANT_CREATE = 0xC002004Cu32 as _,
-    RPC_NT_INVALID_OBJECT = 0xC002004Du32 as _,
-    RPC_NT_NO_INTERFACES = 0xC002004Fu32 as _,
-    RPC_NT_CALL_CANCELLED = 0xC0020050u32 as _,
-    RPC_NT_BINDING_INCOMPLETE = 0xC0020051u32 as _,
-    RPC_NT_COMM_FAILURE = 0xC0020052u32 as _,
-    RPC_NT_UNSUPPORTED_AUTHN_LEVEL = 0xC0020053u32 as _,
-    RPC_NT_NO_PRINC_NAME = 0xC0020054u32 as _,
-    RPC_NT_NOT_RPC_ERROR = 0xC0020055u32 as _,
-    RPC_NT_UUID_LOCAL_ONLY = 0x40020056u32 as _,
-    RPC_NT_SEC_PKG_ERROR = 0xC0020057u32 as _,
-    RPC_NT_NOT_CANCELLED = 0xC0020058u32 as _,
-    RPC_NT_INVALID_ES_ACTION = 0xC0030059u32 as _,
-    RPC_NT_WRONG_ES_VERSION = 0xC003005Au32 as _,
-    RPC_NT_WRONG_STUB_VERSION = 0xC003005Bu32 as _,
-    RPC_NT_INVALID_PIPE_OBJECT = 0xC003005Cu32 as _,
-    RPC_NT_INVALID_PIPE_OPERATION = 0xC003005Du32 as _,
-    RPC_NT_WRONG_PIPE_VERSION = 0xC003005Eu32 as _,
-    RPC_NT_PIPE_CLOSED = 0xC003005Fu32 as _,
-    RPC_NT_PIPE_DISCIPLINE_ERROR = 0xC0030060u32 as _,
-    RPC_NT_PIPE_EMPTY = 0xC0030061u32 as _,
-    RPC_NT_INVALID_ASYNC_HANDLE = 0xC0020062u32 as _,
-    RPC_NT_INVALID_ASYNC_CALL = 0xC0020063u32 as _,
-    RPC_NT_PROXY_ACCESS_DENIED = 0xC0020064u32 as _,
-    RPC_NT_COOKIE_AUTH_FAILED = 0xC0020065u32 as _,
-    RPC_NT_SEND_INCOMPLETE = 0x400200AFu32 as _,
-    STATUS_ACPI_INVALID_OPCODE = 0xC0140001u32 as _,
-    STATUS_ACPI_STACK_OVERFLOW = 0xC0140002u32 as _,
-    STATUS_ACPI_ASSERT_FAILED = 0xC0140003u32 as _,
-    STATUS_ACPI_INVALID_INDEX = 0xC0140004u32 as _,
-    STATUS_ACPI_INVALID_ARGUMENT = 0xC0140005u32 as _,
-    STATUS_ACPI_FATAL = 0xC0140006u32 as _,
-    STATUS_ACPI_INVALID_SUPERNAME = 0xC0140007u32 as _,
-    STATUS_ACPI_INVALID_ARGTYPE = 0xC0140008u32 as _,
-    STATUS_ACPI_INVALID_OBJTYPE = 0xC0140009u32 as _,
-    STATUS_ACPI_INVALID_TARGETTYPE = 0xC014000Au32 as _,
-    STATUS_ACPI_INCORRECT_ARGUMENT_COUNT = 0xC014000Bu32 as _,
-    STATUS_ACPI_ADDRESS_NOT_MAPPED = 0xC014000Cu32 as _,
-    STATUS_ACPI_INVALID_EVENTTYPE = 0xC014000Du32 as _,
-    STATUS_ACPI_HANDLER_COLLISION = 0xC014000Eu32 as _,
-    STATUS_ACPI_INVALID_DATA = 0xC014000Fu32 as _,
-    STATUS_ACPI_INVALID_REGION = 0xC0140010u32 as _,
-    STATUS_ACPI_INVALID_ACCESS_SIZE = 0xC0140011u32 as _,
-    STATUS_ACPI_ACQUIRE_GLOBAL_LOCK = 0xC0140012u32 as _,
-    STATUS_ACPI_ALREADY_INITIALIZED = 0xC0140013u32 as _,
-    STATUS_ACPI_NOT_INITIALIZED = 0xC0140014u32 as _,
-    STATUS_ACPI_INVALID_MUTEX_LEVEL = 0xC0140015u32 as _,
-    STATUS_ACPI_MUTEX_NOT_OWNED = 0xC0140016u32 as _,
-    STATUS_ACPI_MUTEX_NOT_OWNER = 0xC0140017u32 as _,
-    STATUS_ACPI_RS_ACCESS = 0xC0140018u32 as _,
-    STATUS_ACPI_INVALID_TABLE = 0xC0140019u32 as _,
-    STATUS_ACPI_REG_HANDLER_FAILED = 0xC0140020u32 as _,
-    STATUS_ACPI_POWER_REQUEST_FAILED = 0xC0140021u32 as _,
-    STATUS_CTX_WINSTATION_NAME_INVALID = 0xC00A0001u32 as _,
-    STATUS_CTX_INVALID_PD = 0xC00A0002u32 as _,
-    STATUS_CTX_PD_NOT_FOUND = 0xC00A0003u32 as _,
-    STATUS_CTX_CDM_CONNECT = 0x400A0004u32 as _,
-    STATUS_CTX_CDM_DISCONNECT = 0x400A0005u32 as _,
-    STATUS_CTX_CLOSE_PENDING = 0xC00A0006u32 as _,
-    STATUS_CTX_NO_OUTBUF = 0xC00A0007u32 as _,
-    STATUS_CTX_MODEM_INF_NOT_FOUND = 0xC00A0008u32 as _,
-    STATUS_CTX_INVALID_MODEMNAME = 0xC00A0009u32 as _,
-    STATUS_CTX_RESPONSE_ERROR = 0xC00A000Au32 as _,
-    STATUS_CTX_MODEM_RESPONSE_TIMEOUT = 0xC00A000Bu32 as _,
-    STATUS_CTX_MODEM_RESPONSE_NO_CARRIER = 0xC00A000Cu32 as _,
-    STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE = 0xC00A000Du32 as _,
-    STATUS_CTX_MODEM_RESPONSE_BUSY = 0xC00A000Eu32 as _,
-    STATUS_CTX_MODEM_RESPONSE_VOICE = 0xC00A000Fu32 as _,
-    STATUS_CTX_TD_ERROR = 0xC00A0010u32 as _,
-    STATUS_CTX_LICENSE_CLIENT_INVALID = 0xC00A0012u32 as _,
-    STATUS_CTX_LICENSE_NOT_AVAILABLE = 0xC00A0013u32 as _,
-    STATUS_CTX_LICENSE_EXPIRED = 0xC00A0014u32 as _,
-    STATUS_CTX_WINSTATION_NOT_FOUND = 0xC00A0015u32 as _,
-    STATUS_CTX_WINSTATION_NAME_COLLISION = 0xC00A0016u32 as _,
-    STATUS_CTX_WINSTATION_BUSY = 0xC00A0017u32 as _,
-    STATUS_CTX_BAD_VIDEO_MODE = 0xC00A0018u32 as _,
-    STATUS_CTX_GRAPHICS_INVALID = 0xC00A0022u32 as _,
-    STATUS_CTX_NOT_CONSOLE = 0xC00A0024u32 as _,
-    STATUS_CTX_CLIENT_QUERY_TIMEOUT = 0xC00A0026u32 as _,
-    STATUS_CTX_CONSOLE_DISCONNECT = 0xC00A0027u32 as _,
-    STATUS_CTX_CONSOLE_CONNECT = 0xC00A0028u32 as _,
-    STATUS_CTX_SHADOW_DENIED = 0xC00A002Au32 as _,
-    STATUS_CTX_WINSTATION_ACCESS_DENIED = 0xC00A002Bu32 as _,
-    STATUS_CTX_INVALID_WD = 0xC00A002Eu32 as _,
-    STATUS_CTX_WD_NOT_FOUND = 0xC00A002Fu32 as _,
-    STATUS_CTX_SHADOW_INVALID = 0xC00A0030u32 as _,
-    STATUS_CTX_SHADOW_DISABLED = 0xC00A0031u32 as _,
-    STATUS_RDP_PROTOCOL_ERROR = 0xC00A0032u32 as _,
-    STATUS_CTX_CLIENT_LICENSE_NOT_SET = 0xC00A0033u32 as _,
-    STATUS_CTX_CLIENT_LICENSE_IN_USE = 0xC00A0034u32 as _,
-    STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE = 0xC00A0035u32 as _,
-    STATUS_CTX_SHADOW_NOT_RUNNING = 0xC00A0036u32 as _,
-    STATUS_CTX_LOGON_DISABLED = 0xC00A0037u32 as _,
-    STATUS_CTX_SECURITY_LAYER_ERROR = 0xC00A0038u32 as _,
-    STATUS_TS_INCOMPATIBLE_SESSIONS = 0xC00A0039u32 as _,
-    STATUS_TS_VIDEO_SUBSYSTEM_ERROR = 0xC00A003Au32 as _,
-    STATUS_PNP_BAD_MPS_TABLE = 0xC0040035u32 as _,
-    STATUS_PNP_TRANSLATION_FAILED = 0xC0040036u32 as _,
-    STATUS_PNP_IRQ_TRANSLATION_FAILED = 0xC0040037u32 as _,
-    STATUS_PNP_INVALID_ID = 0xC0040038u32 as _,
-    STATUS_IO_REISSUE_AS_CACHED = 0xC0040039u32 as _,
-    STATUS_MUI_FILE_NOT_FOUND = 0xC00B0001u32 as _,
-    STATUS_MUI_INVALID_FILE = 0xC00B0002u32 as _,
-    STATUS_MUI_INVALID_RC_CONFIG = 0xC00B0003u32 as _,
-    STATUS_MUI_INVALID_LOCALE_NAME = 0xC00B0004u32 as _,
-    STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME = 0xC00B0005u32 as _,
-    STATUS_MUI_FILE_NOT_LOADED = 0xC00B0006u32 as _,
-    STATUS_RESOURCE_ENUM_USER_STOP = 0xC00B0007u32 as _,
-    //FILTER_FLT_NTSTATUS_FROM_HRESULT
-    STATUS_FLT_NO_HANDLER_DEFINED = 0xC01C0001u32 as _,
-    STATUS_FLT_CONTEXT_ALREADY_DEFINED = 0xC01C0002u32 as _,
-    STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST = 0xC01C0003u32 as _,
-    STATUS_FLT_DISALLOW_FAST_IO = 0xC01C0004u32 as _,
-    STATUS_FLT_INVALID_NAME_REQUEST = 0xC01C0005u32 as _,
-    STATUS_FLT_NOT_SAFE_TO_POST_OPERATION = 0xC01C0006u32 as _,
-    STATUS_FLT_NOT_INITIALIZED = 0xC01C0007u32 as _,
-    STATUS_FLT_FILTER_NOT_READY = 0xC01C0008u32 as _,
-    STATUS_FLT_POST_OPERATION_CLEANUP = 0xC01C0009u32 as _,
-    STATUS_FLT_INTERNAL_ERROR = 0xC01C000Au32 as _,
-    STATUS_FLT_DELETING_OBJECT = 0xC01C000Bu32 as _,
-    STATUS_FLT_MUST_BE_NONPAGED_POOL = 0xC01C000Cu32 as _,
-    STATUS_FLT_DUPLICATE_ENTRY = 0xC01C000Du32 as _,
-    STATUS_FLT_CBDQ_DISABLED = 0xC01C000Eu32 as _,
-    STATUS_FLT_DO_NOT_ATTACH = 0xC01C000Fu32 as _,
-    STATUS_FLT_DO_NOT_DETACH = 0xC01C0010u32 as _,
-    STATUS_FLT_INSTANCE_ALTITUDE_COLLISION = 0xC01C0011u32 as _,
-    STATUS_FLT_INSTANCE_NAME_COLLISION = 0xC01C0012u32 as _,
-    STATUS_FLT_FILTER_NOT_FOUND = 0xC01C0013u32 as _,
-    STATUS_FLT_VOLUME_NOT_FOUND = 0xC01C0014u32 as _,
-    STATUS_FLT_INSTANCE_NOT_FOUND = 0xC01C0015u32 as _,
-    STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND = 0xC01C0016u32 as _,
-    STATUS_FLT_INVALID_CONTEXT_REGISTRATION = 0xC01C0017u32 as _,
-    STATUS_FLT_NAME_CACHE_MISS = 0xC01C0018u32 as _,
-    STATUS_FLT_NO_DEVICE_OBJECT = 0xC01C0019u32 as _,
-    STATUS_FLT_VOLUME_ALREADY_MOUNTED = 0xC01C001Au32 as _,
-    STATUS_FLT_ALREADY_ENLISTED = 0xC01C001Bu32 as _,
-    STATUS_FLT_CONTEXT_ALREADY_LINKED = 0xC01C001Cu32 as _,
-    STATUS_FLT_NO_WAITER_FOR_REPLY = 0xC01C0020u32 as _,
-    STATUS_FLT_REGISTRATION_BUSY = 0xC01C0023u32 as _,
-    STATUS_SXS_SECTION_NOT_FOUND = 0xC0150001u32 as _,
-    STATUS_SXS_CANT_GEN_ACTCTX = 0xC0150002u32 as _,
-    STATUS_SXS_INVALID_ACTCTXDATA_FORMAT = 0xC0150003u32 as _,
-    STATUS_SXS_ASSEMBLY_NOT_FOUND = 0xC0150004u32 as _,
-    STATUS_SXS_MANIFEST_FORMAT_ERROR = 0xC0150005u32 as _,
-    STATUS_SXS_MANIFEST_PARSE_ERROR = 0xC0150006u32 as _,
-    STATUS_SXS_ACTIVATION_CONTEXT_DISABLED = 0xC0150007u32 as _,
-    STATUS_SXS_KEY_NOT_FOUND = 0xC0150008u32 as _,
-    STATUS_SXS_VERSION_CONFLICT = 0xC0150009u32 as _,
-    STATUS_SXS_WRONG_SECTION_TYPE = 0xC015000Au32 as _,
-    STATUS_SXS_THREAD_QUERIES_DISABLED = 0xC015000Bu32 as _,
-    STATUS_SXS_ASSEMBLY_MISSING = 0xC015000Cu32 as _,
-    STATUS_SXS_RELEASE_ACTIVATION_CONTEXT = 0x4015000Du32 as _,
-    STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET = 0xC015000Eu32 as _,
-    STATUS_SXS_EARLY_DEACTIVATION = 0xC015000Fu32 as _,
-    STATUS_SXS_INVALID_DEACTIVATION = 0xC0150010u32 as _,
-    STATUS_SXS_MULTIPLE_DEACTIVATION = 0xC0150011u32 as _,
-    STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY = 0xC0150012u32 as _,
-    STATUS_SXS_PROCESS_TERMINATION_REQUESTED = 0xC0150013u32 as _,
-    STATUS_SXS_CORRUPT_ACTIVATION_STACK = 0xC0150014u32 as _,
-    STATUS_SXS_CORRUPTION = 0xC0150015u32 as _,
-    STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE = 0xC0150016u32 as _,
-    STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME = 0xC0150017u32 as _,
-    STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE = 0xC0150018u32 as _,
-    STATUS_SXS_IDENTITY_PARSE_ERROR = 0xC0150019u32 as _,
-    STATUS_SXS_COMPONENT_STORE_CORRUPT = 0xC015001Au32 as _,
-    STATUS_SXS_FILE_HASH_MISMATCH = 0xC015001Bu32 as _,
-    STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT = 0xC015001Cu32 as _,
-    STATUS_SXS_IDENTITIES_DIFFERENT = 0xC015001Du32 as _,
-    STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT = 0xC015001Eu32 as _,
-    STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY = 0xC015001Fu32 as _,
-    STATUS_ADVANCED_INSTALLER_FAILED = 0xC0150020u32 as _,
-    STATUS_XML_ENCODING_MISMATCH = 0xC0150021u32 as _,
-    STATUS_SXS_MANIFEST_TOO_BIG = 0xC0150022u32 as _,
-    STATUS_SXS_SETTING_NOT_REGISTERED = 0xC0150023u32 as _,
-    STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE = 0xC0150024u32 as _,
-    STATUS_SMI_PRIMITIVE_INSTALLER_FAILED = 0xC0150025u32 as _,
-    STATUS_GENERIC_COMMAND_FAILED = 0xC0150026u32 as _,
-    STATUS_SXS_FILE_HASH_MISSING = 0xC0150027u32 as _,
-    STATUS_CLUSTER_INVALID_NODE = 0xC0130001u32 as _,
-    STATUS_CLUSTER_NODE_EXISTS = 0xC0130002u32 as _,
-    STATUS_CLUSTER_JOIN_IN_PROGRESS = 0xC0130003u32 as _,
-    STATUS_CLUSTER_NODE_NOT_FOUND = 0xC0130004u32 as _,
-    STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND = 0xC0130005u32 as _,
-    STATUS_CLUSTER_NETWORK_EXISTS = 0xC0130006u32 as _,
-    STATUS_CLUSTER_NETWORK_NOT_FOUND = 0xC0130007u32 as _,
-    STATUS_CLUSTER_NETINTERFACE_EXISTS = 0xC0130008u32 as _,
-    STATUS_CLUSTER_NETINTERFACE_NOT_FOUND = 0xC0130009u32 as _,
-    STATUS_CLUSTER_INVALID_REQUEST = 0xC013000Au32 as _,
-    STATUS_CLUSTER_INVALID_NETWORK_PROVIDER = 0xC013000Bu32 as _,
-    STATUS_CLUSTER_NODE_DOWN = 0xC013000Cu32 as _,
-    STATUS_CLUSTER_NODE_UNREACHABLE = 0xC013000Du32 as _,
-    STATUS_CLUSTER_NODE_NOT_MEMBER = 0xC013000Eu32 as _,
-    STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS = 0xC013000Fu32 as _,
-    STATUS_CLUSTER_INVALID_NETWORK = 0xC0130010u32 as _,
-    STATUS_CLUSTER_NO_NET_ADAPTERS = 0xC0130011u32 as _,
-    STATUS_CLUSTER_NODE_UP = 0xC0130012u32 as _,
-    STATUS_CLUSTER_NODE_PAUSED = 0xC0130013u32 as _,
-    STATUS_CLUSTER_NODE_NOT_PAUSED = 0xC0130014u32 as _,
-    STATUS_CLUSTER_NO_SECURITY_CONTEXT = 0xC0130015u32 as _,
-    STATUS_CLUSTER_NETWORK_NOT_INTERNAL = 0xC0130016u32 as _,
-    STATUS_CLUSTER_POISONED = 0xC0130017u32 as _,
-    STATUS_CLUSTER_NON_CSV_PATH = 0xC0130018u32 as _,
-    STATUS_CLUSTER_CSV_VOLUME_NOT_LOCAL = 0xC0130019u32 as _,
-    STATUS_CLUSTER_CSV_READ_OPLOCK_BREAK_IN_PROGRESS = 0xC0130020u32 as _,
-    STATUS_CLUSTER_CSV_AUTO_PAUSE_ERROR = 0xC0130021u32 as _,
-    STATUS_CLUSTER_CSV_REDIRECTED = 0xC0130022u32 as _,
-    STATUS_CLUSTER_CSV_NOT_REDIRECTED = 0xC0130023u32 as _,
-    STATUS_CLUSTER_CSV_VOLUME_DRAINING = 0xC0130024u32 as _,
-    STATUS_CLUSTER_CSV_SNAPSHOT_CREATION_IN_PROGRESS = 0xC0130025u32 as _,
-    STATUS_CLUSTER_CSV_VOLUME_DRAINING_SUCCEEDED_DOWNLEVEL = 0xC0130026u32 as _,
-    STATUS_CLUSTER_CSV_NO_SNAPSHOTS = 0xC0130027u32 as _,
-    STATUS_CSV_IO_PAUSE_TIMEOUT = 0xC0130028u32 as _,
-    STATUS_CLUSTER_CSV_INVALID_HANDLE = 0xC0130029u32 as _,
-    STATUS_CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR = 0xC0130030u32 as _,
-    STATUS_TRANSACTIONAL_CONFLICT = 0xC0190001u32 as _,
-    STATUS_INVALID_TRANSACTION = 0xC0190002u32 as _,
-    STATUS_TRANSACTION_NOT_ACTIVE = 0xC0190003u32 as _,
-    STATUS_TM_INITIALIZATION_FAILED = 0xC0190004u32 as _,
-    STATUS_RM_NOT_ACTIVE = 0xC0190005u32 as _,
-    STATUS_RM_METADATA_CORRUPT = 0xC0190006u32 as _,
-    STATUS_TRANSACTION_NOT_JOINED = 0xC0190007u32 as _,
-    STATUS_DIRECTORY_NOT_RM = 0xC0190008u32 as _,
-    STATUS_COULD_NOT_RESIZE_LOG = 0x80190009u32 as _,
-    STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE = 0xC019000Au32 as _,
-    STATUS_LOG_RESIZE_INVALID_SIZE = 0xC019000Bu32 as _,
-    STATUS_REMOTE_FILE_VERSION_MISMATCH = 0xC019000Cu32 as _,
-    STATUS_CRM_PROTOCOL_ALREADY_EXISTS = 0xC019000Fu32 as _,
-    STATUS_TRANSACTION_PROPAGATION_FAILED = 0xC0190010u32 as _,
-    STATUS_CRM_PROTOCOL_NOT_FOUND = 0xC0190011u32 as _,
-    STATUS_TRANSACTION_SUPERIOR_EXISTS = 0xC0190012u32 as _,
-    STATUS_TRANSACTION_REQUEST_NOT_VALID = 0xC0190013u32 as _,
-    STATUS_TRANSACTION_NOT_REQUESTED = 0xC0190014u32 as _,
-    STATUS_TRANSACTION_ALREADY_ABORTED = 0xC0190015u32 as _,
-    STATUS_TRANSACTION_ALREADY_COMMITTED = 0xC0190016u32 as _,
-    STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER = 0xC0190017u32 as _,
-    STATUS_CURRENT_TRANSACTION_NOT_VALID = 0xC0190018u32 as _,
-    STATUS_LOG_GROWTH_FAILED = 0xC0190019u32 as _,
-    STATUS_OBJECT_NO_LONGER_EXISTS = 0xC0190021u32 as _,
-    STATUS_STREAM_MINIVERSION_NOT_FOUND = 0xC0190022u32 as _,
-    STATUS_STREAM_MINIVERSION_NOT_VALID = 0xC0190023u32 as _,
-    STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION = 0xC0190024u32 as _,
-    STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT = 0xC0190025u32 as _,
-    STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS = 0xC0190026u32 as _,
-    STATUS_HANDLE_NO_LONGER_VALID = 0xC0190028u32 as _,
-    STATUS_NO_TXF_METADATA = 0x80190029u32 as _,
-    STATUS_LOG_CORRUPTION_DETECTED = 0xC0190030u32 as _,
-    STATUS_CANT_RECOVER_WITH_HANDLE_OPEN = 0x80190031u32 as _,
-    STATUS_RM_DISCONNECTED = 0xC0190032u32 as _,
-    STATUS_ENLISTMENT_NOT_SUPERIOR = 0xC0190033u32 as _,
-    STATUS_RECOVERY_NOT_NEEDED = 0x40190034u32 as _,
-    STATUS_RM_ALREADY_STARTED = 0x40190035u32 as _,
-    STATUS_FILE_IDENTITY_NOT_PERSISTENT = 0xC0190036u32 as _,
-    STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY = 0xC0190037u32 as _,
-    STATUS_CANT_CROSS_RM_BOUNDARY = 0xC0190038u32 as _,
-    STATUS_TXF_DIR_NOT_EMPTY = 0xC0190039u32 as _,
-    STATUS_INDOUBT_TRANSACTIONS_EXIST = 0xC019003Au32 as _,
-    STATUS_TM_VOLATILE = 0xC019003Bu32 as _,
-    STATUS_ROLLBACK_TIMER_EXPIRED = 0xC019003Cu32 as _,
-    STATUS_TXF_ATTRIBUTE_CORRUPT = 0xC019003Du32 as _,
-    STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION = 0xC019003Eu32 as _,
-    STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED = 0xC019003Fu32 as _,
-    STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE = 0xC0190040u32 as _,
-    STATUS_TXF_METADATA_ALREADY_PRESENT = 0x80190041u32 as _,
-    STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET = 0x80190042u32 as _,
-    STATUS_TRANSACTION_REQUIRED_PROMOTION = 0xC0190043u32 as _,
-    STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION = 0xC0190044u32 as _,
-    STATUS_TRANSACTIONS_NOT_FROZEN = 0xC0190045u32 as _,
-    STATUS_TRANSACTION_FREEZE_IN_PROGRESS = 0xC0190046u32 as _,
-    STATUS_NOT_SNAPSHOT_VOLUME = 0xC0190047u32 as _,
-    STATUS_NO_SAVEPOINT_WITH_OPEN_FILES = 0xC0190048u32 as _,
-    STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION = 0xC0190049u32 as _,
-    STATUS_TM_IDENTITY_MISMATCH = 0xC019004Au32 as _,
-    STATUS_FLOATED_SECTION = 0xC019004Bu32 as _,
-    STATUS_CANNOT_ACCEPT_TRANSACTED_WORK = 0xC019004Cu32 as _,
-    STATUS_CANNOT_ABORT_TRANSACTIONS = 0xC019004Du32 as _,
-    STATUS_TRANSACTION_NOT_FOUND = 0xC019004Eu32 as _,
-    STATUS_RESOURCEMANAGER_NOT_FOUND = 0xC019004Fu32 as _,
-    STATUS_ENLISTMENT_NOT_FOUND = 0xC0190050u32 as _,
-    STATUS_TRANSACTIONMANAGER_NOT_FOUND = 0xC0190051u32 as _,
-    STATUS_TRANSACTIONMANAGER_NOT_ONLINE = 0xC0190052u32 as _,
-    STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION = 0xC0190053u32 as _,
-    STATUS_TRANSACTION_NOT_ROOT = 0xC0190054u32 as _,
-    STATUS_TRANSACTION_OBJECT_EXPIRED = 0xC0190055u32 as _,
-    STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION = 0xC0190056u32 as _,
-    STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED = 0xC0190057u32 as _,
-    STATUS_TRANSACTION_RECORD_TOO_LONG = 0xC0190058u32 as _,
-    STATUS_NO_LINK_TRACKING_IN_TRANSACTION = 0xC0190059u32 as _,
-    STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION = 0xC019005Au32 as _,
-    STATUS_TRANSACTION_INTEGRITY_VIOLATED = 0xC019005Bu32 as _,
-    STATUS_TRANSACTIONMANAGER_IDENTITY_MISMATCH = 0xC019005Cu32 as _,
-    STATUS_RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT = 0xC019005Du32 as _,
-    STATUS_TRANSACTION_MUST_WRITETHROUGH = 0xC019005Eu32 as _,
-    STATUS_TRANSACTION_NO_SUPERIOR = 0xC019005Fu32 as _,
-    STATUS_EXPIRED_HANDLE = 0xC0190060u32 as _,
-    STATUS_TRANSACTION_NOT_ENLISTED = 0xC0190061u32 as _,
-    STATUS_LOG_SECTOR_INVALID = 0xC01A0001u32 as _,
-    STATUS_LOG_SECTOR_PARITY_INVALID = 0xC01A0002u32 as _,
-    STATUS_LOG_SECTOR_REMAPPED = 0xC01A0003u32 as _,
-    STATUS_LOG_BLOCK_INCOMPLETE = 0xC01A0004u32 as _,
-    STATUS_LOG_INVALID_RANGE = 0xC01A0005u32 as _,
-    STATUS_LOG_BLOCKS_EXHAUSTED = 0xC01A0006u32 as _,
-    STATUS_LOG_READ_CONTEXT_INVALID = 0xC01A0007u32 as _,
-    STATUS_LOG_RESTART_INVALID = 0xC01A0008u32 as _,
-    STATUS_LOG_BLOCK_VERSION = 0xC01A0009u32 as _,
-    STATUS_LOG_BLOCK_INVALID = 0xC01A000Au32 as _,
-    STATUS_LOG_READ_MODE_INVALID = 0xC01A000Bu32 as _,
-    STATUS_LOG_NO_RESTART = 0x401A000Cu32 as _,
-    STATUS_LOG_METADATA_CORRUPT = 0xC01A000Du32 as _,
-    STATUS_LOG_METADATA_INVALID = 0xC01A000Eu32 as _,
-    STATUS_LOG_METADATA_INCONSISTENT = 0xC01A000Fu32 as _,
-    STATUS_LOG_RESERVATION_INVALID = 0xC01A0010u32 as _,
-    STATUS_LOG_CANT_DELETE = 0xC01A0011u32 as _,
-    STATUS_LOG_CONTAINER_LIMIT_EXCEEDED = 0xC01A0012u32 as _,
-    STATUS_LOG_START_OF_LOG = 0xC01A0013u32 as _,
-    STATUS_LOG_POLICY_ALREADY_INSTALLED = 0xC01A0014u32 as _,
-    STATUS_LOG_POLICY_NOT_INSTALLED = 0xC01A0015u32 as _,
-    STATUS_LOG_POLICY_INVALID = 0xC01A0016u32 as _,
-    STATUS_LOG_POLICY_CONFLICT = 0xC01A0017u32 as _,
-    STATUS_LOG_PINNED_ARCHIVE_TAIL = 0xC01A0018u32 as _,
-    STATUS_LOG_RECORD_NONEXISTENT = 0xC01A0019u32 as _,
-    STATUS_LOG_RECORDS_RESERVED_INVALID = 0xC01A001Au32 as _,
-    STATUS_LOG_SPACE_RESERVED_INVALID = 0xC01A001Bu32 as _,
-    STATUS_LOG_TAIL_INVALID = 0xC01A001Cu32 as _,
-    STATUS_LOG_FULL = 0xC01A001Du32 as _,
-    STATUS_LOG_MULTIPLEXED = 0xC01A001Eu32 as _,
-    STATUS_LOG_DEDICATED = 0xC01A001Fu32 as _,
-    STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS = 0xC01A0020u32 as _,
-    STATUS_LOG_ARCHIVE_IN_PROGRESS = 0xC01A0021u32 as _,
-    STATUS_LOG_EPHEMERAL = 0xC01A0022u32 as _,
-    STATUS_LOG_NOT_ENOUGH_CONTAINERS = 0xC01A0023u32 as _,
-    STATUS_LOG_CLIENT_ALREADY_REGISTERED = 0xC01A0024u32 as _,
-    STATUS_LOG_CLIENT_NOT_REGISTERED = 0xC01A0025u32 as _,
-    STATUS_LOG_FULL_HANDLER_IN_PROGRESS = 0xC01A0026u32 as _,
-    STATUS_LOG_CONTAINER_READ_FAILED = 0xC01A0027u32 as _,
-    STATUS_LOG_CONTAINER_WRITE_FAILED = 0xC01A0028u32 as _,
-    STATUS_LOG_CONTAINER_OPEN_FAILED = 0xC01A0029u32 as _,
-    STATUS_LOG_CONTAINER_STATE_INVALID = 0xC01A002Au32 as _,
-    STATUS_LOG_STATE_INVALID = 0xC01A002Bu32 as _,
-    STATUS_LOG_PINNED = 0xC01A002Cu32 as _,
-    STATUS_LOG_METADATA_FLUSH_FAILED = 0xC01A002Du32 as _,
-    STATUS_LOG_INCONSISTENT_SECURITY = 0xC01A002Eu32 as _,
-    STATUS_LOG_APPENDED_FLUSH_FAILED = 0xC01A002Fu32 as _,
-    STATUS_LOG_PINNED_RESERVATION = 0xC01A0030u32 as _,
-    STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD = 0xC01B00EAu32 as _,
-    STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED = 0x801B00EBu32 as _,
-    STATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST = 0x401B00ECu32 as _,
-    STATUS_MONITOR_NO_DESCRIPTOR = 0xC01D0001u32 as _,
-    STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT = 0xC01D0002u32 as _,
-    STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM = 0xC01D0003u32 as _,
-    STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK = 0xC01D0004u32 as _,
-    STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED = 0xC01D0005u32 as _,
-    STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK = 0xC01D0006u32 as _,
-    STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK = 0xC01D0007u32 as _,
-    STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA = 0xC01D0008u32 as _,
-    STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK = 0xC01D0009u32 as _,
-    STATUS_MONITOR_INVALID_MANUFACTURE_DATE = 0xC01D000Au32 as _,
-    STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER = 0xC01E0000u32 as _,
-    STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER = 0xC01E0001u32 as _,
-    STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER = 0xC01E0002u32 as _,
-    STATUS_GRAPHICS_ADAPTER_WAS_RESET = 0xC01E0003u32 as _,
-    STATUS_GRAPHICS_INVALID_DRIVER_MODEL = 0xC01E0004u32 as _,
-    STATUS_GRAPHICS_PRESENT_MODE_CHANGED = 0xC01E0005u32 as _,
-    STATUS_GRAPHICS_PRESENT_OCCLUDED = 0xC01E0006u32 as _,
-    STATUS_GRAPHICS_PRESENT_DENIED = 0xC01E0007u32 as _,
-    STATUS_GRAPHICS_CANNOTCOLORCONVERT = 0xC01E0008u32 as _,
-    STATUS_GRAPHICS_DRIVER_MISMATCH = 0xC01E0009u32 as _,
-    STATUS_GRAPHICS_PARTIAL_DATA_POPULATED = 0x401E000Au32 as _,
-    STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED = 0xC01E000Bu32 as _,
-    STATUS_GRAPHICS_PRESENT_UNOCCLUDED = 0xC01E000Cu32 as _,
-    STATUS_GRAPHICS_WINDOWDC_NOT_AVAILABLE = 0xC01E000Du32 as _,
-    STATUS_GRAPHICS_WINDOWLESS_PRESENT_DISABLED = 0xC01E000Eu32 as _,
-    STATUS_GRAPHICS_NO_VIDEO_MEMORY = 0xC01E0100u32 as _,
-    STATUS_GRAPHICS_CANT_LOCK_MEMORY = 0xC01E0101u32 as _,
-    STATUS_GRAPHICS_ALLOCATION_BUSY = 0xC01E0102u32 as _,
-    STATUS_GRAPHICS_TOO_MANY_REFERENCES = 0xC01E0103u32 as _,
-    STATUS_GRAPHICS_TRY_AGAIN_LATER = 0xC01E0104u32 as _,
-    STATUS_GRAPHICS_TRY_AGAIN_NOW = 0xC01E0105u32 as _,
-    STATUS_GRAPHICS_ALLOCATION_INVALID = 0xC01E0106u32 as _,
-    STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE = 0xC01E0107u32 as _,
-    STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED = 0xC01E0108u32 as _,
-    STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION = 0xC01E0109u32 as _,
-    STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE = 0xC01E0110u32 as _,
-    STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION = 0xC01E0111u32 as _,
-    STATUS_GRAPHICS_ALLOCATION_CLOSED = 0xC01E0112u32 as _,
-    STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE = 0xC01E0113u32 as _,
-    STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE = 0xC01E0114u32 as _,
-    STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE = 0xC01E0115u32 as _,
-    STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST = 0xC01E0116u32 as _,
-    STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE = 0xC01E0200u32 as _,
-    STATUS_GRAPHICS_SKIP_ALLOCATION_PREPARATION = 0x401E0201u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY = 0xC01E0300u32 as _,
-    STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED = 0xC01E0301u32 as _,
-    STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED = 0xC01E0302u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN = 0xC01E0303u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE = 0xC01E0304u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET = 0xC01E0305u32 as _,
-    STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED = 0xC01E0306u32 as _,
-    STATUS_GRAPHICS_MODE_NOT_PINNED = 0x401E0307u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET = 0xC01E0308u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET = 0xC01E0309u32 as _,
-    STATUS_GRAPHICS_INVALID_FREQUENCY = 0xC01E030Au32 as _,
-    STATUS_GRAPHICS_INVALID_ACTIVE_REGION = 0xC01E030Bu32 as _,
-    STATUS_GRAPHICS_INVALID_TOTAL_REGION = 0xC01E030Cu32 as _,
-    STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE = 0xC01E0310u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE = 0xC01E0311u32 as _,
-    STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET = 0xC01E0312u32 as _,
-    STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY = 0xC01E0313u32 as _,
-    STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET = 0xC01E0314u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET = 0xC01E0315u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET = 0xC01E0316u32 as _,
-    STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET = 0xC01E0317u32 as _,
-    STATUS_GRAPHICS_TARGET_ALREADY_IN_SET = 0xC01E0318u32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH = 0xC01E0319u32 as _,
-    STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY = 0xC01E031Au32 as _,
-    STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET = 0xC01E031Bu32 as _,
-    STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE = 0xC01E031Cu32 as _,
-    STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET = 0xC01E031Du32 as _,
-    STATUS_GRAPHICS_NO_PREFERRED_MODE = 0x401E031Eu32 as _,
-    STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET = 0xC01E031Fu32 as _,
-    STATUS_GRAPHICS_STALE_MODESET = 0xC01E0320u32 as _,
-    STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET = 0xC01E0321u32 as _,
-    STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE = 0xC01E0322u32 as _,
-    STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN = 0xC01E0323u32 as _,
-    STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE = 0xC01E0324u32 as _,
-    STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION = 0xC01E0325u32 as _,
-    STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES = 0xC01E0326u32 as _,
-    STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY = 0xC01E0327u32 as _,
-    STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE = 0xC01E0328u32 as _,
-    STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET = 0xC01E0329u32 as _,
-    STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET = 0xC01E032Au32 as _,
-    STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR = 0xC01E032Bu32 as _,
-    STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET = 0xC01E032Cu32 as _,
-    STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET = 0xC01E032Du32 as _,
-    STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE = 0xC01E032Eu32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE = 0xC01E032Fu32 as _,
-    STATUS_GRAPHICS_RESOURCES_NOT_RELATED = 0xC01E0330u32 as _,
-    STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE = 0xC01E0331u32 as _,
-    STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE = 0xC01E0332u32 as _,
-    STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET = 0xC01E0333u32 as _,
-    STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER = 0xC01E0334u32 as _,
-    STATUS_GRAPHICS_NO_VIDPNMGR = 0xC01E0335u32 as _,
-    STATUS_GRAPHICS_NO_ACTIVE_VIDPN = 0xC01E0336u32 as _,
-    STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY = 0xC01E0337u32 as _,
-    STATUS_GRAPHICS_MONITOR_NOT_CONNECTED = 0xC01E0338u32 as _,
-    STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY = 0xC01E0339u32 as _,
-    STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE = 0xC01E033Au32 as _,
-    STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE = 0xC01E033Bu32 as _,
-    STATUS_GRAPHICS_INVALID_STRIDE = 0xC01E033Cu32 as _,
-    STATUS_GRAPHICS_INVALID_PIXELFORMAT = 0xC01E033Du32 as _,
-    STATUS_GRAPHICS_INVALID_COLORBASIS = 0xC01E033Eu32 as _,
-    STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE = 0xC01E033Fu32 as _,
-    STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY = 0xC01E0340u32 as _,
-    STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT = 0xC01E0341u32 as _,
-    STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE = 0xC01E0342u32 as _,
-    STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN = 0xC01E0343u32 as _,
-    STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL = 0xC01E0344u32 as _,
-    STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION = 0xC01E0345u32 as _,
-    STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED = 0xC01E0346u32 as _,
-    STATUS_GRAPHICS_INVALID_GAMMA_RAMP = 0xC01E0347u32 as _,
-    STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED = 0xC01E0348u32 as _,
-    STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED = 0xC01E0349u32 as _,
-    STATUS_GRAPHICS_MODE_NOT_IN_MODESET = 0xC01E034Au32 as _,
-    STATUS_GRAPHICS_DATASET_IS_EMPTY = 0x401E034Bu32 as _,
-    STATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET = 0x401E034Cu32 as _,
-    STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON = 0xC01E034Du32 as _,
-    STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE = 0xC01E034Eu32 as _,
-    STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE = 0xC01E034Fu32 as _,
-    STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS = 0xC01E0350u32 as _,
-    STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED = 0x401E0351u32 as _,
-    STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING = 0xC01E0352u32 as _,
-    STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED = 0xC01E0353u32 as _,
-    STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS = 0xC01E0354u32 as _,
-    STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT = 0xC01E0355u32 as _,
-    STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM = 0xC01E0356u32 as _,
-    STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN = 0xC01E0357u32 as _,
-    STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT = 0xC01E0358u32 as _,
-    STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED = 0xC01E0359u32 as _,
-    STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION = 0xC01E035Au32 as _,
-    STATUS_GRAPHICS_INVALID_CLIENT_TYPE = 0xC01E035Bu32 as _,
-    STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET = 0xC01E035Cu32 as _,
-    STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED = 0xC01E0400u32 as _,
-    STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED = 0xC01E0401u32 as _,
-    STATUS_GRAPHICS_UNKNOWN_CHILD_STATUS = 0x401E042Fu32 as _,
-    STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER = 0xC01E0430u32 as _,
-    STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED = 0xC01E0431u32 as _,
-    STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED = 0xC01E0432u32 as _,
-    STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY = 0xC01E0433u32 as _,
-    STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED = 0xC01E0434u32 as _,
-    STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON = 0xC01E0435u32 as _,
-    STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE = 0xC01E0436u32 as _,
-    STATUS_GRAPHICS_LEADLINK_START_DEFERRED = 0x401E0437u32 as _,
-    STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER = 0xC01E0438u32 as _,
-    STATUS_GRAPHICS_POLLING_TOO_FREQUENTLY = 0x401E0439u32 as _,
-    STATUS_GRAPHICS_START_DEFERRED = 0x401E043Au32 as _,
-    STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED = 0xC01E043Bu32 as _,
-    STATUS_GRAPHICS_DEPENDABLE_CHILD_STATUS = 0x401E043Cu32 as _,
-    STATUS_GRAPHICS_OPM_NOT_SUPPORTED = 0xC01E0500u32 as _,
-    STATUS_GRAPHICS_COPP_NOT_SUPPORTED = 0xC01E0501u32 as _,
-    STATUS_GRAPHICS_UAB_NOT_SUPPORTED = 0xC01E0502u32 as _,
-    STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS = 0xC01E0503u32 as _,
-    STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST = 0xC01E0505u32 as _,
-    STATUS_GRAPHICS_OPM_INTERNAL_ERROR = 0xC01E050Bu32 as _,
-    STATUS_GRAPHICS_OPM_INVALID_HANDLE = 0xC01E050Cu32 as _,
-    STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH = 0xC01E050Eu32 as _,
-    STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED = 0xC01E050Fu32 as _,
-    STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED = 0xC01E0510u32 as _,
-    STATUS_GRAPHICS_PVP_HFS_FAILED = 0xC01E0511u32 as _,
-    STATUS_GRAPHICS_OPM_INVALID_SRM = 0xC01E0512u32 as _,
-    STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP = 0xC01E0513u32 as _,
-    STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP = 0xC01E0514u32 as _,
-    STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA = 0xC01E0515u32 as _,
-    STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET = 0xC01E0516u32 as _,
-    STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH = 0xC01E0517u32 as _,
-    STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE = 0xC01E0518u32 as _,
-    STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS = 0xC01E051Au32 as _,
-    STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS = 0xC01E051Cu32 as _,
-    STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST = 0xC01E051Du32 as _,
-    STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR = 0xC01E051Eu32 as _,
-    STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS = 0xC01E051Fu32 as _,
-    STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED = 0xC01E0520u32 as _,
-    STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST = 0xC01E0521u32 as _,
-    STATUS_GRAPHICS_I2C_NOT_SUPPORTED = 0xC01E0580u32 as _,
-    STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST = 0xC01E0581u32 as _,
-    STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA = 0xC01E0582u32 as _,
-    STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA = 0xC01E0583u32 as _,
-    STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED = 0xC01E0584u32 as _,
-    STATUS_GRAPHICS_DDCCI_INVALID_DATA = 0xC01E0585u32 as _,
-    STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE = 0xC01E0586u32 as _,
-    STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING = 0xC01E0587u32 as _,
-    STATUS_GRAPHICS_MCA_INTERNAL_ERROR = 0xC01E0588u32 as _,
-    STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND = 0xC01E0589u32 as _,
-    STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH = 0xC01E058Au32 as _,
-    STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM = 0xC01E058Bu32 as _,
-    STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE = 0xC01E058Cu32 as _,
-    STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS = 0xC01E058Du32 as _,
-    STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED = 0xC01E05E0u32 as _,
-    STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME = 0xC01E05E1u32 as _,
-    STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP = 0xC01E05E2u32 as _,
-    STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED = 0xC01E05E3u32 as _,
-    STATUS_GRAPHICS_INVALID_POINTER = 0xC01E05E4u32 as _,
-    STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE = 0xC01E05E5u32 as _,
-    STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL = 0xC01E05E6u32 as _,
-    STATUS_GRAPHICS_INTERNAL_ERROR = 0xC01E05E7u32 as _,
-    STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS = 0xC01E05E8u32 as _,
-    STATUS_FVE_LOCKED_VOLUME = 0xC0210000u32 as _,
-    STATUS_FVE_NOT_ENCRYPTED = 0xC0210001u32 as _,
-    STATUS_FVE_BAD_INFORMATION = 0xC0210002u32 as _,
-    STATUS_FVE_TOO_SMALL = 0xC0210003u32 as _,
-    STATUS_FVE_FAILED_WRONG_FS = 0xC0210004u32 as _,
-    STATUS_FVE_BAD_PARTITION_SIZE = 0xC0210005u32 as _,
-    STATUS_FVE_FS_NOT_EXTENDED = 0xC0210006u32 as _,
-    STATUS_FVE_FS_MOUNTED = 0xC0210007u32 as _,
-    STATUS_FVE_NO_LICENSE = 0xC0210008u32 as _,
-    STATUS_FVE_ACTION_NOT_ALLOWED = 0xC0210009u32 as _,
-    STATUS_FVE_BAD_DATA = 0xC021000Au32 as _,
-    STATUS_FVE_VOLUME_NOT_BOUND = 0xC021000Bu32 as _,
-    STATUS_FVE_NOT_DATA_VOLUME = 0xC021000Cu32 as _,
-    STATUS_FVE_CONV_READ_ERROR = 0xC021000Du32 as _,
-    STATUS_FVE_CONV_WRITE_ERROR = 0xC021000Eu32 as _,
-    STATUS_FVE_OVERLAPPED_UPDATE = 0xC021000Fu32 as _,
-    STATUS_FVE_FAILED_SECTOR_SIZE = 0xC0210010u32 as _,
-    STATUS_FVE_FAILED_AUTHENTICATION = 0xC0210011u32 as _,
-    STATUS_FVE_NOT_OS_VOLUME = 0xC0210012u32 as _,
-    STATUS_FVE_KEYFILE_NOT_FOUND = 0xC0210013u32 as _,
-    STATUS_FVE_KEYFILE_INVALID = 0xC0210014u32 as _,
-    STATUS_FVE_KEYFILE_NO_VMK = 0xC0210015u32 as _,
-    STATUS_FVE_TPM_DISABLED = 0xC0210016u32 as _,
-    STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO = 0xC0210017u32 as _,
-    STATUS_FVE_TPM_INVALID_PCR = 0xC0210018u32 as _,
-    STATUS_FVE_TPM_NO_VMK = 0xC0210019u32 as _,
-    STATUS_FVE_PIN_INVALID = 0xC021001Au32 as _,
-    STATUS_FVE_AUTH_INVALID_APPLICATION = 0xC021001Bu32 as _,
-    STATUS_FVE_AUTH_INVALID_CONFIG = 0xC021001Cu32 as _,
-    STATUS_FVE_DEBUGGER_ENABLED = 0xC021001Du32 as _,
-    STATUS_FVE_DRY_RUN_FAILED = 0xC021001Eu32 as _,
-    STATUS_FVE_BAD_METADATA_POINTER = 0xC021001Fu32 as _,
-    STATUS_FVE_OLD_METADATA_COPY = 0xC0210020u32 as _,
-    STATUS_FVE_REBOOT_REQUIRED = 0xC0210021u32 as _,
-    STATUS_FVE_RAW_ACCESS = 0xC0210022u32 as _,
-    STATUS_FVE_RAW_BLOCKED = 0xC0210023u32 as _,
-    STATUS_FVE_NO_AUTOUNLOCK_MASTER_KEY = 0xC0210024u32 as _,
-    STATUS_FVE_MOR_FAILED = 0xC0210025u32 as _,
-    STATUS_FVE_NO_FEATURE_LICENSE = 0xC0210026u32 as _,
-    STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED = 0xC0210027u32 as _,
-    STATUS_FVE_CONV_RECOVERY_FAILED = 0xC0210028u32 as _,
-    STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG = 0xC0210029u32 as _,
-    STATUS_FVE_INVALID_DATUM_TYPE = 0xC021002Au32 as _,
-    STATUS_FVE_VOLUME_TOO_SMALL = 0xC0210030u32 as _,
-    STATUS_FVE_ENH_PIN_INVALID = 0xC0210031u32 as _,
-    STATUS_FVE_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE = 0xC0210032u32 as _,
-    STATUS_FVE_WIPE_NOT_ALLOWED_ON_TP_STORAGE = 0xC0210033u32 as _,
-    STATUS_FVE_NOT_ALLOWED_ON_CSV_STACK = 0xC0210034u32 as _,
-    STATUS_FVE_NOT_ALLOWED_ON_CLUSTER = 0xC0210035u32 as _,
-    STATUS_FVE_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING = 0xC0210036u32 as _,
-    STATUS_FVE_WIPE_CANCEL_NOT_APPLICABLE = 0xC0210037u32 as _,
-    STATUS_FVE_EDRIVE_DRY_RUN_FAILED = 0xC0210038u32 as _,
-    STATUS_FVE_SECUREBOOT_DISABLED = 0xC0210039u32 as _,
-    STATUS_FVE_SECUREBOOT_CONFIG_CHANGE = 0xC021003Au32 as _,
-    STATUS_FVE_DEVICE_LOCKEDOUT = 0xC021003Bu32 as _,
-    STATUS_FVE_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT = 0xC021003Cu32 as _,
-    STATUS_FVE_NOT_DE_VOLUME = 0xC021003Du32 as _,
-    STATUS_FVE_PROTECTION_DISABLED = 0xC021003Eu32 as _,
-    STATUS_FVE_PROTECTION_CANNOT_BE_DISABLED = 0xC021003Fu32 as _,
-    STATUS_FWP_CALLOUT_NOT_FOUND = 0xC0220001u32 as _,
-    STATUS_FWP_CONDITION_NOT_FOUND = 0xC0220002u32 as _,
-    STATUS_FWP_FILTER_NOT_FOUND = 0xC0220003u32 as _,
-    STATUS_FWP_LAYER_NOT_FOUND = 0xC0220004u32 as _,
-    STATUS_FWP_PROVIDER_NOT_FOUND = 0xC0220005u32 as _,
-    STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND = 0xC0220006u32 as _,
-    STATUS_FWP_SUBLAYER_NOT_FOUND = 0xC0220007u32 as _,
-    STATUS_FWP_NOT_FOUND = 0xC0220008u32 as _,
-    STATUS_FWP_ALREADY_EXISTS = 0xC0220009u32 as _,
-    STATUS_FWP_IN_USE = 0xC022000Au32 as _,
-    STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS = 0xC022000Bu32 as _,
-    STATUS_FWP_WRONG_SESSION = 0xC022000Cu32 as _,
-    STATUS_FWP_NO_TXN_IN_PROGRESS = 0xC022000Du32 as _,
-    STATUS_FWP_TXN_IN_PROGRESS = 0xC022000Eu32 as _,
-    STATUS_FWP_TXN_ABORTED = 0xC022000Fu32 as _,
-    STATUS_FWP_SESSION_ABORTED = 0xC0220010u32 as _,
-    STATUS_FWP_INCOMPATIBLE_TXN = 0xC0220011u32 as _,
-    STATUS_FWP_TIMEOUT = 0xC0220012u32 as _,
-    STATUS_FWP_NET_EVENTS_DISABLED = 0xC0220013u32 as _,
-    STATUS_FWP_INCOMPATIBLE_LAYER = 0xC0220014u32 as _,
-    STATUS_FWP_KM_CLIENTS_ONLY = 0xC0220015u32 as _,
-    STATUS_FWP_LIFETIME_MISMATCH = 0xC0220016u32 as _,
-    STATUS_FWP_BUILTIN_OBJECT = 0xC0220017u32 as _,
-    STATUS_FWP_TOO_MANY_CALLOUTS = 0xC0220018u32 as _,
-    STATUS_FWP_NOTIFICATION_DROPPED = 0xC0220019u32 as _,
-    STATUS_FWP_TRAFFIC_MISMATCH = 0xC022001Au32 as _,
-    STATUS_FWP_INCOMPATIBLE_SA_STATE = 0xC022001Bu32 as _,
-    STATUS_FWP_NULL_POINTER = 0xC022001Cu32 as _,
-    STATUS_FWP_INVALID_ENUMERATOR = 0xC022001Du32 as _,
-    STATUS_FWP_INVALID_FLAGS = 0xC022001Eu32 as _,
-    STATUS_FWP_INVALID_NET_MASK = 0xC022001Fu32 as _,
-    STATUS_FWP_INVALID_RANGE = 0xC0220020u32 as _,
-    STATUS_FWP_INVALID_INTERVAL = 0xC0220021u32 as _,
-    STATUS_FWP_ZERO_LENGTH_ARRAY = 0xC0220022u32 as _,
-    STATUS_FWP_NULL_DISPLAY_NAME = 0xC0220023u32 as _,
-    STATUS_FWP_INVALID_ACTION_TYPE = 0xC0220024u32 as _,
-    STATUS_FWP_INVALID_WEIGHT = 0xC0220025u32 as _,
-    STATUS_FWP_MATCH_TYPE_MISMATCH = 0xC0220026u32 as _,
-    STATUS_FWP_TYPE_MISMATCH = 0xC0220027u32 as _,
-    STATUS_FWP_OUT_OF_BOUNDS = 0xC0220028u32 as _,
-    STATUS_FWP_RESERVED = 0xC0220029u32 as _,
-    STATUS_FWP_DUPLICATE_CONDITION = 0xC022002Au32 as _,
-    STATUS_FWP_DUPLICATE_KEYMOD = 0xC022002Bu32 as _,
-    STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER = 0xC022002Cu32 as _,
-    STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER = 0xC022002Du32 as _,
-    STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER = 0xC022002Eu32 as _,
-    STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT = 0xC022002Fu32 as _,
-    STATUS_FWP_INCOMPATIBLE_AUTH_METHOD = 0xC0220030u32 as _,
-    STATUS_FWP_INCOMPATIBLE_DH_GROUP = 0xC0220031u32 as _,
-    STATUS_FWP_EM_NOT_SUPPORTED = 0xC0220032u32 as _,
-    STATUS_FWP_NEVER_MATCH = 0xC0220033u32 as _,
-    STATUS_FWP_PROVIDER_CONTEXT_MISMATCH = 0xC0220034u32 as _,
-    STATUS_FWP_INVALID_PARAMETER = 0xC0220035u32 as _,
-    STATUS_FWP_TOO_MANY_SUBLAYERS = 0xC0220036u32 as _,
-    STATUS_FWP_CALLOUT_NOTIFICATION_FAILED = 0xC0220037u32 as _,
-    STATUS_FWP_INVALID_AUTH_TRANSFORM = 0xC0220038u32 as _,
-    STATUS_FWP_INVALID_CIPHER_TRANSFORM = 0xC0220039u32 as _,
-    STATUS_FWP_INCOMPATIBLE_CIPHER_TRANSFORM = 0xC022003Au32 as _,
-    STATUS_FWP_INVALID_TRANSFORM_COMBINATION = 0xC022003Bu32 as _,
-    STATUS_FWP_DUPLICATE_AUTH_METHOD = 0xC022003Cu32 as _,
-    STATUS_FWP_INVALID_TUNNEL_ENDPOINT = 0xC022003Du32 as _,
-    STATUS_FWP_L2_DRIVER_NOT_READY = 0xC022003Eu32 as _,
-    STATUS_FWP_KEY_DICTATOR_ALREADY_REGISTERED = 0xC022003Fu32 as _,
-    STATUS_FWP_KEY_DICTATION_INVALID_KEYING_MATERIAL = 0xC0220040u32 as _,
-    STATUS_FWP_CONNECTIONS_DISABLED = 0xC0220041u32 as _,
-    STATUS_FWP_INVALID_DNS_NAME = 0xC0220042u32 as _,
-    STATUS_FWP_STILL_ON = 0xC0220043u32 as _,
-    STATUS_FWP_IKEEXT_NOT_RUNNING = 0xC0220044u32 as _,
-    STATUS_FWP_TCPIP_NOT_READY = 0xC0220100u32 as _,
-    STATUS_FWP_INJECT_HANDLE_CLOSING = 0xC0220101u32 as _,
-    STATUS_FWP_INJECT_HANDLE_STALE = 0xC0220102u32 as _,
-    STATUS_FWP_CANNOT_PEND = 0xC0220103u32 as _,
-    STATUS_FWP_DROP_NOICMP = 0xC0220104u32 as _,
-    STATUS_NDIS_CLOSING = 0xC0230002u32 as _,
-    STATUS_NDIS_BAD_VERSION = 0xC0230004u32 as _,
-    STATUS_NDIS_BAD_CHARACTERISTICS = 0xC0230005u32 as _,
-    STATUS_NDIS_ADAPTER_NOT_FOUND = 0xC0230006u32 as _,
-    STATUS_NDIS_OPEN_FAILED = 0xC0230007u32 as _,
-    STATUS_NDIS_DEVICE_FAILED = 0xC0230008u32 as _,
-    STATUS_NDIS_MULTICAST_FULL = 0xC0230009u32 as _,
-    STATUS_NDIS_MULTICAST_EXISTS = 0xC023000Au32 as _,
-    STATUS_NDIS_MULTICAST_NOT_FOUND = 0xC023000Bu32 as _,
-    STATUS_NDIS_REQUEST_ABORTED = 0xC023000Cu32 as _,
-    STATUS_NDIS_RESET_IN_PROGRESS = 0xC023000Du32 as _,
-    STATUS_NDIS_NOT_SUPPORTED = 0xC02300BBu32 as _,
-    STATUS_NDIS_INVALID_PACKET = 0xC023000Fu32 as _,
-    STATUS_NDIS_ADAPTER_NOT_READY = 0xC0230011u32 as _,
-    STATUS_NDIS_INVALID_LENGTH = 0xC0230014u32 as _,
-    STATUS_NDIS_INVALID_DATA = 0xC0230015u32 as _,
-    STATUS_NDIS_BUFFER_TOO_SHORT = 0xC0230016u32 as _,
-    STATUS_NDIS_INVALID_OID = 0xC0230017u32 as _,
-    STATUS_NDIS_ADAPTER_REMOVED = 0xC0230018u32 as _,
-    STATUS_NDIS_UNSUPPORTED_MEDIA = 0xC0230019u32 as _,
-    STATUS_NDIS_GROUP_ADDRESS_IN_USE = 0xC023001Au32 as _,
-    STATUS_NDIS_FILE_NOT_FOUND = 0xC023001Bu32 as _,
-    STATUS_NDIS_ERROR_READING_FILE = 0xC023001Cu32 as _,
-    STATUS_NDIS_ALREADY_MAPPED = 0xC023001Du32 as _,
-    STATUS_NDIS_RESOURCE_CONFLICT = 0xC023001Eu32 as _,
-    STATUS_NDIS_MEDIA_DISCONNECTED = 0xC023001Fu32 as _,
-    STATUS_NDIS_INVALID_ADDRESS = 0xC0230022u32 as _,
-    STATUS_NDIS_INVALID_DEVICE_REQUEST = 0xC0230010u32 as _,
-    STATUS_NDIS_PAUSED = 0xC023002Au32 as _,
-    STATUS_NDIS_INTERFACE_NOT_FOUND = 0xC023002Bu32 as _,
-    STATUS_NDIS_UNSUPPORTED_REVISION = 0xC023002Cu32 as _,
-    STATUS_NDIS_INVALID_PORT = 0xC023002Du32 as _,
-    STATUS_NDIS_INVALID_PORT_STATE = 0xC023002Eu32 as _,
-    STATUS_NDIS_LOW_POWER_STATE = 0xC023002Fu32 as _,
-    STATUS_NDIS_REINIT_REQUIRED = 0xC0230030u32 as _,
-    STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED = 0xC0232000u32 as _,
-    STATUS_NDIS_DOT11_MEDIA_IN_USE = 0xC0232001u32 as _,
-    STATUS_NDIS_DOT11_POWER_STATE_INVALID = 0xC0232002u32 as _,
-    STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL = 0xC0232003u32 as _,
-    STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL = 0xC0232004u32 as _,
-    STATUS_NDIS_DOT11_AP_CHANNEL_CURRENTLY_NOT_AVAILABLE = 0xC0232005u32 as _,
-    STATUS_NDIS_DOT11_AP_BAND_CURRENTLY_NOT_AVAILABLE = 0xC0232006u32 as _,
-    STATUS_NDIS_DOT11_AP_CHANNEL_NOT_ALLOWED = 0xC0232007u32 as _,
-    STATUS_NDIS_DOT11_AP_BAND_NOT_ALLOWED = 0xC0232008u32 as _,
-    STATUS_NDIS_INDICATION_REQUIRED = 0x40230001u32 as _,
-    STATUS_NDIS_OFFLOAD_POLICY = 0xC023100Fu32 as _,
-    STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED = 0xC0231012u32 as _,
-    STATUS_NDIS_OFFLOAD_PATH_REJECTED = 0xC0231013u32 as _,
-    STATUS_TPM_ERROR_MASK = 0xC0290000u32 as _,
-    STATUS_TPM_AUTHFAIL = 0xC0290001u32 as _,
-    STATUS_TPM_BADINDEX = 0xC0290002u32 as _,
-    STATUS_TPM_BAD_PARAMETER = 0xC0290003u32 as _,
-    STATUS_TPM_AUDITFAILURE = 0xC0290004u32 as _,
-    STATUS_TPM_CLEAR_DISABLED = 0xC0290005u32 as _,
-    STATUS_TPM_DEACTIVATED = 0xC0290006u32 as _,
-    STATUS_TPM_DISABLED = 0xC0290007u32 as _,
-    STATUS_TPM_DISABLED_CMD = 0xC0290008u32 as _,
-    STATUS_TPM_FAIL = 0xC0290009u32 as _,
-    STATUS_TPM_BAD_ORDINAL = 0xC029000Au32 as _,
-    STATUS_TPM_INSTALL_DISABLED = 0xC029000Bu32 as _,
-    STATUS_TPM_INVALID_KEYHANDLE = 0xC029000Cu32 as _,
-    STATUS_TPM_KEYNOTFOUND = 0xC029000Du32 as _,
-    STATUS_TPM_INAPPROPRIATE_ENC = 0xC029000Eu32 as _,
-    STATUS_TPM_MIGRATEFAIL = 0xC029000Fu32 as _,
-    STATUS_TPM_INVALID_PCR_INFO = 0xC0290010u32 as _,
-    STATUS_TPM_NOSPACE = 0xC0290011u32 as _,
-    STATUS_TPM_NOSRK = 0xC0290012u32 as _,
-    STATUS_TPM_NOTSEALED_BLOB = 0xC0290013u32 as _,
-    STATUS_TPM_OWNER_SET = 0xC0290014u32 as _,
-    STATUS_TPM_RESOURCES = 0xC0290015u32 as _,
-    STATUS_TPM_SHORTRANDOM = 0xC0290016u32 as _,
-    STATUS_TPM_SIZE = 0xC0290017u32 as _,
-    STATUS_TPM_WRONGPCRVAL = 0xC0290018u32 as _,
-    STATUS_TPM_BAD_PARAM_SIZE = 0xC0290019u32 as _,
-    STATUS_TPM_SHA_THREAD = 0xC029001Au32 as _,
-    STATUS_TPM_SHA_ERROR = 0xC029001Bu32 as _,
-    STATUS_TPM_FAILEDSELFTEST = 0xC029001Cu32 as _,
-    STATUS_TPM_AUTH2FAIL = 0xC029001Du32 as _,
-    STATUS_TPM_BADTAG = 0xC029001Eu32 as _,
-    STATUS_TPM_IOERROR = 0xC029001Fu32 as _,
-    STATUS_TPM_ENCRYPT_ERROR = 0xC0290020u32 as _,
-    STATUS_TPM_DECRYPT_ERROR = 0xC0290021u32 as _,
-    STATUS_TPM_INVALID_AUTHHANDLE = 0xC0290022u32 as _,
-    STATUS_TPM_NO_ENDORSEMENT = 0xC0290023u32 as _,
-    STATUS_TPM_INVALID_KEYUSAGE = 0xC0290024u32 as _,
-    STATUS_TPM_WRONG_ENTITYTYPE = 0xC0290025u32 as _,
-    STATUS_TPM_INVALID_POSTINIT = 0xC0290026u32 as _,
-    STATUS_TPM_INAPPROPRIATE_SIG = 0xC0290027u32 as _,
-    STATUS_TPM_BAD_KEY_PROPERTY = 0xC0290028u32 as _,
-    STATUS_TPM_BAD_MIGRATION = 0xC0290029u32 as _,
-    STATUS_TPM_BAD_SCHEME = 0xC029002Au32 as _,
-    STATUS_TPM_BAD_DATASIZE = 0xC029002Bu32 as _,
-    STATUS_TPM_BAD_MODE = 0xC029002Cu32 as _,
-    STATUS_TPM_BAD_PRESENCE = 0xC029002Du32 as _,
-    STATUS_TPM_BAD_VERSION = 0xC029002Eu32 as _,
-    STATUS_TPM_NO_WRAP_TRANSPORT = 0xC029002Fu32 as _,
-    STATUS_TPM_AUDITFAIL_UNSUCCESSFUL = 0xC0290030u32 as _,
-    STATUS_TPM_AUDITFAIL_SUCCESSFUL = 0xC0290031u32 as _,
-    STATUS_TPM_NOTRESETABLE = 0xC0290032u32 as _,
-    STATUS_TPM_NOTLOCAL = 0xC0290033u32 as _,
-    STATUS_TPM_BAD_TYPE = 0xC0290034u32 as _,
-    STATUS_TPM_INVALID_RESOURCE = 0xC0290035u32 as _,
-    STATUS_TPM_NOTFIPS = 0xC0290036u32 as _,
-    STATUS_TPM_INVALID_FAMILY = 0xC0290037u32 as _,
-    STATUS_TPM_NO_NV_PERMISSION = 0xC0290038u32 as _,
-    STATUS_TPM_REQUIRES_SIGN = 0xC0290039u32 as _,
-    STATUS_TPM_KEY_NOTSUPPORTED = 0xC029003Au32 as _,
-    STATUS_TPM_AUTH_CONFLICT = 0xC029003Bu32 as _,
-    STATUS_TPM_AREA_LOCKED = 0xC029003Cu32 as _,
-    STATUS_TPM_BAD_LOCALITY = 0xC029003Du32 as _,
-    STATUS_TPM_READ_ONLY = 0xC029003Eu32 as _,
-    STATUS_TPM_PER_NOWRITE = 0xC029003Fu32 as _,
-    STATUS_TPM_FAMILYCOUNT = 0xC0290040u32 as _,
-    STATUS_TPM_WRITE_LOCKED = 0xC0290041u32 as _,
-    STATUS_TPM_BAD_ATTRIBUTES = 0xC0290042u32 as _,
-    STATUS_TPM_INVALID_STRUCTURE = 0xC0290043u32 as _,
-    STATUS_TPM_KEY_OWNER_CONTROL = 0xC0290044u32 as _,
-    STATUS_TPM_BAD_COUNTER = 0xC0290045u32 as _,
-    STATUS_TPM_NOT_FULLWRITE = 0xC0290046u32 as _,
-    STATUS_TPM_CONTEXT_GAP = 0xC0290047u32 as _,
-    STATUS_TPM_MAXNVWRITES = 0xC0290048u32 as _,
-    STATUS_TPM_NOOPERATOR = 0xC0290049u32 as _,
-    STATUS_TPM_RESOURCEMISSING = 0xC029004Au32 as _,
-    STATUS_TPM_DELEGATE_LOCK = 0xC029004Bu32 as _,
-    STATUS_TPM_DELEGATE_FAMILY = 0xC029004Cu32 as _,
-    STATUS_TPM_DELEGATE_ADMIN = 0xC029004Du32 as _,
-    STATUS_TPM_TRANSPORT_NOTEXCLUSIVE = 0xC029004Eu32 as _,
-    STATUS_TPM_OWNER_CONTROL = 0xC029004Fu32 as _,
-    STATUS_TPM_DAA_RESOURCES = 0xC0290050u32 as _,
-    STATUS_TPM_DAA_INPUT_DATA0 = 0xC0290051u32 as _,
-    STATUS_TPM_DAA_INPUT_DATA1 = 0xC0290052u32 as _,
-    STATUS_TPM_DAA_ISSUER_SETTINGS = 0xC0290053u32 as _,
-    STATUS_TPM_DAA_TPM_SETTINGS = 0xC0290054u32 as _,
-    STATUS_TPM_DAA_STAGE = 0xC0290055u32 as _,
-    STATUS_TPM_DAA_ISSUER_VALIDITY = 0xC0290056u32 as _,
-    STATUS_TPM_DAA_WRONG_W = 0xC0290057u32 as _,
-    STATUS_TPM_BAD_HANDLE = 0xC0290058u32 as _,
-    STATUS_TPM_BAD_DELEGATE = 0xC0290059u32 as _,
-    STATUS_TPM_BADCONTEXT = 0xC029005Au32 as _,
-    STATUS_TPM_TOOMANYCONTEXTS = 0xC029005Bu32 as _,
-    STATUS_TPM_MA_TICKET_SIGNATURE = 0xC029005Cu32 as _,
-    STATUS_TPM_MA_DESTINATION = 0xC029005Du32 as _,
-    STATUS_TPM_MA_SOURCE = 0xC029005Eu32 as _,
-    STATUS_TPM_MA_AUTHORITY = 0xC029005Fu32 as _,
-    STATUS_TPM_PERMANENTEK = 0xC0290061u32 as _,
-    STATUS_TPM_BAD_SIGNATURE = 0xC0290062u32 as _,
-    STATUS_TPM_NOCONTEXTSPACE = 0xC0290063u32 as _,
-    STATUS_TPM_COMMAND_BLOCKED = 0xC0290400u32 as _,
-    STATUS_TPM_INVALID_HANDLE = 0xC0290401u32 as _,
-    STATUS_TPM_DUPLICATE_VHANDLE = 0xC0290402u32 as _,
-    STATUS_TPM_EMBEDDED_COMMAND_BLOCKED = 0xC0290403u32 as _,
-    STATUS_TPM_EMBEDDED_COMMAND_UNSUPPORTED = 0xC0290404u32 as _,
-    STATUS_TPM_RETRY = 0xC0290800u32 as _,
-    STATUS_TPM_NEEDS_SELFTEST = 0xC0290801u32 as _,
-    STATUS_TPM_DOING_SELFTEST = 0xC0290802u32 as _,
-    STATUS_TPM_DEFEND_LOCK_RUNNING = 0xC0290803u32 as _,
-    STATUS_TPM_COMMAND_CANCELED = 0xC0291001u32 as _,
-    STATUS_TPM_TOO_MANY_CONTEXTS = 0xC0291002u32 as _,
-    STATUS_TPM_NOT_FOUND = 0xC0291003u32 as _,
-    STATUS_TPM_ACCESS_DENIED = 0xC0291004u32 as _,
-    STATUS_TPM_INSUFFICIENT_BUFFER = 0xC0291005u32 as _,
-    STATUS_TPM_PPI_FUNCTION_UNSUPPORTED = 0xC0291006u32 as _,
-    STATUS_PCP_ERROR_MASK = 0xC0292000u32 as _,
-    STATUS_PCP_DEVICE_NOT_READY = 0xC0292001u32 as _,
-    STATUS_PCP_INVALID_HANDLE = 0xC0292002u32 as _,
-    STATUS_PCP_INVALID_PARAMETER = 0xC0292003u32 as _,
-    STATUS_PCP_FLAG_NOT_SUPPORTED = 0xC0292004u32 as _,
-    STATUS_PCP_NOT_SUPPORTED = 0xC0292005u32 as _,
-    STATUS_PCP_BUFFER_TOO_SMALL = 0xC0292006u32 as _,
-    STATUS_PCP_INTERNAL_ERROR = 0xC0292007u32 as _,
-    STATUS_PCP_AUTHENTICATION_FAILED = 0xC0292008u32 as _,
-    STATUS_PCP_AUTHENTICATION_IGNORED = 0xC0292009u32 as _,
-    STATUS_PCP_POLICY_NOT_FOUND = 0xC029200Au32 as _,
-    STATUS_PCP_PROFILE_NOT_FOUND = 0xC029200Bu32 as _,
-    STATUS_PCP_VALIDATION_FAILED = 0xC029200Cu32 as _,
-    STATUS_PCP_DEVICE_NOT_FOUND = 0xC029200Du32 as _,
-    STATUS_RTPM_CONTEXT_CONTINUE = 0x00293000u32 as _,
-    STATUS_RTPM_CONTEXT_COMPLETE = 0x00293001u32 as _,
-    STATUS_RTPM_NO_RESULT = 0xC0293002u32 as _,
-    STATUS_RTPM_PCR_READ_INCOMPLETE = 0xC0293003u32 as _,
-    STATUS_RTPM_INVALID_CONTEXT = 0xC0293004u32 as _,
-    STATUS_RTPM_UNSUPPORTED_CMD = 0xC0293005u32 as _,
-    STATUS_HV_INVALID_HYPERCALL_CODE = 0xC0350002u32 as _,
-    STATUS_HV_INVALID_HYPERCALL_INPUT = 0xC0350003u32 as _,
-    STATUS_HV_INVALID_ALIGNMENT = 0xC0350004u32 as _,
-    STATUS_HV_INVALID_PARAMETER = 0xC0350005u32 as _,
-    STATUS_HV_ACCESS_DENIED = 0xC0350006u32 as _,
-    STATUS_HV_INVALID_PARTITION_STATE = 0xC0350007u32 as _,
-    STATUS_HV_OPERATION_DENIED = 0xC0350008u32 as _,
-    STATUS_HV_UNKNOWN_PROPERTY = 0xC0350009u32 as _,
-    STATUS_HV_PROPERTY_VALUE_OUT_OF_RANGE = 0xC035000Au32 as _,
-    STATUS_HV_INSUFFICIENT_MEMORY = 0xC035000Bu32 as _,
-    STATUS_HV_PARTITION_TOO_DEEP = 0xC035000Cu32 as _,
-    STATUS_HV_INVALID_PARTITION_ID = 0xC035000Du32 as _,
-    STATUS_HV_INVALID_VP_INDEX = 0xC035000Eu32 as _,
-    STATUS_HV_INVALID_PORT_ID = 0xC0350011u32 as _,
-    STATUS_HV_INVALID_CONNECTION_ID = 0xC0350012u32 as _,
-    STATUS_HV_INSUFFICIENT_BUFFERS = 0xC0350013u32 as _,
-    STATUS_HV_NOT_ACKNOWLEDGED = 0xC0350014u32 as _,
-    STATUS_HV_INVALID_VP_STATE = 0xC0350015u32 as _,
-    STATUS_HV_ACKNOWLEDGED = 0xC0350016u32 as _,
-    STATUS_HV_INVALID_SAVE_RESTORE_STATE = 0xC0350017u32 as _,
-    STATUS_HV_INVALID_SYNIC_STATE = 0xC0350018u32 as _,
-    STATUS_HV_OBJECT_IN_USE = 0xC0350019u32 as _,
-    STATUS_HV_INVALID_PROXIMITY_DOMAIN_INFO = 0xC035001Au32 as _,
-    STATUS_HV_NO_DATA = 0xC035001Bu32 as _,
-    STATUS_HV_INACTIVE = 0xC035001Cu32 as _,
-    STATUS_HV_NO_RESOURCES = 0xC035001Du32 as _,
-    STATUS_HV_FEATURE_UNAVAILABLE = 0xC035001Eu32 as _,
-    STATUS_HV_INSUFFICIENT_BUFFER = 0xC0350033u32 as _,
-    STATUS_HV_INSUFFICIENT_DEVICE_DOMAINS = 0xC0350038u32 as _,
-    STATUS_HV_CPUID_FEATURE_VALIDATION_ERROR = 0xC035003Cu32 as _,
-    STATUS_HV_CPUID_XSAVE_FEATURE_VALIDATION_ERROR = 0xC035003Du32 as _,
-    STATUS_HV_PROCESSOR_STARTUP_TIMEOUT = 0xC035003Eu32 as _,
-    STATUS_HV_SMX_ENABLED = 0xC035003Fu32 as _,
-    STATUS_HV_INVALID_LP_INDEX = 0xC0350041u32 as _,
-    STATUS_HV_INVALID_REGISTER_VALUE = 0xC0350050u32 as _,
-    STATUS_HV_INVALID_VTL_STATE = 0xC0350051u32 as _,
-    STATUS_HV_NX_NOT_DETECTED = 0xC0350055u32 as _,
-    STATUS_HV_INVALID_DEVICE_ID = 0xC0350057u32 as _,
-    STATUS_HV_INVALID_DEVICE_STATE = 0xC0350058u32 as _,
-    STATUS_HV_PENDING_PAGE_REQUESTS = 0x00350059u32 as _,
-    STATUS_HV_PAGE_REQUEST_INVALID = 0xC0350060u32 as _,
-    STATUS_HV_INVALID_CPU_GROUP_ID = 0xC035006Fu32 as _,
-    STATUS_HV_INVALID_CPU_GROUP_STATE = 0xC0350070u32 as _,
-    STATUS_HV_NOT_ALLOWED_WITH_NESTED_VIRT_ACTIVE = 0xC0350071u32 as _,
-    STATUS_HV_NOT_PRESENT = 0xC0351000u32 as _,
-    STATUS_VID_DUPLICATE_HANDLER = 0xC0370001u32 as _,
-    STATUS_VID_TOO_MANY_HANDLERS = 0xC0370002u32 as _,
-    STATUS_VID_QUEUE_FULL = 0xC0370003u32 as _,
-    STATUS_VID_HANDLER_NOT_PRESENT = 0xC0370004u32 as _,
-    STATUS_VID_INVALID_OBJECT_NAME = 0xC0370005u32 as _,
-    STATUS_VID_PARTITION_NAME_TOO_LONG = 0xC0370006u32 as _,
-    STATUS_VID_MESSAGE_QUEUE_NAME_TOO_LONG = 0xC0370007u32 as _,
-    STATUS_VID_PARTITION_ALREADY_EXISTS = 0xC0370008u32 as _,
-    STATUS_VID_PARTITION_DOES_NOT_EXIST = 0xC0370009u32 as _,
-    STATUS_VID_PARTITION_NAME_NOT_FOUND = 0xC037000Au32 as _,
-    STATUS_VID_MESSAGE_QUEUE_ALREADY_EXISTS = 0xC037000Bu32 as _,
-    STATUS_VID_EXCEEDED_MBP_ENTRY_MAP_LIMIT = 0xC037000Cu32 as _,
-    STATUS_VID_MB_STILL_REFERENCED = 0xC037000Du32 as _,
-    STATUS_VID_CHILD_GPA_PAGE_SET_CORRUPTED = 0xC037000Eu32 as _,
-    STATUS_VID_INVALID_NUMA_SETTINGS = 0xC037000Fu32 as _,
-    STATUS_VID_INVALID_NUMA_NODE_INDEX = 0xC0370010u32 as _,
-    STATUS_VID_NOTIFICATION_QUEUE_ALREADY_ASSOCIATED = 0xC0370011u32 as _,
-    STATUS_VID_INVALID_MEMORY_BLOCK_HANDLE = 0xC0370012u32 as _,
-    STATUS_VID_PAGE_RANGE_OVERFLOW = 0xC0370013u32 as _,
-    STATUS_VID_INVALID_MESSAGE_QUEUE_HANDLE = 0xC0370014u32 as _,
-    STATUS_VID_INVALID_GPA_RANGE_HANDLE = 0xC0370015u32 as _,
-    STATUS_VID_NO_MEMORY_BLOCK_NOTIFICATION_QUEUE = 0xC0370016u32 as _,
-    STATUS_VID_MEMORY_BLOCK_LOCK_COUNT_EXCEEDED = 0xC0370017u32 as _,
-    STATUS_VID_INVALID_PPM_HANDLE = 0xC0370018u32 as _,
-    STATUS_VID_MBPS_ARE_LOCKED = 0xC0370019u32 as _,
-    STATUS_VID_MESSAGE_QUEUE_CLOSED = 0xC037001Au32 as _,
-    STATUS_VID_VIRTUAL_PROCESSOR_LIMIT_EXCEEDED = 0xC037001Bu32 as _,
-    STATUS_VID_STOP_PENDING = 0xC037001Cu32 as _,
-    STATUS_VID_INVALID_PROCESSOR_STATE = 0xC037001Du32 as _,
-    STATUS_VID_EXCEEDED_KM_CONTEXT_COUNT_LIMIT = 0xC037001Eu32 as _,
-    STATUS_VID_KM_INTERFACE_ALREADY_INITIALIZED = 0xC037001Fu32 as _,
-    STATUS_VID_MB_PROPERTY_ALREADY_SET_RESET = 0xC0370020u32 as _,
-    STATUS_VID_MMIO_RANGE_DESTROYED = 0xC0370021u32 as _,
-    STATUS_VID_INVALID_CHILD_GPA_PAGE_SET = 0xC0370022u32 as _,
-    STATUS_VID_RESERVE_PAGE_SET_IS_BEING_USED = 0xC0370023u32 as _,
-    STATUS_VID_RESERVE_PAGE_SET_TOO_SMALL = 0xC0370024u32 as _,
-    STATUS_VID_MBP_ALREADY_LOCKED_USING_RESERVED_PAGE = 0xC0370025u32 as _,
-    STATUS_VID_MBP_COUNT_EXCEEDED_LIMIT = 0xC0370026u32 as _,
-    STATUS_VID_SAVED_STATE_CORRUPT = 0xC0370027u32 as _,
-    STATUS_VID_SAVED_STATE_UNRECOGNIZED_ITEM = 0xC0370028u32 as _,
-    STATUS_VID_SAVED_STATE_INCOMPATIBLE = 0xC0370029u32 as _,
-    STATUS_VID_VTL_ACCESS_DENIED = 0xC037002Au32 as _,
-    STATUS_VID_REMOTE_NODE_PARENT_GPA_PAGES_USED = 0x80370001u32 as _,
-    STATUS_IPSEC_BAD_SPI = 0xC0360001u32 as _,
-    STATUS_IPSEC_SA_LIFETIME_EXPIRED = 0xC0360002u32 as _,
-    STATUS_IPSEC_WRONG_SA = 0xC0360003u32 as _,
-    STATUS_IPSEC_REPLAY_CHECK_FAILED = 0xC0360004u32 as _,
-    STATUS_IPSEC_INVALID_PACKET = 0xC0360005u32 as _,
-    STATUS_IPSEC_INTEGRITY_CHECK_FAILED = 0xC0360006u32 as _,
-    STATUS_IPSEC_CLEAR_TEXT_DROP = 0xC0360007u32 as _,
-    STATUS_IPSEC_AUTH_FIREWALL_DROP = 0xC0360008u32 as _,
-    STATUS_IPSEC_THROTTLE_DROP = 0xC0360009u32 as _,
-    STATUS_IPSEC_DOSP_BLOCK = 0xC0368000u32 as _,
-    STATUS_IPSEC_DOSP_RECEIVED_MULTICAST = 0xC0368001u32 as _,
-    STATUS_IPSEC_DOSP_INVALID_PACKET = 0xC0368002u32 as _,
-    STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED = 0xC0368003u32 as _,
-    STATUS_IPSEC_DOSP_MAX_ENTRIES = 0xC0368004u32 as _,
-    STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED = 0xC0368005u32 as _,
-    STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES = 0xC0368006u32 as _,
-    STATUS_VOLMGR_INCOMPLETE_REGENERATION = 0x80380001u32 as _,
-    STATUS_VOLMGR_INCOMPLETE_DISK_MIGRATION = 0x80380002u32 as _,
-    STATUS_VOLMGR_DATABASE_FULL = 0xC0380001u32 as _,
-    STATUS_VOLMGR_DISK_CONFIGURATION_CORRUPTED = 0xC0380002u32 as _,
-    STATUS_VOLMGR_DISK_CONFIGURATION_NOT_IN_SYNC = 0xC0380003u32 as _,
-    STATUS_VOLMGR_PACK_CONFIG_UPDATE_FAILED = 0xC0380004u32 as _,
-    STATUS_VOLMGR_DISK_CONTAINS_NON_SIMPLE_VOLUME = 0xC0380005u32 as _,
-    STATUS_VOLMGR_DISK_DUPLICATE = 0xC0380006u32 as _,
-    STATUS_VOLMGR_DISK_DYNAMIC = 0xC0380007u32 as _,
-    STATUS_VOLMGR_DISK_ID_INVALID = 0xC0380008u32 as _,
-    STATUS_VOLMGR_DISK_INVALID = 0xC0380009u32 as _,
-    STATUS_VOLMGR_DISK_LAST_VOTER = 0xC038000Au32 as _,
-    STATUS_VOLMGR_DISK_LAYOUT_INVALID = 0xC038000Bu32 as _,
-    STATUS_VOLMGR_DISK_LAYOUT_NON_BASIC_BETWEEN_BASIC_PARTITIONS = 0xC038000Cu32 as _,
-    STATUS_VOLMGR_DISK_LAYOUT_NOT_CYLINDER_ALIGNED = 0xC038000Du32 as _,
-    STATUS_VOLMGR_DISK_LAYOUT_PARTITIONS_TOO_SMALL = 0xC038000Eu32 as _,
-    STATUS_VOLMGR_DISK_LAYOUT_PRIMARY_BETWEEN_LOGICAL_PARTITIONS = 0xC038000Fu32 as _,
-    STATUS_VOLMGR_DISK_LAYOUT_TOO_MANY_PARTITIONS = 0xC0380010u32 as _,
-    STATUS_VOLMGR_DISK_MISSING = 0xC0380011u32 as _,
-    STATUS_VOLMGR_DISK_NOT_EMPTY = 0xC0380012u32 as _,
-    STATUS_VOLMGR_DISK_NOT_ENOUGH_SPACE = 0xC0380013u32 as _,
-    STATUS_VOLMGR_DISK_REVECTORING_FAILED = 0xC0380014u32 as _,
-    STATUS_VOLMGR_DISK_SECTOR_SIZE_INVALID = 0xC0380015u32 as _,
-    STATUS_VOLMGR_DISK_SET_NOT_CONTAINED = 0xC0380016u32 as _,
-    STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_MEMBERS = 0xC0380017u32 as _,
-    STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_PLEXES = 0xC0380018u32 as _,
-    STATUS_VOLMGR_DYNAMIC_DISK_NOT_SUPPORTED = 0xC0380019u32 as _,
-    STATUS_VOLMGR_EXTENT_ALREADY_USED = 0xC038001Au32 as _,
-    STATUS_VOLMGR_EXTENT_NOT_CONTIGUOUS = 0xC038001Bu32 as _,
-    STATUS_VOLMGR_EXTENT_NOT_IN_PUBLIC_REGION = 0xC038001Cu32 as _,
-    STATUS_VOLMGR_EXTENT_NOT_SECTOR_ALIGNED = 0xC038001Du32 as _,
-    STATUS_VOLMGR_EXTENT_OVERLAPS_EBR_PARTITION = 0xC038001Eu32 as _,
-    STATUS_VOLMGR_EXTENT_VOLUME_LENGTHS_DO_NOT_MATCH = 0xC038001Fu32 as _,
-    STATUS_VOLMGR_FAULT_TOLERANT_NOT_SUPPORTED = 0xC0380020u32 as _,
-    STATUS_VOLMGR_INTERLEAVE_LENGTH_INVALID = 0xC0380021u32 as _,
-    STATUS_VOLMGR_MAXIMUM_REGISTERED_USERS = 0xC0380022u32 as _,
-    STATUS_VOLMGR_MEMBER_IN_SYNC = 0xC0380023u32 as _,
-    STATUS_VOLMGR_MEMBER_INDEX_DUPLICATE = 0xC0380024u32 as _,
-    STATUS_VOLMGR_MEMBER_INDEX_INVALID = 0xC0380025u32 as _,
-    STATUS_VOLMGR_MEMBER_MISSING = 0xC0380026u32 as _,
-    STATUS_VOLMGR_MEMBER_NOT_DETACHED = 0xC0380027u32 as _,
-    STATUS_VOLMGR_MEMBER_REGENERATING = 0xC0380028u32 as _,
-    STATUS_VOLMGR_ALL_DISKS_FAILED = 0xC0380029u32 as _,
-    STATUS_VOLMGR_NO_REGISTERED_USERS = 0xC038002Au32 as _,
-    STATUS_VOLMGR_NO_SUCH_USER = 0xC038002Bu32 as _,
-    STATUS_VOLMGR_NOTIFICATION_RESET = 0xC038002Cu32 as _,
-    STATUS_VOLMGR_NUMBER_OF_MEMBERS_INVALID = 0xC038002Du32 as _,
-    STATUS_VOLMGR_NUMBER_OF_PLEXES_INVALID = 0xC038002Eu32 as _,
-    STATUS_VOLMGR_PACK_DUPLICATE = 0xC038002Fu32 as _,
-    STATUS_VOLMGR_PACK_ID_INVALID = 0xC0380030u32 as _,
-    STATUS_VOLMGR_PACK_INVALID = 0xC0380031u32 as _,
-    STATUS_VOLMGR_PACK_NAME_INVALID = 0xC0380032u32 as _,
-    STATUS_VOLMGR_PACK_OFFLINE = 0xC0380033u32 as _,
-    STATUS_VOLMGR_PACK_HAS_QUORUM = 0xC0380034u32 as _,
-    STATUS_VOLMGR_PACK_WITHOUT_QUORUM = 0xC0380035u32 as _,
-    STATUS_VOLMGR_PARTITION_STYLE_INVALID = 0xC0380036u32 as _,
-    STATUS_VOLMGR_PARTITION_UPDATE_FAILED = 0xC0380037u32 as _,
-    STATUS_VOLMGR_PLEX_IN_SYNC = 0xC0380038u32 as _,
-    STATUS_VOLMGR_PLEX_INDEX_DUPLICATE = 0xC0380039u32 as _,
-    STATUS_VOLMGR_PLEX_INDEX_INVALID = 0xC038003Au32 as _,
-    STATUS_VOLMGR_PLEX_LAST_ACTIVE = 0xC038003Bu32 as _,
-    STATUS_VOLMGR_PLEX_MISSING = 0xC038003Cu32 as _,
-    STATUS_VOLMGR_PLEX_REGENERATING = 0xC038003Du32 as _,
-    STATUS_VOLMGR_PLEX_TYPE_INVALID = 0xC038003Eu32 as _,
-    STATUS_VOLMGR_PLEX_NOT_RAID5 = 0xC038003Fu32 as _,
-    STATUS_VOLMGR_PLEX_NOT_SIMPLE = 0xC0380040u32 as _,
-    STATUS_VOLMGR_STRUCTURE_SIZE_INVALID = 0xC0380041u32 as _,
-    STATUS_VOLMGR_TOO_MANY_NOTIFICATION_REQUESTS = 0xC0380042u32 as _,
-    STATUS_VOLMGR_TRANSACTION_IN_PROGRESS = 0xC0380043u32 as _,
-    STATUS_VOLMGR_UNEXPECTED_DISK_LAYOUT_CHANGE = 0xC0380044u32 as _,
-    STATUS_VOLMGR_VOLUME_CONTAINS_MISSING_DISK = 0xC0380045u32 as _,
-    STATUS_VOLMGR_VOLUME_ID_INVALID = 0xC0380046u32 as _,
-    STATUS_VOLMGR_VOLUME_LENGTH_INVALID = 0xC0380047u32 as _,
-    STATUS_VOLMGR_VOLUME_LENGTH_NOT_SECTOR_SIZE_MULTIPLE = 0xC0380048u32 as _,
-    STATUS_VOLMGR_VOLUME_NOT_MIRRORED = 0xC0380049u32 as _,
-    STATUS_VOLMGR_VOLUME_NOT_RETAINED = 0xC038004Au32 as _,
-    STATUS_VOLMGR_VOLUME_OFFLINE = 0xC038004Bu32 as _,
-    STATUS_VOLMGR_VOLUME_RETAINED = 0xC038004Cu32 as _,
-    STATUS_VOLMGR_NUMBER_OF_EXTENTS_INVALID = 0xC038004Du32 as _,
-    STATUS_VOLMGR_DIFFERENT_SECTOR_SIZE = 0xC038004Eu32 as _,
-    STATUS_VOLMGR_BAD_BOOT_DISK = 0xC038004Fu32 as _,
-    STATUS_VOLMGR_PACK_CONFIG_OFFLINE = 0xC0380050u32 as _,
-    STATUS_VOLMGR_PACK_CONFIG_ONLINE = 0xC0380051u32 as _,
-    STATUS_VOLMGR_NOT_PRIMARY_PACK = 0xC0380052u32 as _,
-    STATUS_VOLMGR_PACK_LOG_UPDATE_FAILED = 0xC0380053u32 as _,
-    STATUS_VOLMGR_NUMBER_OF_DISKS_IN_PLEX_INVALID = 0xC0380054u32 as _,
-    STATUS_VOLMGR_NUMBER_OF_DISKS_IN_MEMBER_INVALID = 0xC0380055u32 as _,
-    STATUS_VOLMGR_VOLUME_MIRRORED = 0xC0380056u32 as _,
-    STATUS_VOLMGR_PLEX_NOT_SIMPLE_SPANNED = 0xC0380057u32 as _,
-    STATUS_VOLMGR_NO_VALID_LOG_COPIES = 0xC0380058u32 as _,
-    STATUS_VOLMGR_PRIMARY_PACK_PRESENT = 0xC0380059u32 as _,
-    STATUS_VOLMGR_NUMBER_OF_DISKS_INVALID = 0xC038005Au32 as _,
-    STATUS_VOLMGR_MIRROR_NOT_SUPPORTED = 0xC038005Bu32 as _,
-    STATUS_VOLMGR_RAID5_NOT_SUPPORTED = 0xC038005Cu32 as _,
-    STATUS_BCD_NOT_ALL_ENTRIES_IMPORTED = 0x80390001u32 as _,
-    STATUS_BCD_TOO_MANY_ELEMENTS = 0xC0390002u32 as _,
-    STATUS_BCD_NOT_ALL_ENTRIES_SYNCHRONIZED = 0x80390003u32 as _,
-    STATUS_VHD_DRIVE_FOOTER_MISSING = 0xC03A0001u32 as _,
-    STATUS_VHD_DRIVE_FOOTER_CHECKSUM_MISMATCH = 0xC03A0002u32 as _,
-    STATUS_VHD_DRIVE_FOOTER_CORRUPT = 0xC03A0003u32 as _,
-    STATUS_VHD_FORMAT_UNKNOWN = 0xC03A0004u32 as _,
-    STATUS_VHD_FORMAT_UNSUPPORTED_VERSION = 0xC03A0005u32 as _,
-    STATUS_VHD_SPARSE_HEADER_CHECKSUM_MISMATCH = 0xC03A0006u32 as _,
-    STATUS_VHD_SPARSE_HEADER_UNSUPPORTED_VERSION = 0xC03A0007u32 as _,
-    STATUS_VHD_SPARSE_HEADER_CORRUPT = 0xC03A0008u32 as _,
-    STATUS_VHD_BLOCK_ALLOCATION_FAILURE = 0xC03A0009u32 as _,
-    STATUS_VHD_BLOCK_ALLOCATION_TABLE_CORRUPT = 0xC03A000Au32 as _,
-    STATUS_VHD_INVALID_BLOCK_SIZE = 0xC03A000Bu32 as _,
-    STATUS_VHD_BITMAP_MISMATCH = 0xC03A000Cu32 as _,
-    STATUS_VHD_PARENT_VHD_NOT_FOUND = 0xC03A000Du32 as _,
-    STATUS_VHD_CHILD_PARENT_ID_MISMATCH = 0xC03A000Eu32 as _,
-    STATUS_VHD_CHILD_PARENT_TIMESTAMP_MISMATCH = 0xC03A000Fu32 as _,
-    STATUS_VHD_METADATA_READ_FAILURE = 0xC03A0010u32 as _,
-    STATUS_VHD_METADATA_WRITE_FAILURE = 0xC03A0011u32 as _,
-    STATUS_VHD_INVALID_SIZE = 0xC03A0012u32 as _,
-    STATUS_VHD_INVALID_FILE_SIZE = 0xC03A0013u32 as _,
-    STATUS_VIRTDISK_PROVIDER_NOT_FOUND = 0xC03A0014u32 as _,
-    STATUS_VIRTDISK_NOT_VIRTUAL_DISK = 0xC03A0015u32 as _,
-    STATUS_VHD_PARENT_VHD_ACCESS_DENIED = 0xC03A0016u32 as _,
-    STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH = 0xC03A0017u32 as _,
-    STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED = 0xC03A0018u32 as _,
-    STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT = 0xC03A0019u32 as _,
-    STATUS_VIRTUAL_DISK_LIMITATION = 0xC03A001Au32 as _,
-    STATUS_VHD_INVALID_TYPE = 0xC03A001Bu32 as _,
-    STATUS_VHD_INVALID_STATE = 0xC03A001Cu32 as _,
-    STATUS_VIRTDISK_UNSUPPORTED_DISK_SECTOR_SIZE = 0xC03A001Du32 as _,
-    STATUS_VIRTDISK_DISK_ALREADY_OWNED = 0xC03A001Eu32 as _,
-    STATUS_VIRTDISK_DISK_ONLINE_AND_WRITABLE = 0xC03A001Fu32 as _,
-    STATUS_CTLOG_TRACKING_NOT_INITIALIZED = 0xC03A0020u32 as _,
-    STATUS_CTLOG_LOGFILE_SIZE_EXCEEDED_MAXSIZE = 0xC03A0021u32 as _,
-    STATUS_CTLOG_VHD_CHANGED_OFFLINE = 0xC03A0022u32 as _,
-    STATUS_CTLOG_INVALID_TRACKING_STATE = 0xC03A0023u32 as _,
-    STATUS_CTLOG_INCONSISTENT_TRACKING_FILE = 0xC03A0024u32 as _,
-    STATUS_VHD_METADATA_FULL = 0xC03A0028u32 as _,
-    STATUS_VHD_INVALID_CHANGE_TRACKING_ID = 0xC03A0029u32 as _,
-    STATUS_VHD_CHANGE_TRACKING_DISABLED = 0xC03A002Au32 as _,
-    STATUS_VHD_MISSING_CHANGE_TRACKING_INFORMATION = 0xC03A0030u32 as _,
-    STATUS_VHD_RESIZE_WOULD_TRUNCATE_DATA = 0xC03A0031u32 as _,
-    STATUS_VHD_COULD_NOT_COMPUTE_MINIMUM_VIRTUAL_SIZE = 0xC03A0032u32 as _,
-    STATUS_VHD_ALREADY_AT_OR_BELOW_MINIMUM_VIRTUAL_SIZE = 0xC03A0033u32 as _,
-    STATUS_QUERY_STORAGE_ERROR = 0x803A0001u32 as _,
-    STATUS_RKF_KEY_NOT_FOUND = 0xC0400001u32 as _,
-    STATUS_RKF_DUPLICATE_KEY = 0xC0400002u32 as _,
-    STATUS_RKF_BLOB_FULL = 0xC0400003u32 as _,
-    STATUS_RKF_STORE_FULL = 0xC0400004u32 as _,
-    STATUS_RKF_FILE_BLOCKED = 0xC0400005u32 as _,
-    STATUS_RKF_ACTIVE_KEY = 0xC0400006u32 as _,
-    STATUS_RDBSS_RESTART_OPERATION = 0xC0410001u32 as _,
-    STATUS_RDBSS_CONTINUE_OPERATION = 0xC0410002u32 as _,
-    STATUS_RDBSS_POST_OPERATION = 0xC0410003u32 as _,
-    STATUS_BTH_ATT_INVALID_HANDLE = 0xC0420001u32 as _,
-    STATUS_BTH_ATT_READ_NOT_PERMITTED = 0xC0420002u32 as _,
-    STATUS_BTH_ATT_WRITE_NOT_PERMITTED = 0xC0420003u32 as _,
-    STATUS_BTH_ATT_INVALID_PDU = 0xC0420004u32 as _,
-    STATUS_BTH_ATT_INSUFFICIENT_AUTHENTICATION = 0xC0420005u32 as _,
-    STATUS_BTH_ATT_REQUEST_NOT_SUPPORTED = 0xC0420006u32 as _,
-    STATUS_BTH_ATT_INVALID_OFFSET = 0xC0420007u32 as _,
-    STATUS_BTH_ATT_INSUFFICIENT_AUTHORIZATION = 0xC0420008u32 as _,
-    STATUS_BTH_ATT_PREPARE_QUEUE_FULL = 0xC0420009u32 as _,
-    STATUS_BTH_ATT_ATTRIBUTE_NOT_FOUND = 0xC042000Au32 as _,
-    STATUS_BTH_ATT_ATTRIBUTE_NOT_LONG = 0xC042000Bu32 as _,
-    STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE = 0xC042000Cu32 as _,
-    STATUS_BTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH = 0xC042000Du32 as _,
-    STATUS_BTH_ATT_UNLIKELY = 0xC042000Eu32 as _,
-    STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION = 0xC042000Fu32 as _,
-    STATUS_BTH_ATT_UNSUPPORTED_GROUP_TYPE = 0xC0420010u32 as _,
-    STATUS_BTH_ATT_INSUFFICIENT_RESOURCES = 0xC0420011u32 as _,
-    STATUS_BTH_ATT_UNKNOWN_ERROR = 0xC0421000u32 as _,
-    STATUS_SECUREBOOT_ROLLBACK_DETECTED = 0xC0430001u32 as _,
-    STATUS_SECUREBOOT_POLICY_VIOLATION = 0xC0430002u32 as _,
-    STATUS_SECUREBOOT_INVALID_POLICY = 0xC0430003u32 as _,
-    STATUS_SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND = 0xC0430004u32 as _,
-    STATUS_SECUREBOOT_POLICY_NOT_SIGNED = 0xC0430005u32 as _,
-    STATUS_SECUREBOOT_NOT_ENABLED = 0x80430006u32 as _,
-    STATUS_SECUREBOOT_FILE_REPLACED = 0xC0430007u32 as _,
-    STATUS_SECUREBOOT_POLICY_NOT_AUTHORIZED = 0xC0430008u32 as _,
-    STATUS_SECUREBOOT_POLICY_UNKNOWN = 0xC0430009u32 as _,
-    STATUS_SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION = 0xC043000Au32 as _,
-    STATUS_SECUREBOOT_PLATFORM_ID_MISMATCH = 0xC043000Bu32 as _,
-    STATUS_SECUREBOOT_POLICY_ROLLBACK_DETECTED = 0xC043000Cu32 as _,
-    STATUS_SECUREBOOT_POLICY_UPGRADE_MISMATCH = 0xC043000Du32 as _,
-    STATUS_SECUREBOOT_REQUIRED_POLICY_FILE_MISSING = 0xC043000Eu32 as _,
-    STATUS_SECUREBOOT_NOT_BASE_POLICY = 0xC043000Fu32 as _,
-    STATUS_SECUREBOOT_NOT_SUPPLEMENTAL_POLICY = 0xC0430010u32 as _,
-    STATUS_PLATFORM_MANIFEST_NOT_AUTHORIZED = 0xC0EB0001u32 as _,
-    STATUS_PLATFORM_MANIFEST_INVALID = 0xC0EB0002u32 as _,
-    STATUS_PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED = 0xC0EB0003u32 as _,
-    STATUS_PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED = 0xC0EB0004u32 as _,
-    STATUS_PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND = 0xC0EB0005u32 as _,
-    STATUS_PLATFORM_MANIFEST_NOT_ACTIVE = 0xC0EB0006u32 as _,
-    STATUS_PLATFORM_MANIFEST_NOT_SIGNED = 0xC0EB0007u32 as _,
-    STATUS_SYSTEM_INTEGRITY_ROLLBACK_DETECTED = 0xC0E90001u32 as _,
-    STATUS_SYSTEM_INTEGRITY_POLICY_VIOLATION = 0xC0E90002u32 as _,
-    STATUS_SYSTEM_INTEGRITY_INVALID_POLICY = 0xC0E90003u32 as _,
-    STATUS_SYSTEM_INTEGRITY_POLICY_NOT_SIGNED = 0xC0E90004u32 as _,
-    STATUS_NO_APPLICABLE_APP_LICENSES_FOUND = 0xC0EA0001u32 as _,
-    STATUS_CLIP_LICENSE_NOT_FOUND = 0xC0EA0002u32 as _,
-    STATUS_CLIP_DEVICE_LICENSE_MISSING = 0xC0EA0003u32 as _,
-    STATUS_CLIP_LICENSE_INVALID_SIGNATURE = 0xC0EA0004u32 as _,
-    STATUS_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID = 0xC0EA0005u32 as _,
-    STATUS_CLIP_LICENSE_EXPIRED = 0xC0EA0006u32 as _,
-    STATUS_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE = 0xC0EA0007u32 as _,
-    STATUS_CLIP_LICENSE_NOT_SIGNED = 0xC0EA0008u32 as _,
-    STATUS_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE = 0xC0EA0009u32 as _,
-    STATUS_CLIP_LICENSE_DEVICE_ID_MISMATCH = 0xC0EA000Au32 as _,
-    STATUS_AUDIO_ENGINE_NODE_NOT_FOUND = 0xC0440001u32 as _,
-    STATUS_HDAUDIO_EMPTY_CONNECTION_LIST = 0xC0440002u32 as _,
-    STATUS_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED = 0xC0440003u32 as _,
-    STATUS_HDAUDIO_NO_LOGICAL_DEVICES_CREATED = 0xC0440004u32 as _,
-    STATUS_HDAUDIO_NULL_LINKED_LIST_ENTRY = 0xC0440005u32 as _,
-    STATUS_SPACES_REPAIRED = 0x00E70000u32 as _,
-    STATUS_SPACES_PAUSE = 0x00E70001u32 as _,
-    STATUS_SPACES_COMPLETE = 0x00E70002u32 as _,
-    STATUS_SPACES_FAULT_DOMAIN_TYPE_INVALID = 0xC0E70001u32 as _,
-    STATUS_SPACES_RESILIENCY_TYPE_INVALID = 0xC0E70003u32 as _,
-    STATUS_SPACES_DRIVE_SECTOR_SIZE_INVALID = 0xC0E70004u32 as _,
-    STATUS_SPACES_DRIVE_REDUNDANCY_INVALID = 0xC0E70006u32 as _,
-    STATUS_SPACES_NUMBER_OF_DATA_COPIES_INVALID = 0xC0E70007u32 as _,
-    STATUS_SPACES_INTERLEAVE_LENGTH_INVALID = 0xC0E70009u32 as _,
-    STATUS_SPACES_NUMBER_OF_COLUMNS_INVALID = 0xC0E7000Au32 as _,
-    STATUS_SPACES_NOT_ENOUGH_DRIVES = 0xC0E7000Bu32 as _,
-    STATUS_SPACES_EXTENDED_ERROR = 0xC0E7000Cu32 as _,
-    STATUS_SPACES_PROVISIONING_TYPE_INVALID = 0xC0E7000Du32 as _,
-    STATUS_SPACES_ALLOCATION_SIZE_INVALID = 0xC0E7000Eu32 as _,
-    STATUS_SPACES_ENCLOSURE_AWARE_INVALID = 0xC0E7000Fu32 as _,
-    STATUS_SPACES_WRITE_CACHE_SIZE_INVALID = 0xC0E70010u32 as _,
-    STATUS_SPACES_NUMBER_OF_GROUPS_INVALID = 0xC0E70011u32 as _,
-    STATUS_SPACES_DRIVE_OPERATIONAL_STATE_INVALID = 0xC0E70012u32 as _,
-    STATUS_SPACES_UPDATE_COLUMN_STATE = 0xC0E70013u32 as _,
-    STATUS_SPACES_MAP_REQUIRED = 0xC0E70014u32 as _,
-    STATUS_SPACES_UNSUPPORTED_VERSION = 0xC0E70015u32 as _,
-    STATUS_SPACES_CORRUPT_METADATA = 0xC0E70016u32 as _,
-    STATUS_SPACES_DRT_FULL = 0xC0E70017u32 as _,
-    STATUS_SPACES_INCONSISTENCY = 0xC0E70018u32 as _,
-    STATUS_SPACES_LOG_NOT_READY = 0xC0E70019u32 as _,
-    STATUS_SPACES_NO_REDUNDANCY = 0xC0E7001Au32 as _,
-    STATUS_SPACES_DRIVE_NOT_READY = 0xC0E7001Bu32 as _,
-    STATUS_SPACES_DRIVE_SPLIT = 0xC0E7001Cu32 as _,
-    STATUS_SPACES_DRIVE_LOST_DATA = 0xC0E7001Du32 as _,
-    STATUS_VOLSNAP_BOOTFILE_NOT_VALID = 0xC0500003u32 as _,
-    STATUS_VOLSNAP_ACTIVATION_TIMEOUT = 0xC0500004u32 as _,
-    STATUS_IO_PREEMPTED = 0xC0510001u32 as _,
-    STATUS_SVHDX_ERROR_STORED = 0xC05C0000u32 as _,
-    STATUS_SVHDX_ERROR_NOT_AVAILABLE = 0xC05CFF00u32 as _,
-    STATUS_SVHDX_UNIT_ATTENTION_AVAILABLE = 0xC05CFF01u32 as _,
-    STATUS_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED = 0xC05CFF02u32 as _,
-    STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED = 0xC05CFF03u32 as _,
-    STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED = 0xC05CFF04u32 as _,
-    STATUS_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED = 0xC05CFF05u32 as _,
-    STATUS_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED = 0xC05CFF06u32 as _,
-    STATUS_SVHDX_RESERVATION_CONFLICT = 0xC05CFF07u32 as _,
-    STATUS_SVHDX_WRONG_FILE_TYPE = 0xC05CFF08u32 as _,
-    STATUS_SVHDX_VERSION_MISMATCH = 0xC05CFF09u32 as _,
-    STATUS_VHD_SHARED = 0xC05CFF0Au32 as _,
-    STATUS_SVHDX_NO_INITIATOR = 0xC05CFF0Bu32 as _,
-    STATUS_VHDSET_BACKING_STORAGE_NOT_FOUND = 0xC05CFF0Cu32 as _,
-    STATUS_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP = 0xC05D0000u32 as _,
-    STATUS_SMB_BAD_CLUSTER_DIALECT = 0xC05D0001u32 as _,
-    STATUS_SMB_GUEST_LOGON_BLOCKED = 0xC05D0002u32 as _,
-    STATUS_SECCORE_INVALID_COMMAND = 0xC0E80000u32 as _,
-    STATUS_VSM_NOT_INITIALIZED = 0xC0450000u32 as _,
-    STATUS_VSM_DMA_PROTECTION_NOT_IN_USE = 0xC0450001u32 as _,
+macro_rules! define_ntstatus {
+    (
+        statuses { $($name:ident = $value:literal => $message:literal [$($alias:ident),*],)* }
+        facilities { $($facility:literal => [$($member:ident),*],)* }
+        lookup {
+            displacements [$($displacement:literal,)*]
+            slots [$($slot:literal,)*]
+        }
+    ) => {
+        #[repr(i32)]
+        #[allow(non_camel_case_types)]
+        #[allow(non_upper_case_globals)]
+        #[cfg_attr(not(feature = "nosym"), derive(Debug))]
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive)]
+        pub enum NtStatus {
+            $($name = $value as _,)*
+        }
+
+        impl NtStatus {
+            $($(
+                #[doc = concat!("Alias of [`", stringify!($name), "`](NtStatus::", stringify!($name), ").")]
+                pub const $alias: NtStatus = NtStatus::$name;
+            )*)*
+
+            /// Every known status, in declaration order.
+            pub const ALL: &'static [NtStatus] = &[$(NtStatus::$name,)*];
+
+            /// The perfect hash [`from_bits`](Self::from_bits) uses, generated by `build.rs`.
+            const DISPLACEMENTS: &'static [u16; 1 << LOOKUP_BUCKET_BITS] = &[$($displacement,)*];
+            const SLOTS: &'static [u16; 1 << LOOKUP_SLOT_BITS] = &[$($slot,)*];
+
+            /// The name hashes `from_name` and `from_name_hash` search, aliases after the variants.
+            const NAME_HASHES: &'static [(u32, NtStatus)] = &[
+                $((crate::hash::hash(stringify!($name), 0..usize::MAX), NtStatus::$name),)*
+                $($((crate::hash::hash(stringify!($alias), 0..usize::MAX), NtStatus::$name),)*)*
+            ];
+
+            /// The names matching [`NAME_HASHES`](Self::NAME_HASHES) entry for entry.
+            #[cfg(not(feature = "nosym"))]
+            const NAMES: &'static [&'static str] = &[
+                $(stringify!($name),)*
+                $($(stringify!($alias),)*)*
+            ];
+
+            /// Every SDK name for this value, starting with the variant name.
+            #[cfg(not(feature = "nosym"))]
+            pub const fn aliases(&self) -> &'static [&'static str] {
+                match self {
+                    $(NtStatus::$name => &[stringify!($name) $(, stringify!($alias))*],)*
+                }
+            }
+
+            /// The message text `ntstatus.h` documents for this status.
+            #[cfg(feature = "messages")]
+            pub const fn message(&self) -> &'static str {
+                match self {
+                    $(NtStatus::$name => $message,)*
+                }
+            }
+        }
+
+        #[cfg(feature = "obf-names")]
+        static OBF_NAMES: ObfNames<
+            { obf_names_len(&[$(stringify!($name),)*]) },
+            { [$(stringify!($name),)*].len() },
+        > = ObfNames::new(&[$(stringify!($name),)*]);
+
+        impl NtStatusFacility {
+            /// Every known status in this facility, ordered by value.
+            pub const fn statuses(&self) -> &'static [NtStatus] {
+                match *self as u16 {
+                    $($facility => &[$(NtStatus::$member),*],)*
+                    _ => &[],
+                }
+            }
+        }
+    };
 }
 
+include!(concat!(env!("OUT_DIR"), "/ntstatus.rs"));
+
+/// The 2-bit severity field of an NTSTATUS.
 #[repr(u32)]
 #[cfg_attr(not(feature = "nosym"), derive(Debug))]
 #[derive(Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
@@ -2413,102 +95,1079 @@ pub enum NtStatusKind {
     Info = 1,
     Warning = 2,
     Error = 3,
-    Unknown = 255,
+}
+
+impl NtStatusKind {
+    /// Decodes the severity from the two high bits of an NTSTATUS.
+    #[inline(always)]
+    pub const fn from_status_bits(bits: u32) -> Self {
+        match bits >> 30 {
+            0 => Self::Success,
+            1 => Self::Info,
+            2 => Self::Warning,
+            _ => Self::Error,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NtStatusKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            Self::Success => "Success",
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NtStatusKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KindVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for KindVisitor {
+            type Value = NtStatusKind;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("an NTSTATUS severity")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                match v {
+                    "Success" => Ok(NtStatusKind::Success),
+                    "Info" => Ok(NtStatusKind::Info),
+                    "Warning" => Ok(NtStatusKind::Warning),
+                    "Error" => Ok(NtStatusKind::Error),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+macro_rules! define_facilities {
+    ($($name:ident = $value:literal,)*) => {
+        /// The 12-bit facility field of an NTSTATUS, as defined by the `FACILITY_*` constants in `ntstatus.h`.
+        #[repr(u16)]
+        #[cfg_attr(not(feature = "nosym"), derive(Debug))]
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+        pub enum NtStatusFacility {
+            $($name = $value,)*
+        }
+
+        impl NtStatusFacility {
+            /// Decodes a facility field, `None` for facilities `ntstatus.h` does not define.
+            #[inline]
+            pub const fn from_bits(bits: u16) -> Option<Self> {
+                match bits {
+                    $($value => Some(Self::$name),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_facilities! {
+    Null = 0x0,
+    Debugger = 0x1,
+    RpcRuntime = 0x2,
+    RpcStubs = 0x3,
+    IoErrorCode = 0x4,
+    CodClassErrorCode = 0x6,
+    NtWin32 = 0x7,
+    NtCert = 0x8,
+    NtSspi = 0x9,
+    TerminalServer = 0xA,
+    MuiErrorCode = 0xB,
+    UsbErrorCode = 0x10,
+    HidErrorCode = 0x11,
+    FirewireErrorCode = 0x12,
+    ClusterErrorCode = 0x13,
+    AcpiErrorCode = 0x14,
+    SxsErrorCode = 0x15,
+    Transaction = 0x19,
+    CommonLog = 0x1A,
+    Video = 0x1B,
+    FilterManager = 0x1C,
+    Monitor = 0x1D,
+    GraphicsKernel = 0x1E,
+    DriverFramework = 0x20,
+    FveErrorCode = 0x21,
+    FwpErrorCode = 0x22,
+    NdisErrorCode = 0x23,
+    QuicErrorCode = 0x24,
+    Tpm = 0x29,
+    Rtpm = 0x2A,
+    Hypervisor = 0x35,
+    Ipsec = 0x36,
+    Virtualization = 0x37,
+    VolMgr = 0x38,
+    BcdErrorCode = 0x39,
+    Vhd = 0x3A,
+    Win32kNtUser = 0x3E,
+    Win32kNtGdi = 0x3F,
+    ResumeKeyFilter = 0x40,
+    Rdbss = 0x41,
+    BthAtt = 0x42,
+    SecureBoot = 0x43,
+    AudioKernel = 0x44,
+    Vsm = 0x45,
+    NtIoRing = 0x46,
+    VolSnap = 0x50,
+    SdBus = 0x51,
+    SharedVhdx = 0x5C,
+    Smb = 0x5D,
+    Xvs = 0x5E,
+    Interix = 0x99,
+    Spaces = 0xE7,
+    SecurityCore = 0xE8,
+    SystemIntegrity = 0xE9,
+    Licensing = 0xEA,
+    PlatformManifest = 0xEB,
+    AppExec = 0xEC,
+}
+
+/// Builds a raw NTSTATUS value from its severity, customer bit, facility and code.
+#[inline(always)]
+pub const fn make_ntstatus(
+    kind: NtStatusKind,
+    customer: bool,
+    facility: NtStatusFacility,
+    code: u16,
+) -> NTSTATUS {
+    let bits = ((kind as u32) << 30)
+        | ((customer as u32) << 29)
+        | ((facility as u32 & 0xFFF) << 16)
+        | code as u32;
+
+    bits as _
 }
 
 impl NtStatus {
     #[inline(always)]
-    pub fn bits(&self) -> u32 {
-        (*self as NTSTATUS) as _
+    pub const fn raw(&self) -> RawNtStatus {
+        RawNtStatus(*self as NTSTATUS)
+    }
+
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.raw().bits()
+    }
+
+    /// Equivalent to the `NT_SUCCESS` macro: true for both success and informational severities.
+    #[inline(always)]
+    pub const fn is_nt_success(&self) -> bool {
+        self.raw().is_nt_success()
+    }
+
+    #[deprecated(
+        note = "ambiguous for informational statuses, use is_nt_success or is_severity_success"
+    )]
+    #[inline(always)]
+    pub const fn is_success(&self) -> bool {
+        self.raw().is_nt_success()
+    }
+
+    #[inline(always)]
+    pub const fn is_severity_success(&self) -> bool {
+        self.raw().is_severity_success()
+    }
+
+    #[inline(always)]
+    pub const fn is_info(&self) -> bool {
+        self.raw().is_info()
+    }
+
+    #[inline(always)]
+    pub const fn is_warning(&self) -> bool {
+        self.raw().is_warning()
+    }
+
+    #[inline(always)]
+    pub const fn is_error(&self) -> bool {
+        self.raw().is_error()
+    }
+
+    #[inline(always)]
+    pub const fn kind(&self) -> NtStatusKind {
+        self.raw().kind()
+    }
+
+    #[inline(always)]
+    pub const fn is_customer(&self) -> bool {
+        self.raw().is_customer()
+    }
+
+    #[inline(always)]
+    pub const fn facility_bits(&self) -> u16 {
+        self.raw().facility_bits()
+    }
+
+    #[inline(always)]
+    pub const fn facility(&self) -> Option<NtStatusFacility> {
+        self.raw().facility()
+    }
+
+    #[inline(always)]
+    pub const fn code(&self) -> u16 {
+        self.raw().code()
+    }
+
+    /// The object index of a `STATUS_WAIT_N` or `STATUS_ABANDONED_WAIT_N` result.
+    #[inline(always)]
+    pub const fn as_wait_index(&self) -> Option<u8> {
+        self.raw().as_wait_index()
+    }
+
+    /// The Win32 error `RtlNtStatusToDosError` would return for this status.
+    ///
+    /// The mapping is partial, see [`RawNtStatus::to_win32_error`].
+    #[inline(always)]
+    pub const fn to_win32_error(&self) -> Win32Error {
+        self.raw().to_win32_error()
+    }
+
+    /// Looks up a status by its symbolic name, e.g. `"STATUS_ACCESS_DENIED"`.
+    ///
+    /// Aliases such as `"STATUS_ABANDONED_WAIT_0"` resolve to the status they alias. Under `nosym`
+    /// only the hash is compared, see [`from_name_hash`](Self::from_name_hash).
+    pub const fn from_name(name: &str) -> Option<Self> {
+        let hash = crate::hash::hash(name, 0..usize::MAX);
+
+        #[cfg(feature = "nosym")]
+        return Self::from_name_hash(hash);
+
+        // Skip past hash collisions when the names are available
+        #[cfg(not(feature = "nosym"))]
+        {
+            let mut from = 0;
+            while let Some(index) = Self::name_hash_index(hash, from) {
+                if crate::util::str_eq(Self::NAMES[index], name) {
+                    return Some(Self::NAME_HASHES[index].1);
+                }
+                from = index + 1;
+            }
+            None
+        }
+    }
+
+    /// Looks up a status by the [`hash_str!`](crate::hash_str) of its symbolic name.
+    ///
+    /// Works under `nosym`, where no names are compiled in. A hash shared by names of different
+    /// statuses under this build's [`SEED`](crate::hash::SEED) is ambiguous and yields `None`.
+    pub const fn from_name_hash(hash: u32) -> Option<Self> {
+        let Some(index) = Self::name_hash_index(hash, 0) else {
+            return None;
+        };
+
+        let status = Self::NAME_HASHES[index].1;
+        let mut from = index + 1;
+        while let Some(index) = Self::name_hash_index(hash, from) {
+            if Self::NAME_HASHES[index].1.bits() != status.bits() {
+                return None;
+            }
+            from = index + 1;
+        }
+        Some(status)
+    }
+
+    const fn name_hash_index(hash: u32, from: usize) -> Option<usize> {
+        let mut i = from;
+        while i < Self::NAME_HASHES.len() {
+            if Self::NAME_HASHES[i].0 == hash {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// `Err` for warning and error severities, so informational statuses stay on the success path.
+    #[inline(always)]
+    pub fn ok(self) -> NtResult<Self> {
+        match self.is_nt_success() {
+            true => Ok(self),
+            false => Err(self),
+        }
+    }
+
+    /// Looks up the variant for a value in constant time, in place of a match over every variant.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        match Self::index_of(bits) {
+            Some(index) => Some(Self::ALL[index]),
+            None => None,
+        }
+    }
+
+    /// The position of a value in [`ALL`](Self::ALL).
+    #[inline(always)]
+    const fn index_of(bits: u32) -> Option<usize> {
+        let bucket = lookup_mix(bits) >> (32 - LOOKUP_BUCKET_BITS);
+        let displacement = Self::DISPLACEMENTS[bucket as usize] as u32;
+        let slot = lookup_mix(bits ^ displacement) & ((1 << LOOKUP_SLOT_BITS) - 1);
+
+        match Self::SLOTS[slot as usize] as usize {
+            index if index < Self::ALL.len() && Self::ALL[index].bits() == bits => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Passes the variant name to `f`, decrypting it onto the stack under `obf-names`.
+    #[cfg(any(not(feature = "nosym"), feature = "obf-names"))]
+    fn with_name<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        #[cfg(not(feature = "nosym"))]
+        return f(self.aliases()[0]);
+
+        #[cfg(feature = "obf-names")]
+        match Self::index_of(self.bits()) {
+            Some(index) => OBF_NAMES.with_name(index, f),
+            None => unreachable!(),
+        }
+    }
+}
+
+include!("ntstatus_lookup.rs");
+
+#[cfg(feature = "obf-names")]
+const OBF_NAME_MAX_LEN: usize = 96;
+
+/// Drawn from the build seed, so the keystreams change with [`SEED`](crate::hash::SEED).
+#[cfg(feature = "obf-names")]
+const OBF_NAME_KEY: u64 = crate::hash::SEED64;
+
+/// The splitmix64 keystream of the name at `index`.
+#[cfg(feature = "obf-names")]
+const fn obf_name_keystream(index: usize) -> [u8; OBF_NAME_MAX_LEN] {
+    let mut state = OBF_NAME_KEY ^ (index as u64).wrapping_mul(0xD1B5_4A32_D192_ED03);
+    let mut keys = [0; OBF_NAME_MAX_LEN];
+    let mut i = 0;
+    while i < OBF_NAME_MAX_LEN {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        let bytes = z.to_le_bytes();
+        let mut j = 0;
+        while j < bytes.len() && i + j < OBF_NAME_MAX_LEN {
+            keys[i + j] = bytes[j];
+            j += 1;
+        }
+        i += bytes.len();
+    }
+    keys
+}
+
+#[cfg(feature = "obf-names")]
+const fn obf_names_len(names: &[&str]) -> usize {
+    let (mut len, mut i) = (0, 0);
+    while i < names.len() {
+        len += names[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Every status name XORed with a keystream, so `strings` finds none of them.
+///
+/// Each name gets its own keystream, derived from a per-build key and its index.
+#[cfg(feature = "obf-names")]
+struct ObfNames<const LEN: usize, const COUNT: usize> {
+    bytes: [u8; LEN],
+    ends: [u16; COUNT],
+}
+
+#[cfg(feature = "obf-names")]
+impl<const LEN: usize, const COUNT: usize> ObfNames<LEN, COUNT> {
+    const fn new(names: &[&str; COUNT]) -> Self {
+        let mut obf = Self {
+            bytes: [0; LEN],
+            ends: [0; COUNT],
+        };
+
+        let (mut offset, mut i) = (0, 0);
+        while i < COUNT {
+            let name = names[i].as_bytes();
+            assert!(name.len() <= OBF_NAME_MAX_LEN);
+
+            let keys = obf_name_keystream(i);
+            let mut j = 0;
+            while j < name.len() {
+                obf.bytes[offset + j] = name[j] ^ keys[j];
+                j += 1;
+            }
+
+            offset += name.len();
+            obf.ends[i] = offset as _;
+            i += 1;
+        }
+        obf
+    }
+
+    #[inline(never)]
+    fn with_name<R>(&self, index: usize, f: impl FnOnce(&str) -> R) -> R {
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1] as usize,
+        };
+        let name = &self.bytes[start..self.ends[index] as usize];
+
+        let keys = obf_name_keystream(index);
+        let mut buffer = [0u8; OBF_NAME_MAX_LEN];
+        for (i, byte) in name.iter().enumerate() {
+            buffer[i] = unsafe { core::ptr::read_volatile(byte) } ^ keys[i];
+        }
+
+        f(unsafe { core::str::from_utf8_unchecked(&buffer[..name.len()]) })
+    }
+}
+
+impl TryFromPrimitive for NtStatus {
+    type Primitive = NTSTATUS;
+    type Error = TryFromPrimitiveError<Self>;
+
+    const NAME: &'static str = "NtStatus";
+
+    #[inline(always)]
+    fn try_from_primitive(number: NTSTATUS) -> Result<Self, Self::Error> {
+        Self::from_bits(number as _).ok_or(TryFromPrimitiveError::new(number))
+    }
+}
+
+impl TryFrom<NTSTATUS> for NtStatus {
+    type Error = TryFromPrimitiveError<Self>;
+
+    #[inline(always)]
+    fn try_from(value: NTSTATUS) -> Result<Self, Self::Error> {
+        Self::try_from_primitive(value)
+    }
+}
+
+impl TryFrom<u32> for NtStatus {
+    type Error = <NtStatus as TryFrom<NTSTATUS>>::Error;
+
+    #[inline(always)]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::try_from(value as NTSTATUS)
+    }
+}
+
+impl From<NtStatus> for u32 {
+    #[inline(always)]
+    fn from(value: NtStatus) -> Self {
+        value.bits()
+    }
+}
+
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ParseNtStatusError;
+
+#[cfg(feature = "nosym")]
+impl core::fmt::Debug for ParseNtStatusError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+impl core::fmt::Display for ParseNtStatusError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("invalid NTSTATUS name or value")
+    }
+}
+
+impl core::error::Error for ParseNtStatusError {}
+
+/// Accepts a symbolic name, a `0x`-prefixed hex value or a decimal value, signed or not.
+impl core::str::FromStr for NtStatus {
+    type Err = ParseNtStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<RawNtStatus>()?.known().ok_or(ParseNtStatusError)
+    }
+}
+
+/// Like the [`NtStatus`] parser, but keeps numeric values the enum does not know.
+impl core::str::FromStr for RawNtStatus {
+    type Err = ParseNtStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            u32::from_str_radix(hex, 16).ok()
+        } else if s.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+            s.parse::<i32>()
+                .map(|value| value as u32)
+                .or_else(|_| s.parse::<u32>())
+                .ok()
+        } else {
+            return NtStatus::from_name(s)
+                .map(|status| status.raw())
+                .ok_or(ParseNtStatusError);
+        };
+
+        value.map(Self::from).ok_or(ParseNtStatusError)
+    }
+}
+
+/// Prints the decrypted name under `obf-names`, or the hex value otherwise.
+#[cfg(feature = "nosym")]
+impl core::fmt::Debug for NtStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "obf-names")]
+        return self.with_name(|name| f.write_str(name));
+
+        #[cfg(not(feature = "obf-names"))]
+        return write!(f, "{:#010X}", self.bits());
+    }
+}
+
+/// Renders as `STATUS_ACCESS_DENIED (0xC0000022)`, or just `0xC0000022` under `nosym` without `obf-names`.
+impl core::fmt::Display for NtStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(any(not(feature = "nosym"), feature = "obf-names"))]
+        return self.with_name(|name| write!(f, "{name} ({:#010X})", self.bits()));
+
+        #[cfg(all(feature = "nosym", not(feature = "obf-names")))]
+        return write!(f, "{:#010X}", self.bits());
+    }
+}
+
+impl core::error::Error for NtStatus {}
+
+/// An NTSTATUS value that may or may not be a known [`NtStatus`] variant.
+///
+/// Every 32-bit value is valid, so this is the lossless way to carry statuses returned by the system.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RawNtStatus(NTSTATUS);
+
+impl RawNtStatus {
+    #[inline(always)]
+    pub const fn new(value: NTSTATUS) -> Self {
+        Self(value)
+    }
+
+    #[inline(always)]
+    pub const fn from_parts(
+        kind: NtStatusKind,
+        customer: bool,
+        facility: NtStatusFacility,
+        code: u16,
+    ) -> Self {
+        Self(make_ntstatus(kind, customer, facility, code))
+    }
+
+    #[inline(always)]
+    pub const fn value(&self) -> NTSTATUS {
+        self.0
+    }
+
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.0 as _
+    }
+
+    /// Equivalent to the `NT_SUCCESS` macro: true for both success and informational severities.
+    #[inline(always)]
+    pub const fn is_nt_success(&self) -> bool {
+        self.0 >= 0
+    }
+
+    #[deprecated(
+        note = "ambiguous for informational statuses, use is_nt_success or is_severity_success"
+    )]
+    #[inline(always)]
+    pub const fn is_success(&self) -> bool {
+        self.is_nt_success()
+    }
+
+    #[inline(always)]
+    pub const fn is_severity_success(&self) -> bool {
+        matches!(self.kind(), NtStatusKind::Success)
+    }
+
+    #[inline(always)]
+    pub const fn is_info(&self) -> bool {
+        matches!(self.kind(), NtStatusKind::Info)
+    }
+
+    #[inline(always)]
+    pub const fn is_warning(&self) -> bool {
+        matches!(self.kind(), NtStatusKind::Warning)
+    }
+
+    #[inline(always)]
+    pub const fn is_error(&self) -> bool {
+        matches!(self.kind(), NtStatusKind::Error)
+    }
+
+    #[inline(always)]
+    pub const fn kind(&self) -> NtStatusKind {
+        NtStatusKind::from_status_bits(self.bits())
+    }
+
+    #[inline(always)]
+    pub const fn is_customer(&self) -> bool {
+        (self.bits() >> 29) & 1 == 1
+    }
+
+    #[inline(always)]
+    pub const fn facility_bits(&self) -> u16 {
+        ((self.bits() >> 16) & 0xFFF) as _
+    }
+
+    #[inline(always)]
+    pub const fn facility(&self) -> Option<NtStatusFacility> {
+        NtStatusFacility::from_bits(self.facility_bits())
+    }
+
+    #[inline(always)]
+    pub const fn code(&self) -> u16 {
+        self.bits() as _
+    }
+
+    /// `STATUS_WAIT_0 + n`, the result of a wait satisfied by the object at index `n`.
+    ///
+    /// Panics if `n` is past `STATUS_WAIT_63`.
+    #[allow(non_snake_case)]
+    #[inline(always)]
+    pub const fn STATUS_WAIT_N(n: u8) -> Self {
+        assert!(n <= 63, "wait index out of range");
+        Self(NtStatus::STATUS_WAIT_0 as NTSTATUS + n as NTSTATUS)
+    }
+
+    /// `STATUS_ABANDONED_WAIT_0 + n`, the result of a wait on an abandoned mutex at index `n`.
+    ///
+    /// Panics if `n` is past `STATUS_ABANDONED_WAIT_63`.
+    #[allow(non_snake_case)]
+    #[inline(always)]
+    pub const fn STATUS_ABANDONED_WAIT_N(n: u8) -> Self {
+        assert!(n <= 63, "wait index out of range");
+        Self(NtStatus::STATUS_ABANDONED_WAIT_0 as NTSTATUS + n as NTSTATUS)
+    }
+
+    /// The object index of a `STATUS_WAIT_N` or `STATUS_ABANDONED_WAIT_N` result.
+    #[inline(always)]
+    pub const fn as_wait_index(&self) -> Option<u8> {
+        match self.bits() {
+            bits @ 0x00..=0x3F => Some(bits as _),
+            bits @ 0x80..=0xBF => Some((bits - 0x80) as _),
+            _ => None,
+        }
+    }
+
+    /// The Win32 error `RtlNtStatusToDosError` would return for this value.
+    ///
+    /// Unmapped values yield [`Win32Error::ERROR_MR_MID_NOT_FOUND`]. The table is partial, so that
+    /// includes about half of the generic statuses and the CTX, TPM and VHD families, where
+    /// Windows has specific codes.
+    #[inline(always)]
+    pub const fn to_win32_error(&self) -> Win32Error {
+        ntstatus_to_win32(self.bits())
+    }
+
+    /// `Err` for warning and error severities, so informational statuses stay on the success path.
+    ///
+    /// The error keeps the exact value, including statuses the enum does not know.
+    #[inline(always)]
+    pub fn ok(self) -> NtResult<Self, Self> {
+        match self.is_nt_success() {
+            true => Ok(self),
+            false => Err(self),
+        }
+    }
+
+    /// Upgrades to an [`NtStatus`] if the value is a known variant.
+    #[inline(always)]
+    pub const fn known(&self) -> Option<NtStatus> {
+        NtStatus::from_bits(self.bits())
+    }
+
+    /// The known [`NtStatus`], or [`STATUS_UNSUCCESSFUL`](NtStatus::STATUS_UNSUCCESSFUL) for values
+    /// the enum does not know, e.g. from a compiled-out `status-*` family.
+    #[inline(always)]
+    pub const fn known_or_unsuccessful(&self) -> NtStatus {
+        match self.known() {
+            Some(status) => status,
+            None => NtStatus::STATUS_UNSUCCESSFUL,
+        }
+    }
+
+    /// The `ntstatus.h` message text, if the value is a known variant.
+    #[cfg(feature = "messages")]
+    #[inline(always)]
+    pub fn message(&self) -> Option<&'static str> {
+        self.known().map(|status| status.message())
+    }
+}
+
+#[cfg(any(not(feature = "nosym"), feature = "obf-names"))]
+impl core::fmt::Debug for RawNtStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.known() {
+            Some(status) => status.fmt(f),
+            None => f
+                .debug_tuple("RawNtStatus")
+                .field(&format_args!("{:#010X}", self.bits()))
+                .finish(),
+        }
+    }
+}
+
+/// Prints the hex value under `nosym` without `obf-names`.
+#[cfg(all(feature = "nosym", not(feature = "obf-names")))]
+impl core::fmt::Debug for RawNtStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:#010X}", self.bits())
+    }
+}
+
+/// Renders a known status like [`NtStatus`], and anything else as its hex value.
+impl core::fmt::Display for RawNtStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.known() {
+            Some(status) => status.fmt(f),
+            None => write!(f, "{:#010X}", self.bits()),
+        }
+    }
+}
+
+impl core::error::Error for RawNtStatus {}
+
+/// The name of a known status in human-readable formats, the `u32` value otherwise.
+#[cfg(feature = "serde")]
+impl serde::Serialize for RawNtStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(any(not(feature = "nosym"), feature = "obf-names"))]
+        if let (true, Some(status)) = (serializer.is_human_readable(), self.known()) {
+            return status.with_name(|name| serializer.serialize_str(name));
+        }
+
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+/// Accepts anything [`FromStr`](core::str::FromStr) does, or an integer, keeping unknown values.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RawNtStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RawNtStatusVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for RawNtStatusVisitor {
+            type Value = RawNtStatus;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("an NTSTATUS name or value")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                u32::try_from(v)
+                    .map(RawNtStatus::from)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                match (NTSTATUS::try_from(v), u32::try_from(v)) {
+                    (Ok(value), _) => Ok(RawNtStatus::from(value)),
+                    (_, Ok(value)) => Ok(RawNtStatus::from(value)),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Signed(v), &self)),
+                }
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        match deserializer.is_human_readable() {
+            true => deserializer.deserialize_any(RawNtStatusVisitor),
+            false => deserializer.deserialize_u32(RawNtStatusVisitor),
+        }
+    }
+}
+
+/// Serialized like [`RawNtStatus`], use that to keep values the enum does not know.
+#[cfg(feature = "serde")]
+impl serde::Serialize for NtStatus {
+    #[inline(always)]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NtStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawNtStatus::deserialize(deserializer)?;
+        raw.known().ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(raw.bits() as _),
+                &"a known NTSTATUS",
+            )
+        })
+    }
+}
+
+impl From<NTSTATUS> for RawNtStatus {
+    #[inline(always)]
+    fn from(value: NTSTATUS) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u32> for RawNtStatus {
+    #[inline(always)]
+    fn from(value: u32) -> Self {
+        Self(value as _)
+    }
+}
+
+impl From<NtStatus> for RawNtStatus {
+    #[inline(always)]
+    fn from(value: NtStatus) -> Self {
+        value.raw()
+    }
+}
+
+impl From<RawNtStatus> for NTSTATUS {
+    #[inline(always)]
+    fn from(value: RawNtStatus) -> Self {
+        value.0
+    }
+}
+
+impl From<RawNtStatus> for u32 {
+    #[inline(always)]
+    fn from(value: RawNtStatus) -> Self {
+        value.bits()
+    }
+}
+
+/// Lossy, see [`RawNtStatus::known_or_unsuccessful`]; [`RawNtStatus::known`] is the fallible
+/// conversion.
+impl From<RawNtStatus> for NtStatus {
+    #[inline(always)]
+    fn from(value: RawNtStatus) -> Self {
+        value.known_or_unsuccessful()
+    }
+}
+
+/// A syscall result whose error is the failing [`NtStatus`].
+///
+/// Failures the enum does not know become [`STATUS_UNSUCCESSFUL`](NtStatus::STATUS_UNSUCCESSFUL)
+/// through `From<RawNtStatus> for NtStatus`. `NtResult<T, RawNtStatus>` keeps the exact value.
+pub type NtResult<T, E = NtStatus> = Result<T, E>;
+
+/// Converts a status returned by a syscall into an [`NtResult`], see [`RawNtStatus::ok`].
+pub trait IntoNtResult: Sized {
+    /// `Err` with the exact status for warning and error severities.
+    fn into_raw_nt_result(self) -> NtResult<RawNtStatus, RawNtStatus>;
+
+    /// Like [`into_raw_nt_result`](Self::into_raw_nt_result), with the error converted to an
+    /// [`NtStatus`], which loses failures the enum does not know.
+    #[inline(always)]
+    fn into_nt_result(self) -> NtResult<RawNtStatus> {
+        self.into_raw_nt_result().map_err(NtStatus::from)
+    }
+}
+
+impl IntoNtResult for NTSTATUS {
+    #[inline(always)]
+    fn into_raw_nt_result(self) -> NtResult<RawNtStatus, RawNtStatus> {
+        RawNtStatus::new(self).ok()
+    }
+}
+
+impl IntoNtResult for u32 {
+    #[inline(always)]
+    fn into_raw_nt_result(self) -> NtResult<RawNtStatus, RawNtStatus> {
+        RawNtStatus::from(self).ok()
+    }
+}
+
+impl IntoNtResult for RawNtStatus {
+    #[inline(always)]
+    fn into_raw_nt_result(self) -> NtResult<RawNtStatus, RawNtStatus> {
+        self.ok()
+    }
+}
+
+impl IntoNtResult for NtStatus {
+    #[inline(always)]
+    fn into_raw_nt_result(self) -> NtResult<RawNtStatus, RawNtStatus> {
+        self.raw().ok()
+    }
+}
+
+/// Like `?` for anything [`IntoNtResult`](crate::ntstatus::IntoNtResult), but evaluates to the
+/// status on success so informational values such as `STATUS_PENDING` can still be inspected.
+///
+/// The failing [`RawNtStatus`](crate::ntstatus::RawNtStatus) is converted with `Into`, so it is only
+/// narrowed when the function returns an [`NtStatus`](crate::ntstatus::NtStatus) error.
+#[macro_export]
+macro_rules! nt_try {
+    ($e:expr) => {
+        match $crate::ntstatus::IntoNtResult::into_raw_nt_result($e) {
+            Ok(status) => status,
+            Err(status) => return Err(status.into()),
+        }
+    };
+}
+
+/// Set in an HRESULT that wraps an NTSTATUS, see `HRESULT_FROM_NT`.
+pub const FACILITY_NT_BIT: u32 = 0x1000_0000;
+
+/// A COM `HRESULT`.
+///
+/// Statuses convert with `HRESULT_FROM_NT` semantics; only HRESULTs carrying [`FACILITY_NT_BIT`]
+/// convert back.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HResult(HRESULT);
+
+impl HResult {
+    #[inline(always)]
+    pub const fn new(value: HRESULT) -> Self {
+        Self(value)
+    }
+
+    /// Equivalent to the `HRESULT_FROM_NT` macro.
+    #[inline(always)]
+    pub const fn from_ntstatus(status: RawNtStatus) -> Self {
+        Self((status.bits() | FACILITY_NT_BIT) as _)
+    }
+
+    #[inline(always)]
+    pub const fn value(&self) -> HRESULT {
+        self.0
+    }
+
+    #[inline(always)]
+    pub const fn bits(&self) -> u32 {
+        self.0 as _
+    }
+
+    /// Equivalent to the `SUCCEEDED` macro.
+    #[inline(always)]
+    pub const fn is_success(&self) -> bool {
+        self.0 >= 0
+    }
+
+    /// Equivalent to the `FAILED` macro.
+    #[inline(always)]
+    pub const fn is_failure(&self) -> bool {
+        self.0 < 0
     }
 
     #[inline(always)]
-    pub fn is_success(&self) -> bool {
-        (*self as NTSTATUS) >= 0
+    pub const fn is_customer(&self) -> bool {
+        (self.bits() >> 29) & 1 == 1
     }
 
+    /// True if this HRESULT wraps an NTSTATUS.
     #[inline(always)]
-    pub fn is_info(&self) -> bool {
-        (self.bits() >> 30) == 1
+    pub const fn is_nt(&self) -> bool {
+        self.bits() & FACILITY_NT_BIT != 0
     }
 
+    /// Equivalent to the `HRESULT_FACILITY` macro, so includes [`FACILITY_NT_BIT`].
     #[inline(always)]
-    pub fn is_warning(&self) -> bool {
-        (self.bits() >> 30) == 2
+    pub const fn facility_bits(&self) -> u16 {
+        ((self.bits() >> 16) & 0x1FFF) as _
     }
 
     #[inline(always)]
-    pub fn is_error(&self) -> bool {
-        (self.bits() >> 30) == 3
+    pub const fn code(&self) -> u16 {
+        self.bits() as _
     }
 
+    /// The wrapped status, if this HRESULT carries [`FACILITY_NT_BIT`].
     #[inline(always)]
-    pub fn kind(&self) -> Option<NtStatusKind> {
-        if let Ok(kind) = NtStatusKind::try_from(self.bits() >> 30) {
-            Some(kind)
-        } else if self.is_success() {
-            Some(NtStatusKind::Success)
-        } else {
-            None
+    pub const fn to_ntstatus(&self) -> Option<RawNtStatus> {
+        match self.is_nt() {
+            true => Some(RawNtStatus::new((self.bits() & !FACILITY_NT_BIT) as _)),
+            false => None,
         }
     }
 }
 
-// #[cfg(feature = "unsafe_conversions")]
-// impl TryFrom<u32> for NtStatus {
-//     type Error = ();
-
-//     #[inline(always)]
-//     fn try_from(value: u32) -> Result<Self, Self::Error> {
-//         Ok(unsafe { core::mem::transmute(value as NTSTATUS) })
-//     }
-// }
-
-#[cfg(not(feature = "unsafe_conversions"))]
-impl TryFrom<u32> for NtStatus {
-    type Error = <NtStatus as TryFrom<NTSTATUS>>::Error;
+#[cfg(not(feature = "nosym"))]
+impl core::fmt::Debug for HResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("HResult")
+            .field(&format_args!("{:#010X}", self.bits()))
+            .finish()
+    }
+}
 
+impl From<HRESULT> for HResult {
     #[inline(always)]
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(value as NTSTATUS)
+    fn from(value: HRESULT) -> Self {
+        Self(value)
     }
 }
 
-#[cfg(feature = "unsafe_conversions")]
-impl From<u32> for NtStatus {
+impl From<HResult> for HRESULT {
     #[inline(always)]
-    fn from(value: u32) -> Self {
-        unsafe { core::mem::transmute(value as NTSTATUS) }
+    fn from(value: HResult) -> Self {
+        value.0
     }
 }
 
-#[cfg(feature = "unsafe_conversions")]
-impl From<i32> for NtStatus {
+impl From<RawNtStatus> for HResult {
     #[inline(always)]
-    fn from(value: i32) -> Self {
-        unsafe { core::mem::transmute(value as NTSTATUS) }
+    fn from(value: RawNtStatus) -> Self {
+        Self::from_ntstatus(value)
     }
 }
 
-#[cfg(feature = "unsafe_conversions")]
-impl From<NtStatus> for u32 {
+impl From<NtStatus> for HResult {
     #[inline(always)]
     fn from(value: NtStatus) -> Self {
-        unsafe { core::mem::transmute(value as NTSTATUS) }
+        Self::from_ntstatus(value.raw())
     }
 }
 
-#[cfg(feature = "unsafe_conversions")]
-impl From<NtStatus> for i32 {
+impl TryFrom<HResult> for RawNtStatus {
+    type Error = HResult;
+
     #[inline(always)]
-    fn from(value: NtStatus) -> Self {
-        unsafe { core::mem::transmute(value as NTSTATUS) }
+    fn try_from(value: HResult) -> Result<Self, Self::Error> {
+        value.to_ntstatus().ok_or(value)
+    }
+}
+
+impl TryFrom<HResult> for NtStatus {
+    type Error = HResult;
+
+    #[inline(always)]
+    fn try_from(value: HResult) -> Result<Self, Self::Error> {
+        value
+            .to_ntstatus()
+            .and_then(|status| status.known())
+            .ok_or(value)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{ntstatus::NtStatusKind, NtStatus};
+    use crate::{
+        hash::{hash, HashAlgorithm},
+        ntstatus::{
+            make_ntstatus, HResult, IntoNtResult, NtResult, NtStatusFacility, NtStatusKind,
+            ParseNtStatusError, RawNtStatus,
+        },
+        util::{all_distinct, catalog_entries},
+        NtStatus,
+    };
 
     #[test]
     #[cfg(not(feature = "nosym"))]
@@ -2517,17 +1176,12 @@ mod tests {
         assert!(NtStatus::STATUS_INVALID_ACCOUNT_NAME.is_error());
         assert!(NtStatus::STATUS_ACCESS_DENIED.is_error());
         assert!(NtStatus::STATUS_NOT_SUPPORTED.is_error());
-        assert!(NtStatus::STATUS_SUCCESS.is_success());
+        assert!(NtStatus::STATUS_SUCCESS.is_nt_success());
+        assert!(NtStatus::STATUS_SUCCESS.is_severity_success());
         assert!(NtStatus::STATUS_DATATYPE_MISALIGNMENT.is_warning());
-        assert!(!NtStatus::STATUS_DATATYPE_MISALIGNMENT.is_success());
-        assert_eq!(
-            NtStatus::STATUS_ACCESS_DENIED.kind(),
-            Some(NtStatusKind::Error)
-        );
-        assert_eq!(NtStatus::STATUS_SUCCESS.kind(), Some(NtStatusKind::Success));
-
-        #[cfg(feature = "unsafe_conversions")]
-        let _: u32 = NtStatus::STATUS_TPM_NOT_FULLWRITE.into();
+        assert!(!NtStatus::STATUS_DATATYPE_MISALIGNMENT.is_nt_success());
+        assert_eq!(NtStatus::STATUS_ACCESS_DENIED.kind(), NtStatusKind::Error);
+        assert_eq!(NtStatus::STATUS_SUCCESS.kind(), NtStatusKind::Success);
     }
 
     #[test]
@@ -2545,22 +1199,739 @@ mod tests {
             "DBG_NO_STATE_CHANGE"
         );
 
+        #[cfg(feature = "status-rpc")]
         assert_eq!(
             format!("{:?}", NtStatus::RPC_NT_BAD_STUB_DATA),
             "RPC_NT_BAD_STUB_DATA"
         );
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_ntstatus_display() {
+        use anyhow::__private::format;
+
+        #[cfg(any(not(feature = "nosym"), feature = "obf-names"))]
+        assert_eq!(
+            format!("{}", NtStatus::STATUS_ACCESS_DENIED),
+            "STATUS_ACCESS_DENIED (0xC0000022)"
+        );
+
+        #[cfg(all(feature = "nosym", not(feature = "obf-names")))]
+        {
+            assert_eq!(format!("{}", NtStatus::STATUS_ACCESS_DENIED), "0xC0000022");
+            assert_eq!(
+                format!("{:?}", NtStatus::STATUS_ACCESS_DENIED),
+                "0xC0000022"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "obf-names"))]
+    fn test_ntstatus_obf_names() {
+        use anyhow::__private::format;
+
+        assert_eq!(
+            format!("{:?}", NtStatus::STATUS_ACCESS_DENIED),
+            "STATUS_ACCESS_DENIED"
+        );
+        assert_eq!(
+            format!("{:?}", NtStatus::STATUS_ABANDONED_WAIT_0),
+            "STATUS_ABANDONED"
+        );
+        assert_eq!(
+            format!("{:?}", RawNtStatus::from(0xC0FF0001u32)),
+            "RawNtStatus(0xC0FF0001)"
+        );
+
+        // every name gets its own keystream, also under the published seed
+        let keys = crate::ntstatus::obf_name_keystream(0);
+        assert!(keys != [0; crate::ntstatus::OBF_NAME_MAX_LEN]);
+        assert!(keys != crate::ntstatus::obf_name_keystream(1));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_ntstatus_serde() {
+        use serde::{
+            de::value::{Error, I64Deserializer, StrDeserializer, U32Deserializer},
+            Deserialize,
+        };
+        use serde_test::{assert_ser_tokens, Configure, Token};
+
+        // binary formats carry the value
+        let bytes = bincode::serialize(&NtStatus::STATUS_ACCESS_DENIED).unwrap();
+        assert_eq!(bytes, 0xC0000022u32.to_le_bytes());
+        assert!(
+            bincode::deserialize::<NtStatus>(&bytes).unwrap() == NtStatus::STATUS_ACCESS_DENIED
+        );
+        assert_ser_tokens(
+            &NtStatus::STATUS_ACCESS_DENIED.compact(),
+            &[Token::U32(0xC0000022)],
+        );
+
+        // unknown values survive as RawNtStatus
+        let unknown = RawNtStatus::from(0xC0FF0001u32);
+        let bytes = bincode::serialize(&unknown).unwrap();
+        assert!(bincode::deserialize::<RawNtStatus>(&bytes).unwrap() == unknown);
+        assert!(bincode::deserialize::<NtStatus>(&bytes).is_err());
+        assert_ser_tokens(&unknown.readable(), &[Token::U32(0xC0FF0001)]);
+
+        // human-readable formats use the name when there is one
+        #[cfg(any(not(feature = "nosym"), feature = "obf-names"))]
+        assert_ser_tokens(
+            &NtStatus::STATUS_ABANDONED_WAIT_0.readable(),
+            &[Token::Str("STATUS_ABANDONED")],
+        );
+        #[cfg(all(feature = "nosym", not(feature = "obf-names")))]
+        assert_ser_tokens(&NtStatus::STATUS_ABANDONED.readable(), &[Token::U32(0x80)]);
+
+        let from_str = |s| RawNtStatus::deserialize(StrDeserializer::<Error>::new(s));
+        assert!(from_str("STATUS_ACCESS_DENIED") == Ok(NtStatus::STATUS_ACCESS_DENIED.raw()));
+        assert!(from_str("0xC0FF0001") == Ok(unknown));
+        assert!(from_str("STATUS_ACCESS_DENIE").is_err());
+
+        let raw = RawNtStatus::deserialize(U32Deserializer::<Error>::new(0xC0FF0001));
+        assert!(raw == Ok(unknown));
+        let raw = RawNtStatus::deserialize(I64Deserializer::<Error>::new(-1073741790));
+        assert!(raw == Ok(NtStatus::STATUS_ACCESS_DENIED.raw()));
+        assert!(NtStatus::deserialize(U32Deserializer::<Error>::new(0xC0FF0001)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_ntstatus_kind_serde() {
+        use serde::{
+            de::value::{Error, StrDeserializer},
+            Deserialize,
+        };
+        use serde_test::{assert_ser_tokens, Configure, Token};
+
+        for kind in [
+            NtStatusKind::Success,
+            NtStatusKind::Info,
+            NtStatusKind::Warning,
+            NtStatusKind::Error,
+        ] {
+            let bytes = bincode::serialize(&kind).unwrap();
+            assert!(bincode::deserialize::<NtStatusKind>(&bytes).unwrap() == kind);
+        }
+
+        assert_ser_tokens(&NtStatusKind::Warning.readable(), &[Token::Str("Warning")]);
+        assert!(
+            NtStatusKind::deserialize(StrDeserializer::<Error>::new("Info"))
+                == Ok(NtStatusKind::Info)
+        );
+        assert!(NtStatusKind::deserialize(StrDeserializer::<Error>::new("Fatal")).is_err());
+    }
+
+    #[test]
+    fn test_ntstatus_error() {
+        fn open() -> anyhow::Result<()> {
+            Err(NtStatus::STATUS_OBJECT_NAME_NOT_FOUND)?
+        }
+
+        let error = open().unwrap_err();
+        assert!(error.downcast_ref::<NtStatus>() == Some(&NtStatus::STATUS_OBJECT_NAME_NOT_FOUND));
+
+        fn call(status: u32) -> anyhow::Result<()> {
+            crate::nt_try!(status);
+            Ok(())
+        }
+
+        // nt_try! keeps the exact value, known or not
+        let error = call(0xC0000008).unwrap_err();
+        assert!(
+            error.downcast_ref::<RawNtStatus>() == Some(&NtStatus::STATUS_INVALID_HANDLE.raw())
+        );
+        let error = call(0xC0FF0001).unwrap_err();
+        assert!(error.downcast_ref::<RawNtStatus>() == Some(&RawNtStatus::from(0xC0FF0001u32)));
+
+        fn call_raw() -> anyhow::Result<()> {
+            0xC0FF0001u32.into_raw_nt_result()?;
+            Ok(())
+        }
+
+        let error = call_raw().unwrap_err();
+        assert!(error.downcast_ref::<RawNtStatus>() == Some(&RawNtStatus::from(0xC0FF0001u32)));
+
+        fn parse() -> anyhow::Result<NtStatus> {
+            Ok("STATUS_ACCESS_DENIE".parse::<NtStatus>()?)
+        }
+
+        let error = parse().unwrap_err();
+        assert!(error.downcast_ref::<ParseNtStatusError>() == Some(&ParseNtStatusError));
+        assert_eq!(
+            anyhow::__private::format!("{error}"),
+            "invalid NTSTATUS name or value"
+        );
+    }
+
     #[test]
     #[cfg(not(feature = "nosym"))]
     fn test_ntstatus_try_from_primitive() {
         let status = NtStatus::try_from(0xC0000008u32).expect("0xC0000008u32");
         assert_eq!(status, NtStatus::STATUS_INVALID_HANDLE);
 
-        let status = NtStatus::try_from(0xC00A000Du32).expect("0xC00A000Du32");
-        assert_eq!(status, NtStatus::STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE);
+        #[cfg(feature = "status-ctx")]
+        {
+            let status = NtStatus::try_from(0xC00A000Du32).expect("0xC00A000Du32");
+            assert_eq!(status, NtStatus::STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE);
+        }
+
+        #[cfg(feature = "status-tpm")]
+        {
+            let status = NtStatus::try_from(0xC0290046u32).expect("0xC00A000Du32");
+            assert_eq!(status, NtStatus::STATUS_TPM_NOT_FULLWRITE)
+        }
+    }
+
+    #[test]
+    fn test_ntstatus_from_bits() {
+        const STATUS: Option<NtStatus> = NtStatus::from_bits(0xC0000022);
+        assert!(STATUS == Some(NtStatus::STATUS_ACCESS_DENIED));
+
+        for status in NtStatus::ALL {
+            assert!(NtStatus::from_bits(status.bits()) == Some(*status));
+            assert!(NtStatus::try_from(status.bits()) == Ok(*status));
+        }
+
+        for bits in [0x00000004, 0x7FFFFFFF, 0xC0FF0001, 0xFFFFFFFF] {
+            assert!(NtStatus::from_bits(bits).is_none());
+            assert_eq!(NtStatus::try_from(bits).unwrap_err().number, bits as i32);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "nosym"))]
+    fn test_ntstatus_facility() {
+        let status = NtStatus::STATUS_ACCESS_DENIED;
+        assert_eq!(status.facility(), Some(NtStatusFacility::Null));
+        assert_eq!(status.code(), 0x0022);
+        assert!(!status.is_customer());
+
+        #[cfg(feature = "status-rpc")]
+        {
+            let status = NtStatus::RPC_NT_BAD_STUB_DATA;
+            assert_eq!(status.facility(), Some(NtStatusFacility::RpcStubs));
+            assert_eq!(status.code(), 0x000C);
+        }
+
+        #[cfg(feature = "status-tpm")]
+        assert_eq!(
+            NtStatus::STATUS_TPM_NOT_FULLWRITE.facility(),
+            Some(NtStatusFacility::Tpm)
+        );
+        #[cfg(feature = "status-ctx")]
+        assert_eq!(
+            NtStatus::STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE.facility(),
+            Some(NtStatusFacility::TerminalServer)
+        );
+        assert_eq!(
+            NtStatus::STATUS_SXS_SECTION_NOT_FOUND.facility(),
+            Some(NtStatusFacility::SxsErrorCode)
+        );
+        #[cfg(feature = "status-vhd")]
+        assert_eq!(
+            NtStatus::STATUS_VHD_DRIVE_FOOTER_MISSING.facility(),
+            Some(NtStatusFacility::Vhd)
+        );
+        assert_eq!(
+            NtStatus::STATUS_SPACES_REPAIRED.facility(),
+            Some(NtStatusFacility::Spaces)
+        );
+        assert_eq!(
+            NtStatus::STATUS_SMB_BAD_CLUSTER_DIALECT.facility(),
+            Some(NtStatusFacility::Smb)
+        );
+        assert_eq!(
+            NtStatus::STATUS_VSM_NOT_INITIALIZED.facility(),
+            Some(NtStatusFacility::Vsm)
+        );
+
+        const FACILITY: Option<NtStatusFacility> = NtStatus::STATUS_ACCESS_DENIED.facility();
+        const CODE: u16 = NtStatus::STATUS_ACCESS_DENIED.code();
+        const CUSTOMER: bool = NtStatus::STATUS_ACCESS_DENIED.is_customer();
+        assert!(FACILITY == Some(NtStatusFacility::Null) && CODE == 0x0022 && !CUSTOMER);
+
+        for bits in 0..=0xFFFu16 {
+            assert!(NtStatusFacility::from_bits(bits) == NtStatusFacility::try_from(bits).ok());
+        }
+    }
+
+    #[test]
+    fn test_make_ntstatus() {
+        let raw = make_ntstatus(NtStatusKind::Error, false, NtStatusFacility::Tpm, 0x46);
+        assert_eq!(raw as u32, 0xC0290046);
+        #[cfg(feature = "status-tpm")]
+        assert_eq!(
+            NtStatus::try_from(raw),
+            Ok(NtStatus::STATUS_TPM_NOT_FULLWRITE)
+        );
+
+        let raw = make_ntstatus(NtStatusKind::Success, false, NtStatusFacility::Null, 0);
+        assert_eq!(NtStatus::try_from(raw), Ok(NtStatus::STATUS_SUCCESS));
+
+        let raw = make_ntstatus(NtStatusKind::Warning, true, NtStatusFacility::Smb, 0x1234);
+        assert_eq!(raw as u32, 0xA05D1234);
+    }
+
+    #[test]
+    #[cfg(not(feature = "nosym"))]
+    fn test_raw_ntstatus() {
+        let raw = RawNtStatus::from(0xC0000022u32);
+        assert_eq!(raw.known(), Some(NtStatus::STATUS_ACCESS_DENIED));
+        assert_eq!(RawNtStatus::from(NtStatus::STATUS_ACCESS_DENIED), raw);
+        assert!(raw.is_error());
+        assert!(!raw.is_nt_success());
+
+        // Unlisted value in a known facility
+        let raw =
+            RawNtStatus::from_parts(NtStatusKind::Error, false, NtStatusFacility::Tpm, 0xFFFF);
+        assert_eq!(raw.known(), None);
+        assert!(NtStatus::from(raw) == NtStatus::STATUS_UNSUCCESSFUL);
+        assert_eq!(raw.kind(), NtStatusKind::Error);
+        assert_eq!(raw.facility(), Some(NtStatusFacility::Tpm));
+        assert_eq!(raw.code(), 0xFFFF);
+        assert_eq!(u32::from(raw), 0xC029FFFF);
+
+        // Customer codes round-trip losslessly
+        let raw = RawNtStatus::new(0xE1230042u32 as _);
+        assert!(raw.is_customer());
+        assert!(raw.is_error());
+        assert_eq!(raw.facility_bits(), 0x123);
+        assert_eq!(raw.facility(), None);
+        assert_eq!(raw.known(), None);
+        assert_eq!(i32::from(raw), 0xE1230042u32 as i32);
 
-        let status = NtStatus::try_from(0xC0290046u32).expect("0xC00A000Du32");
-        assert_eq!(status, NtStatus::STATUS_TPM_NOT_FULLWRITE)
+        let raw = RawNtStatus::new(0x00000123);
+        assert!(raw.is_nt_success());
+        assert_eq!(raw.kind(), NtStatusKind::Success);
+        assert_eq!(raw.known(), Some(NtStatus::STATUS_PROCESS_NOT_IN_JOB));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg(not(feature = "nosym"))]
+    fn test_raw_ntstatus_to_string() {
+        use anyhow::__private::format;
+        assert_eq!(
+            format!("{:?}", RawNtStatus::from(NtStatus::STATUS_ACCESS_DENIED)),
+            "STATUS_ACCESS_DENIED"
+        );
+        assert_eq!(
+            format!("{:?}", RawNtStatus::from(0xE1230042u32)),
+            "RawNtStatus(0xE1230042)"
+        );
+        assert_eq!(
+            format!("{}", RawNtStatus::from(NtStatus::STATUS_ACCESS_DENIED)),
+            "STATUS_ACCESS_DENIED (0xC0000022)"
+        );
+        assert_eq!(
+            format!("{}", RawNtStatus::from(0xE1230042u32)),
+            "0xE1230042"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "nosym"))]
+    fn test_ntstatus_severity_table() {
+        // (status, kind, NT_SUCCESS, severity success)
+        let table = [
+            (NtStatus::STATUS_SUCCESS, NtStatusKind::Success, true, true),
+            (NtStatus::STATUS_ALERTED, NtStatusKind::Success, true, true),
+            (NtStatus::STATUS_PENDING, NtStatusKind::Success, true, true),
+            (
+                NtStatus::STATUS_WAKE_SYSTEM,
+                NtStatusKind::Info,
+                true,
+                false,
+            ),
+            (
+                NtStatus::STATUS_SXS_RELEASE_ACTIVATION_CONTEXT,
+                NtStatusKind::Info,
+                true,
+                false,
+            ),
+            (
+                NtStatus::STATUS_GUARD_PAGE_VIOLATION,
+                NtStatusKind::Warning,
+                false,
+                false,
+            ),
+            (
+                NtStatus::STATUS_NO_MORE_FILES,
+                NtStatusKind::Warning,
+                false,
+                false,
+            ),
+            (
+                NtStatus::STATUS_ACCESS_DENIED,
+                NtStatusKind::Error,
+                false,
+                false,
+            ),
+            (
+                NtStatus::STATUS_SXS_SECTION_NOT_FOUND,
+                NtStatusKind::Error,
+                false,
+                false,
+            ),
+        ];
+
+        for (status, kind, nt_success, severity_success) in table {
+            assert_eq!(status.kind(), kind, "{status:?}");
+            assert_eq!(status.is_nt_success(), nt_success, "{status:?}");
+            assert_eq!(status.is_severity_success(), severity_success, "{status:?}");
+        }
+
+        for &status in NtStatus::ALL {
+            let value = status as i32;
+            let kind = status.kind();
+            assert_eq!(u32::from(kind), status.bits() >> 30, "{status:?}");
+            assert_eq!(status.is_nt_success(), value >= 0, "{status:?}");
+
+            let predicates = [
+                (status.is_severity_success(), NtStatusKind::Success),
+                (status.is_info(), NtStatusKind::Info),
+                (status.is_warning(), NtStatusKind::Warning),
+                (status.is_error(), NtStatusKind::Error),
+            ];
+            for (predicate, expected) in predicates {
+                assert_eq!(predicate, kind == expected, "{status:?}");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "messages")]
+    fn test_ntstatus_message() {
+        assert_eq!(
+            NtStatus::STATUS_ACCESS_DENIED.message(),
+            "A process has requested access to an object, but has not been granted those access rights."
+        );
+        assert_eq!(
+            RawNtStatus::from(NtStatus::STATUS_ACCESS_DENIED).message(),
+            Some(NtStatus::STATUS_ACCESS_DENIED.message())
+        );
+        assert_eq!(RawNtStatus::new(0xE1230042u32 as _).message(), None);
+
+        for status in NtStatus::ALL {
+            assert!(!status.message().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_hresult_from_ntstatus() {
+        let hr = HResult::from(NtStatus::STATUS_ACCESS_DENIED);
+        assert_eq!(hr.bits(), 0xD0000022);
+        assert!(hr.is_failure());
+        assert!(hr.is_nt());
+        assert!(!hr.is_customer());
+        assert_eq!(hr.facility_bits(), 0x1000);
+        assert_eq!(hr.code(), 0x22);
+        assert!(NtStatus::try_from(hr) == Ok(NtStatus::STATUS_ACCESS_DENIED));
+
+        let hr = HResult::from(NtStatus::STATUS_SUCCESS);
+        assert_eq!(hr.bits(), 0x10000000);
+        assert!(hr.is_success());
+        assert!(NtStatus::try_from(hr) == Ok(NtStatus::STATUS_SUCCESS));
+
+        // lossless for statuses the enum does not know
+        let raw = RawNtStatus::from(0xE1230042u32);
+        assert!(RawNtStatus::try_from(HResult::from(raw)) == Ok(raw));
+        assert!(NtStatus::try_from(HResult::from(raw)).is_err());
+
+        // E_ACCESSDENIED does not wrap an NTSTATUS
+        let hr = HResult::new(0x80070005u32 as _);
+        assert!(hr.is_failure());
+        assert!(!hr.is_nt());
+        assert_eq!(hr.facility_bits(), 7);
+        assert!(RawNtStatus::try_from(hr) == Err(hr));
+    }
+
+    #[test]
+    fn test_ntstatus_ok() {
+        assert!(NtStatus::STATUS_SUCCESS.ok().is_ok());
+        assert!(NtStatus::STATUS_PENDING.ok() == Ok(NtStatus::STATUS_PENDING));
+        assert!(NtStatus::STATUS_BUFFER_OVERFLOW.ok() == Err(NtStatus::STATUS_BUFFER_OVERFLOW));
+        assert!(NtStatus::STATUS_ACCESS_DENIED.ok() == Err(NtStatus::STATUS_ACCESS_DENIED));
+
+        assert!(0xC0000022u32.into_nt_result() == Err(NtStatus::STATUS_ACCESS_DENIED));
+        assert!(0x00000103.into_nt_result() == Ok(NtStatus::STATUS_PENDING.raw()));
+        assert!(0x40FF0001.into_nt_result() == Ok(RawNtStatus::new(0x40FF0001)));
+
+        // Unknown failures become STATUS_UNSUCCESSFUL, unless the raw result is asked for
+        assert!(0xC0FF0001u32.into_nt_result() == Err(NtStatus::STATUS_UNSUCCESSFUL));
+        assert!(0x80FF0001u32.into_nt_result() == Err(NtStatus::STATUS_UNSUCCESSFUL));
+        let error = 0xC0FF0001u32.into_raw_nt_result().unwrap_err();
+        assert_eq!(error.bits(), 0xC0FF0001);
+        assert!(error.known().is_none());
+        assert!(RawNtStatus::from(0xC0FF0001u32).ok() == Err(error));
+        assert!(
+            RawNtStatus::from(0xC0000022u32).into_nt_result()
+                == Err(NtStatus::STATUS_ACCESS_DENIED)
+        );
+    }
+
+    #[test]
+    fn test_nt_try() {
+        fn call(status: u32) -> NtResult<RawNtStatus> {
+            let status = crate::nt_try!(status);
+            Ok(status)
+        }
+
+        assert!(call(0x00000103) == Ok(NtStatus::STATUS_PENDING.raw()));
+        assert!(call(0x80000005) == Err(NtStatus::STATUS_BUFFER_OVERFLOW));
+        assert!(call(0xC0000008) == Err(NtStatus::STATUS_INVALID_HANDLE));
+        assert!(call(0xC0FF0001) == Err(NtStatus::STATUS_UNSUCCESSFUL));
+
+        // A raw error type keeps the exact value
+        fn call_raw(status: u32) -> NtResult<RawNtStatus, RawNtStatus> {
+            let status = crate::nt_try!(status);
+            Ok(status)
+        }
+
+        assert!(call_raw(0xC0000008) == Err(NtStatus::STATUS_INVALID_HANDLE.raw()));
+        assert!(call_raw(0xC0FF0001) == Err(RawNtStatus::new(0xC0FF0001u32 as _)));
+
+        // Callers match on the named variants
+        assert!(matches!(
+            call(0xC0000008),
+            Err(NtStatus::STATUS_INVALID_HANDLE)
+        ));
+    }
+
+    #[test]
+    fn test_ntstatus_from_str() {
+        let parse = |s: &str| s.parse::<NtStatus>();
+
+        assert!(parse("STATUS_ACCESS_DENIED") == Ok(NtStatus::STATUS_ACCESS_DENIED));
+        #[cfg(feature = "status-rpc")]
+        assert!(parse("RPC_NT_BAD_STUB_DATA") == Ok(NtStatus::RPC_NT_BAD_STUB_DATA));
+        assert!(parse("0xC0000022") == Ok(NtStatus::STATUS_ACCESS_DENIED));
+        assert!(parse("0Xc0000022") == Ok(NtStatus::STATUS_ACCESS_DENIED));
+        assert!(parse("-1073741790") == Ok(NtStatus::STATUS_ACCESS_DENIED));
+        assert!(parse("3221225506") == Ok(NtStatus::STATUS_ACCESS_DENIED));
+        assert!(parse("259") == Ok(NtStatus::STATUS_PENDING));
+
+        assert!(parse("STATUS_ACCESS_DENIE") == Err(ParseNtStatusError));
+        assert!(parse("0xC0FF0001") == Err(ParseNtStatusError));
+        assert!(parse("0x") == Err(ParseNtStatusError));
+        assert!(parse("-") == Err(ParseNtStatusError));
+        assert!(parse("") == Err(ParseNtStatusError));
+    }
+
+    #[test]
+    fn test_ntstatus_from_name() {
+        const STATUS: Option<NtStatus> = NtStatus::from_name("STATUS_INVALID_HANDLE");
+        assert!(STATUS == Some(NtStatus::STATUS_INVALID_HANDLE));
+        assert!(
+            NtStatus::from_name_hash(crate::hash_str!("STATUS_INVALID_HANDLE"))
+                == Some(NtStatus::STATUS_INVALID_HANDLE)
+        );
+
+        // Every catalog name hashes uniquely under the published seed, except with elf's 28 bits
+        if HashAlgorithm::DEFAULT != HashAlgorithm::Elf {
+            let algorithm = HashAlgorithm::DEFAULT;
+            assert!(all_distinct(catalog_entries().map(|(name, _)| {
+                algorithm.hash_str_with_seed(algorithm.seed(), name, 0..usize::MAX)
+            })));
+        }
+
+        // Under the build's own SEED, a hash shared by two statuses resolves to neither
+        for &(hash, status) in NtStatus::NAME_HASHES {
+            let ambiguous = NtStatus::NAME_HASHES
+                .iter()
+                .any(|&(other, other_status)| other == hash && other_status != status);
+            let expected = if ambiguous { None } else { Some(status) };
+            assert!(NtStatus::from_name_hash(hash) == expected);
+        }
+    }
+
+    fn name_hash_is_unique(hash: u32) -> bool {
+        NtStatus::NAME_HASHES
+            .iter()
+            .filter(|(other, _)| *other == hash)
+            .count()
+            == 1
+    }
+
+    #[test]
+    #[cfg(not(feature = "nosym"))]
+    fn test_ntstatus_name_roundtrip() {
+        for (name, (_, status)) in NtStatus::NAMES.iter().zip(NtStatus::NAME_HASHES) {
+            assert!(NtStatus::from_name(name) == Some(*status));
+            assert_eq!(name.parse(), Ok(*status));
+        }
+    }
+
+    #[test]
+    fn test_ntstatus_no_duplicate_values() {
+        for (i, status) in NtStatus::ALL.iter().enumerate() {
+            assert!(!NtStatus::ALL[i + 1..].contains(status));
+        }
+
+        // Only lines declared as `=TARGET` aliases may share a value in the catalog
+        assert!(all_distinct(
+            catalog_entries()
+                .filter(|(_, value)| !value.starts_with('='))
+                .map(|(_, value)| u32::from_str_radix(&value[2..], 16).unwrap())
+        ));
+    }
+
+    #[test]
+    fn test_ntstatus_aliases() {
+        assert!(NtStatus::STATUS_WAIT_0 == NtStatus::STATUS_SUCCESS);
+        assert!(NtStatus::STATUS_ABANDONED_WAIT_0 == NtStatus::STATUS_ABANDONED);
+        assert!(NtStatus::from_name("STATUS_ABANDONED_WAIT_0") == Some(NtStatus::STATUS_ABANDONED));
+    }
+
+    #[test]
+    #[cfg(not(feature = "nosym"))]
+    fn test_ntstatus_alias_names() {
+        assert_eq!(
+            NtStatus::STATUS_SUCCESS.aliases(),
+            ["STATUS_SUCCESS", "STATUS_WAIT_0"]
+        );
+        assert_eq!(
+            NtStatus::STATUS_ABANDONED_WAIT_0.aliases(),
+            ["STATUS_ABANDONED", "STATUS_ABANDONED_WAIT_0"]
+        );
+        assert_eq!(
+            NtStatus::STATUS_ACCESS_DENIED.aliases(),
+            ["STATUS_ACCESS_DENIED"]
+        );
+    }
+
+    #[test]
+    fn test_ntstatus_wait_index() {
+        assert!(RawNtStatus::STATUS_WAIT_N(0) == NtStatus::STATUS_WAIT_0.into());
+        assert!(RawNtStatus::STATUS_WAIT_N(63) == NtStatus::STATUS_WAIT_63.into());
+        assert!(RawNtStatus::STATUS_ABANDONED_WAIT_N(0) == NtStatus::STATUS_ABANDONED.into());
+        assert!(
+            RawNtStatus::STATUS_ABANDONED_WAIT_N(63) == NtStatus::STATUS_ABANDONED_WAIT_63.into()
+        );
+
+        for n in 0..=63 {
+            assert_eq!(RawNtStatus::STATUS_WAIT_N(n).as_wait_index(), Some(n));
+            assert_eq!(
+                RawNtStatus::STATUS_ABANDONED_WAIT_N(n).as_wait_index(),
+                Some(n)
+            );
+        }
+
+        assert_eq!(NtStatus::STATUS_WAIT_3.as_wait_index(), Some(3));
+        assert_eq!(NtStatus::STATUS_USER_APC.as_wait_index(), None);
+        assert_eq!(NtStatus::STATUS_TIMEOUT.as_wait_index(), None);
+        assert_eq!(NtStatus::STATUS_ACCESS_DENIED.as_wait_index(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ntstatus_wait_index_out_of_range() {
+        RawNtStatus::STATUS_WAIT_N(64);
+    }
+
+    #[test]
+    fn test_ntstatus_facility_statuses() {
+        let mut total = 0;
+        for facility in 0..=0xFFFu16 {
+            let Ok(facility) = NtStatusFacility::try_from(facility) else {
+                continue;
+            };
+
+            let statuses = facility.statuses();
+            assert!(statuses
+                .windows(2)
+                .all(|w| u32::from(w[0]) < u32::from(w[1])));
+            assert!(statuses
+                .iter()
+                .all(|status| status.facility() == Some(facility)));
+            total += statuses.len();
+        }
+
+        #[cfg(feature = "status-tpm")]
+        assert!(NtStatusFacility::Tpm
+            .statuses()
+            .contains(&NtStatus::STATUS_TPM_ERROR_MASK));
+        #[cfg(not(feature = "status-tpm"))]
+        assert!(NtStatusFacility::Tpm.statuses().is_empty());
+        assert!(total <= NtStatus::ALL.len());
+    }
+
+    #[test]
+    fn test_ntstatus_catalog_round_trip() {
+        for (name, value) in catalog_entries() {
+            if let Some(target) = value.strip_prefix('=') {
+                if !cfg!(feature = "nosym") || name_hash_is_unique(hash(name, 0..usize::MAX)) {
+                    assert!(NtStatus::from_name(name) == NtStatus::from_name(target));
+                }
+                continue;
+            }
+            let bits = u32::from_str_radix(&value[2..], 16).unwrap();
+
+            // statuses of compiled-out families are still carried losslessly as raw values
+            let raw = RawNtStatus::from(bits);
+            assert_eq!(raw.bits(), bits);
+            assert!(RawNtStatus::try_from(HResult::from(raw)) == Ok(raw));
+
+            match raw.known() {
+                // without names, a hash shared with another name may be ambiguous
+                Some(_)
+                    if cfg!(feature = "nosym")
+                        && !name_hash_is_unique(hash(name, 0..usize::MAX)) => {}
+                Some(status) => assert!(NtStatus::from_name(name) == Some(status)),
+                None => {
+                    assert!(!cfg!(feature = "status-all"), "{name} is missing");
+                    assert!(NtStatus::from_name(name).is_none());
+                    assert!(raw.ok() == Err(raw) || raw.is_nt_success());
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", not(feature = "nosym"), not(feature = "status-rpc")))]
+    fn test_ntstatus_compiled_out_family() {
+        use anyhow::__private::format;
+
+        let raw = RawNtStatus::from(0xC003000Cu32);
+        assert!(raw.known().is_none());
+        assert!(NtStatus::from(raw) == NtStatus::STATUS_UNSUCCESSFUL);
+        assert_eq!(raw.facility(), Some(NtStatusFacility::RpcStubs));
+        assert_eq!(format!("{raw:?}"), "RawNtStatus(0xC003000C)");
+        assert!(NtStatusFacility::RpcStubs.statuses().is_empty());
+    }
+
+    #[test]
+    #[cfg(not(all(feature = "status-rpc", feature = "status-tpm")))]
+    fn test_ntstatus_compiled_out_family_result() {
+        fn call(status: u32) -> NtResult<RawNtStatus, RawNtStatus> {
+            let status = crate::nt_try!(status);
+            Ok(status)
+        }
+
+        fn call_any(status: u32) -> anyhow::Result<RawNtStatus> {
+            Ok(crate::nt_try!(status))
+        }
+
+        // RPC_NT_BAD_STUB_DATA and STATUS_TPM_NOT_FULLWRITE keep their own value through nt_try!
+        // when compiled out, and only narrow into an NtStatus error
+        for bits in [0xC003000Cu32, 0xC0290046] {
+            let raw = RawNtStatus::from(bits);
+            if raw.known().is_some() {
+                continue;
+            }
+
+            assert!(raw.ok() == Err(raw));
+            assert!(bits.into_raw_nt_result() == Err(raw));
+            assert!(call(bits) == Err(raw));
+            assert!(HResult::from(call(bits).unwrap_err()).to_ntstatus() == Some(raw));
+            let error = call_any(bits).unwrap_err();
+            assert!(error.downcast_ref::<RawNtStatus>() == Some(&raw));
+            assert!(bits.into_nt_result() == Err(NtStatus::STATUS_UNSUCCESSFUL));
+        }
     }
 }
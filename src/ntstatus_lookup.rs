@@ -0,0 +1,13 @@
+// The perfect hash of `NtStatus::from_bits`, included by both build.rs and src/ntstatus.rs
+
+const LOOKUP_BUCKET_BITS: u32 = 10;
+const LOOKUP_SLOT_BITS: u32 = 12;
+
+#[inline(always)]
+const fn lookup_mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85EB_CA6B);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xC2B2_AE35);
+    h ^ (h >> 16)
+}
@@ -0,0 +1,264 @@
+use crate::{util::interp, NativeDuration};
+
+/// Source of randomness for [`SleepSchedule`] jitter.
+pub trait SleepRng {
+    fn next_u32(&mut self) -> u32;
+}
+
+/// Lets any `FnMut() -> u32`, such as a wrapper around `RtlRandomEx` or a borrowed generator, drive the jitter.
+impl<F: FnMut() -> u32> SleepRng for F {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self()
+    }
+}
+
+/// A small seedable generator, so schedules are reproducible. Any seed, including 0, is valid.
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Clone)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+impl SleepRng for SplitMix64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        ((z ^ (z >> 31)) >> 32) as u32
+    }
+}
+
+/// 1.0 in basis points, the unit jitter is drawn in.
+const BASIS: u64 = 10_000;
+
+/// Beacon-style sleeps: a base interval with percentage jitter and optional exponential backoff.
+///
+/// Every [`next_sleep`](Self::next_sleep) jitters the current interval by up to `jitter`% either way,
+/// then multiplies it by the backoff factor, capped at the backoff maximum.
+#[cfg_attr(not(feature = "nosym"), derive(Debug))]
+#[derive(Clone)]
+pub struct SleepSchedule<R = SplitMix64> {
+    base: NativeDuration,
+    current: NativeDuration,
+    jitter: u32,
+    factor: u32,
+    max: NativeDuration,
+    rng: R,
+}
+
+impl SleepSchedule {
+    /// A schedule jittered by a [`SplitMix64`] seeded with `seed`.
+    #[inline]
+    pub const fn seeded(base: NativeDuration, seed: u64) -> Self {
+        Self::new(base, SplitMix64::new(seed))
+    }
+}
+
+impl<R: SleepRng> SleepSchedule<R> {
+    /// A fixed `base` interval without jitter or backoff.
+    #[inline]
+    pub const fn new(base: NativeDuration, rng: R) -> Self {
+        Self {
+            base,
+            current: base,
+            jitter: 0,
+            factor: 1,
+            max: NativeDuration::MAX,
+            rng,
+        }
+    }
+
+    /// Spreads every sleep uniformly over `base ± percent%`, at most 100.
+    #[inline]
+    pub const fn with_jitter(mut self, percent: u32) -> Self {
+        assert!(percent <= 100, "jitter is a percentage of the interval");
+        self.jitter = percent;
+        self
+    }
+
+    /// Multiplies the interval by `factor`, at least 1, after every sleep, up to `max` before jitter.
+    ///
+    /// A base interval above `max` is capped from the first sleep on.
+    #[inline]
+    pub const fn with_backoff(mut self, factor: u32, max: NativeDuration) -> Self {
+        assert!(factor >= 1, "a backoff factor of 0 would stop sleeping");
+        self.factor = factor;
+        self.max = max;
+
+        if self.base.as_ticks() > max.as_ticks() {
+            self.base = max;
+        }
+        if self.current.as_ticks() > max.as_ticks() {
+            self.current = max;
+        }
+        self
+    }
+
+    /// The interval the next sleep is jittered around.
+    #[inline(always)]
+    pub const fn current(&self) -> NativeDuration {
+        self.current
+    }
+
+    /// Drops the backoff back to the base interval, e.g. after a successful check-in.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.current = self.base;
+    }
+
+    /// The next jittered sleep, advancing the backoff.
+    pub fn next_sleep(&mut self) -> NativeDuration {
+        let interval = self.current;
+        if self.factor != 1 {
+            self.current = interval.saturating_mul(self.factor).min(self.max);
+        }
+
+        if self.jitter == 0 || interval.is_infinite() {
+            return interval;
+        }
+
+        let spread = 2 * self.jitter * 100;
+        let offset = interp(self.rng.next_u32() >> 16, 0..1 << 16, 0..spread + 1);
+        let scale = BASIS - (self.jitter * 100) as u64 + offset as u64;
+        let ticks = interval.as_ticks() as u128 * scale as u128 / BASIS as u128;
+
+        NativeDuration::from_ticks(ticks.min(NativeDuration::MAX_TICKS as u128) as u64)
+    }
+}
+
+impl<R: SleepRng> Iterator for SleepSchedule<R> {
+    type Item = NativeDuration;
+
+    #[inline]
+    fn next(&mut self) -> Option<NativeDuration> {
+        Some(self.next_sleep())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        sleep_schedule::{SleepRng, SplitMix64},
+        NativeDuration, SleepSchedule,
+    };
+
+    #[test]
+    fn test_sleep_schedule_fixed() {
+        let mut schedule = SleepSchedule::seeded(NativeDuration::from_secs(5), 0);
+        for _ in 0..100 {
+            assert!(schedule.next_sleep() == NativeDuration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn test_sleep_schedule_seeded_is_deterministic() {
+        let schedule = SleepSchedule::seeded(NativeDuration::from_secs(60), 0x5EED).with_jitter(30);
+        assert!(schedule.clone().take(1000).eq(schedule.take(1000)));
+
+        let a = SleepSchedule::seeded(NativeDuration::from_secs(60), 1).with_jitter(30);
+        let b = SleepSchedule::seeded(NativeDuration::from_secs(60), 2).with_jitter(30);
+        assert!(!a.take(16).eq(b.take(16)));
+
+        let mut rng = SplitMix64::new(0);
+        assert_ne!(rng.next_u32(), rng.next_u32());
+    }
+
+    #[test]
+    fn test_sleep_schedule_jitter_bounds() {
+        let base = NativeDuration::from_secs(60);
+        for percent in [1, 10, 25, 50, 100] {
+            let (low, high) = (
+                base.as_ticks() * (100 - percent as u64) / 100,
+                base.as_ticks() * (100 + percent as u64) / 100,
+            );
+
+            let schedule = SleepSchedule::seeded(base, percent as u64).with_jitter(percent);
+            let (mut min, mut max) = (u64::MAX, 0);
+            for sleep in schedule.take(10_000) {
+                let ticks = sleep.as_ticks();
+                assert!((low..=high).contains(&ticks), "{percent}% jitter: {ticks}");
+                (min, max) = (min.min(ticks), max.max(ticks));
+            }
+
+            // The draws cover most of the window
+            let window = high - low;
+            assert!(min - low < window / 20 && high - max < window / 20);
+        }
+    }
+
+    #[test]
+    fn test_sleep_schedule_injected_rng() {
+        let base = NativeDuration::from_secs(10);
+
+        let mut lowest = SleepSchedule::new(base, || 0).with_jitter(20);
+        assert!(lowest.next_sleep() == NativeDuration::from_secs(8));
+
+        let mut highest = SleepSchedule::new(base, || u32::MAX).with_jitter(20);
+        let sleep = highest.next_sleep();
+        assert!(
+            sleep <= NativeDuration::from_secs(12) && sleep > NativeDuration::from_millis(11_990)
+        );
+
+        struct Middle;
+        impl SleepRng for Middle {
+            fn next_u32(&mut self) -> u32 {
+                1 << 31
+            }
+        }
+
+        let mut middle = SleepSchedule::new(base, Middle).with_jitter(20);
+        assert!(middle.next_sleep() == base);
+    }
+
+    #[test]
+    fn test_sleep_schedule_backoff() {
+        let mut schedule = SleepSchedule::seeded(NativeDuration::from_secs(1), 0)
+            .with_backoff(2, NativeDuration::from_secs(5));
+
+        for secs in [1, 2, 4, 5, 5, 5] {
+            assert!(schedule.next_sleep() == NativeDuration::from_secs(secs));
+        }
+
+        schedule.reset();
+        assert!(schedule.current() == NativeDuration::from_secs(1));
+        assert!(schedule.next_sleep() == NativeDuration::from_secs(1));
+
+        let mut saturating = SleepSchedule::seeded(NativeDuration::from_secs(1), 0)
+            .with_backoff(u32::MAX, NativeDuration::MAX)
+            .with_jitter(50);
+        for _ in 0..8 {
+            assert!(saturating.next_sleep() <= NativeDuration::MAX);
+        }
+        assert!(saturating.current() == NativeDuration::MAX);
+
+        let mut infinite = SleepSchedule::seeded(NativeDuration::INFINITE, 0).with_jitter(50);
+        assert!(infinite.next_sleep().is_infinite());
+
+        // The cap applies from the first sleep, and after a reset
+        let mut capped = SleepSchedule::seeded(NativeDuration::from_secs(10), 0)
+            .with_backoff(2, NativeDuration::from_secs(5));
+        assert!(capped.current() == NativeDuration::from_secs(5));
+        assert!(capped.next_sleep() == NativeDuration::from_secs(5));
+        capped.reset();
+        assert!(capped.next_sleep() == NativeDuration::from_secs(5));
+
+        let mut capped_infinite = SleepSchedule::seeded(NativeDuration::INFINITE, 0)
+            .with_backoff(1, NativeDuration::from_secs(60));
+        assert!(capped_infinite.next_sleep() == NativeDuration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sleep_schedule_zero_backoff() {
+        let _ = SleepSchedule::seeded(NativeDuration::from_secs(1), 0)
+            .with_backoff(0, NativeDuration::MAX);
+    }
+}
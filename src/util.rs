@@ -1,4 +1,5 @@
 use core::{iter::zip, ops::Range};
+#[cfg(windows)]
 use widestring::{U16CStr, Utf16Str};
 
 #[cfg(windows)]
@@ -23,6 +24,23 @@ pub const fn min(x: usize, y: usize) -> usize {
     }
 }
 
+#[inline]
+pub const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 #[cfg(windows)]
 #[inline]
 pub fn convert_unicode_unchecked(s: &UNICODE_STRING) -> Option<&Utf16Str> {
@@ -112,6 +130,62 @@ impl<Other: IntoIterator<Item = char>> EqIgnoreAsciiCase<Other> for UnicodeStrin
     }
 }
 
+/// Every `(name, value)` of `data/ntstatus.tsv`, where aliases have `=TARGET` as their value.
+#[cfg(test)]
+pub(crate) fn catalog_entries() -> impl Iterator<Item = (&'static str, &'static str)> {
+    include_str!("../data/ntstatus.tsv")
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split('\t');
+            (fields.next().unwrap(), fields.next().unwrap())
+        })
+}
+
+/// Whether `values` has no repeats, sorted in a fixed buffer since the tests also run without `alloc`.
+#[cfg(test)]
+pub(crate) fn all_distinct<T: Copy + Default + Ord>(values: impl IntoIterator<Item = T>) -> bool {
+    let mut buffer = [T::default(); 8192];
+    let mut len = 0;
+    for value in values {
+        buffer[len] = value;
+        len += 1;
+    }
+
+    let values = &mut buffer[..len];
+    values.sort_unstable();
+    values.windows(2).all(|pair| pair[0] != pair[1])
+}
+
+/// `s` encoded as UTF-16 in a fixed buffer.
+#[cfg(test)]
+pub(crate) struct Wide {
+    units: [u16; 64],
+    len: usize,
+}
+
+#[cfg(test)]
+impl core::ops::Deref for Wide {
+    type Target = [u16];
+
+    fn deref(&self) -> &[u16] {
+        &self.units[..self.len]
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn to_wide(s: &str) -> Wide {
+    let mut wide = Wide {
+        units: [0; 64],
+        len: 0,
+    };
+    for unit in s.encode_utf16() {
+        wide.units[wide.len] = unit;
+        wide.len += 1;
+    }
+    wide
+}
+
 #[cfg(test)]
 mod tests {
     use crate::util::{interp, EqIgnoreAsciiCase};
@@ -140,4 +214,12 @@ mod tests {
         assert_eq!(min(10, 11), 10);
         assert_eq!(min(11, 10), 10);
     }
+
+    #[test]
+    fn test_const_str_eq() {
+        use crate::util::str_eq;
+        assert!(str_eq("STATUS_SUCCESS", "STATUS_SUCCESS"));
+        assert!(!str_eq("STATUS_SUCCESS", "STATUS_SUCCES"));
+        assert!(!str_eq("STATUS_SUCCESS", "status_success"));
+    }
 }
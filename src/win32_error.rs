@@ -0,0 +1,2364 @@
+use crate::ntstatus::{NtStatus, RawNtStatus};
+
+macro_rules! define_win32_errors {
+    ($($name:ident = $value:literal,)*) => {
+        impl Win32Error {
+            $(pub const $name: Self = Self($value);)*
+
+            /// The `winerror.h` name of this code, if it is one this crate knows.
+            #[cfg(not(feature = "nosym"))]
+            pub const fn name(&self) -> Option<&'static str> {
+                match self.0 {
+                    $($value => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+/// A Win32 error code, as returned by `GetLastError`.
+///
+/// Every 32-bit value is valid; the associated constants only name the codes this crate maps to.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Win32Error(u32);
+
+define_win32_errors! {
+    ERROR_SUCCESS = 0,
+    ERROR_INVALID_FUNCTION = 1,
+    ERROR_FILE_NOT_FOUND = 2,
+    ERROR_PATH_NOT_FOUND = 3,
+    ERROR_TOO_MANY_OPEN_FILES = 4,
+    ERROR_ACCESS_DENIED = 5,
+    ERROR_INVALID_HANDLE = 6,
+    ERROR_NOT_ENOUGH_MEMORY = 8,
+    ERROR_BAD_ENVIRONMENT = 10,
+    ERROR_INVALID_DATA = 13,
+    ERROR_OUTOFMEMORY = 14,
+    ERROR_NOT_SAME_DEVICE = 17,
+    ERROR_NO_MORE_FILES = 18,
+    ERROR_WRITE_PROTECT = 19,
+    ERROR_NOT_READY = 21,
+    ERROR_BAD_COMMAND = 22,
+    ERROR_CRC = 23,
+    ERROR_BAD_LENGTH = 24,
+    ERROR_SECTOR_NOT_FOUND = 27,
+    ERROR_OUT_OF_PAPER = 28,
+    ERROR_GEN_FAILURE = 31,
+    ERROR_SHARING_VIOLATION = 32,
+    ERROR_LOCK_VIOLATION = 33,
+    ERROR_WRONG_DISK = 34,
+    ERROR_HANDLE_EOF = 38,
+    ERROR_NOT_SUPPORTED = 50,
+    ERROR_REM_NOT_LIST = 51,
+    ERROR_DUP_NAME = 52,
+    ERROR_BAD_NETPATH = 53,
+    ERROR_NETWORK_BUSY = 54,
+    ERROR_DEV_NOT_EXIST = 55,
+    ERROR_TOO_MANY_CMDS = 56,
+    ERROR_ADAP_HDW_ERR = 57,
+    ERROR_BAD_NET_RESP = 58,
+    ERROR_UNEXP_NET_ERR = 59,
+    ERROR_BAD_REM_ADAP = 60,
+    ERROR_PRINTQ_FULL = 61,
+    ERROR_NO_SPOOL_SPACE = 62,
+    ERROR_PRINT_CANCELLED = 63,
+    ERROR_NETNAME_DELETED = 64,
+    ERROR_NETWORK_ACCESS_DENIED = 65,
+    ERROR_BAD_DEV_TYPE = 66,
+    ERROR_BAD_NET_NAME = 67,
+    ERROR_TOO_MANY_NAMES = 68,
+    ERROR_TOO_MANY_SESS = 69,
+    ERROR_SHARING_PAUSED = 70,
+    ERROR_REQ_NOT_ACCEP = 71,
+    ERROR_REDIR_PAUSED = 72,
+    ERROR_FILE_EXISTS = 80,
+    ERROR_CANNOT_MAKE = 82,
+    ERROR_INVALID_PASSWORD = 86,
+    ERROR_INVALID_PARAMETER = 87,
+    ERROR_NET_WRITE_FAULT = 88,
+    ERROR_BROKEN_PIPE = 109,
+    ERROR_DISK_FULL = 112,
+    ERROR_SEM_TIMEOUT = 121,
+    ERROR_INSUFFICIENT_BUFFER = 122,
+    ERROR_INVALID_NAME = 123,
+    ERROR_INVALID_LEVEL = 124,
+    ERROR_MOD_NOT_FOUND = 126,
+    ERROR_PROC_NOT_FOUND = 127,
+    ERROR_DIR_NOT_EMPTY = 145,
+    ERROR_LABEL_TOO_LONG = 154,
+    ERROR_SIGNAL_REFUSED = 156,
+    ERROR_NOT_LOCKED = 158,
+    ERROR_BAD_PATHNAME = 161,
+    ERROR_BUSY = 170,
+    ERROR_INVALID_ORDINAL = 182,
+    ERROR_ALREADY_EXISTS = 183,
+    ERROR_BAD_EXE_FORMAT = 193,
+    ERROR_ENVVAR_NOT_FOUND = 203,
+    ERROR_FILENAME_EXCED_RANGE = 206,
+    ERROR_FILE_CHECKED_OUT = 220,
+    ERROR_CHECKOUT_REQUIRED = 221,
+    ERROR_BAD_FILE_TYPE = 222,
+    ERROR_FILE_TOO_LARGE = 223,
+    ERROR_FORMS_AUTH_REQUIRED = 224,
+    ERROR_VIRUS_INFECTED = 225,
+    ERROR_VIRUS_DELETED = 226,
+    ERROR_BAD_PIPE = 230,
+    ERROR_PIPE_BUSY = 231,
+    ERROR_NO_DATA = 232,
+    ERROR_PIPE_NOT_CONNECTED = 233,
+    ERROR_MORE_DATA = 234,
+    ERROR_VC_DISCONNECTED = 240,
+    ERROR_INVALID_EA_NAME = 254,
+    ERROR_EA_LIST_INCONSISTENT = 255,
+    ERROR_NO_MORE_ITEMS = 259,
+    ERROR_DIRECTORY = 267,
+    ERROR_EAS_NOT_SUPPORTED = 282,
+    ERROR_NOT_OWNER = 288,
+    ERROR_TOO_MANY_POSTS = 298,
+    ERROR_PARTIAL_COPY = 299,
+    ERROR_OPLOCK_NOT_GRANTED = 300,
+    ERROR_INVALID_OPLOCK_PROTOCOL = 301,
+    ERROR_DISK_TOO_FRAGMENTED = 302,
+    ERROR_INVALID_LOCK_RANGE = 307,
+    ERROR_IMAGE_SUBSYSTEM_NOT_PRESENT = 308,
+    ERROR_NOTIFICATION_GUID_ALREADY_DEFINED = 309,
+    ERROR_INVALID_EXCEPTION_HANDLER = 310,
+    ERROR_DUPLICATE_PRIVILEGES = 311,
+    ERROR_NO_RANGES_PROCESSED = 312,
+    ERROR_NOT_ALLOWED_ON_SYSTEM_FILE = 313,
+    ERROR_DISK_RESOURCES_EXHAUSTED = 314,
+    ERROR_INVALID_TOKEN = 315,
+    ERROR_DEVICE_FEATURE_NOT_SUPPORTED = 316,
+    ERROR_MR_MID_NOT_FOUND = 317,
+    ERROR_UNDEFINED_SCOPE = 319,
+    ERROR_INVALID_CAP = 320,
+    ERROR_DEVICE_UNREACHABLE = 321,
+    ERROR_DEVICE_NO_RESOURCES = 322,
+    ERROR_DATA_CHECKSUM_ERROR = 323,
+    ERROR_INTERMIXED_KERNEL_EA_OPERATION = 324,
+    ERROR_FILE_LEVEL_TRIM_NOT_SUPPORTED = 326,
+    ERROR_OFFSET_ALIGNMENT_VIOLATION = 327,
+    ERROR_INVALID_FIELD_IN_PARAMETER_LIST = 328,
+    ERROR_OPERATION_IN_PROGRESS = 329,
+    ERROR_BAD_DEVICE_PATH = 330,
+    ERROR_TOO_MANY_DESCRIPTORS = 331,
+    ERROR_SCRUB_DATA_DISABLED = 332,
+    ERROR_NOT_REDUNDANT_STORAGE = 333,
+    ERROR_RESIDENT_FILE_NOT_SUPPORTED = 334,
+    ERROR_COMPRESSED_FILE_NOT_SUPPORTED = 335,
+    ERROR_DIRECTORY_NOT_SUPPORTED = 336,
+    ERROR_NOT_READ_FROM_COPY = 337,
+    ERROR_FT_WRITE_FAILURE = 338,
+    ERROR_FT_DI_SCAN_REQUIRED = 339,
+    ERROR_INVALID_KERNEL_INFO_VERSION = 340,
+    ERROR_INVALID_PEP_INFO_VERSION = 341,
+    ERROR_OBJECT_NOT_EXTERNALLY_BACKED = 342,
+    ERROR_EXTERNAL_BACKING_PROVIDER_UNKNOWN = 343,
+    ERROR_COMPRESSION_NOT_BENEFICIAL = 344,
+    ERROR_STORAGE_TOPOLOGY_ID_MISMATCH = 345,
+    ERROR_BLOCKED_BY_PARENTAL_CONTROLS = 346,
+    ERROR_BLOCK_TOO_MANY_REFERENCES = 347,
+    ERROR_MARKED_TO_DISALLOW_WRITES = 348,
+    ERROR_ENCLAVE_FAILURE = 349,
+    ERROR_NETWORK_ACCESS_DENIED_EDP = 354,
+    ERROR_DEVICE_HINT_NAME_BUFFER_TOO_SMALL = 355,
+    ERROR_DEVICE_IN_MAINTENANCE = 359,
+    ERROR_NOT_SUPPORTED_ON_DAX = 360,
+    ERROR_DAX_MAPPING_EXISTS = 361,
+    ERROR_CLOUD_FILE_PROVIDER_NOT_RUNNING = 362,
+    ERROR_CLOUD_FILE_METADATA_CORRUPT = 363,
+    ERROR_CLOUD_FILE_METADATA_TOO_LARGE = 364,
+    ERROR_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE = 365,
+    ERROR_CHILD_PROCESS_BLOCKED = 367,
+    ERROR_STORAGE_LOST_DATA_PERSISTENCE = 368,
+    ERROR_DEVICE_HARDWARE_ERROR = 483,
+    ERROR_INVALID_ADDRESS = 487,
+    ERROR_PIPE_CONNECTED = 535,
+    ERROR_PIPE_LISTENING = 536,
+    ERROR_VERIFIER_STOP = 537,
+    ERROR_ABIOS_ERROR = 538,
+    ERROR_TIMER_NOT_CANCELED = 541,
+    ERROR_UNWIND = 542,
+    ERROR_BAD_STACK = 543,
+    ERROR_INVALID_UNWIND_TARGET = 544,
+    ERROR_INVALID_PORT_ATTRIBUTES = 545,
+    ERROR_PORT_MESSAGE_TOO_LONG = 546,
+    ERROR_INVALID_QUOTA_LOWER = 547,
+    ERROR_DEVICE_ALREADY_ATTACHED = 548,
+    ERROR_INSTRUCTION_MISALIGNMENT = 549,
+    ERROR_PROFILING_NOT_STARTED = 550,
+    ERROR_PROFILING_NOT_STOPPED = 551,
+    ERROR_COULD_NOT_INTERPRET = 552,
+    ERROR_PROFILING_AT_LIMIT = 553,
+    ERROR_CANT_WAIT = 554,
+    ERROR_CANT_TERMINATE_SELF = 555,
+    ERROR_UNEXPECTED_MM_CREATE_ERR = 556,
+    ERROR_UNEXPECTED_MM_MAP_ERROR = 557,
+    ERROR_UNEXPECTED_MM_EXTEND_ERR = 558,
+    ERROR_BAD_FUNCTION_TABLE = 559,
+    ERROR_NO_GUID_TRANSLATION = 560,
+    ERROR_INVALID_LDT_SIZE = 561,
+    ERROR_INVALID_LDT_OFFSET = 563,
+    ERROR_INVALID_LDT_DESCRIPTOR = 564,
+    ERROR_TOO_MANY_THREADS = 565,
+    ERROR_THREAD_NOT_IN_PROCESS = 566,
+    ERROR_PAGEFILE_QUOTA_EXCEEDED = 567,
+    ERROR_LOGON_SERVER_CONFLICT = 568,
+    ERROR_SYNCHRONIZATION_REQUIRED = 569,
+    ERROR_NET_OPEN_FAILED = 570,
+    ERROR_IO_PRIVILEGE_FAILED = 571,
+    ERROR_CONTROL_C_EXIT = 572,
+    ERROR_MISSING_SYSTEMFILE = 573,
+    ERROR_UNHANDLED_EXCEPTION = 574,
+    ERROR_APP_INIT_FAILURE = 575,
+    ERROR_PAGEFILE_CREATE_FAILED = 576,
+    ERROR_INVALID_IMAGE_HASH = 577,
+    ERROR_NO_PAGEFILE = 578,
+    ERROR_ILLEGAL_FLOAT_CONTEXT = 579,
+    ERROR_NO_EVENT_PAIR = 580,
+    ERROR_DOMAIN_CTRLR_CONFIG_ERROR = 581,
+    ERROR_ILLEGAL_CHARACTER = 582,
+    ERROR_UNDEFINED_CHARACTER = 583,
+    ERROR_FLOPPY_VOLUME = 584,
+    ERROR_BIOS_FAILED_TO_CONNECT_INTERRUPT = 585,
+    ERROR_BACKUP_CONTROLLER = 586,
+    ERROR_MUTANT_LIMIT_EXCEEDED = 587,
+    ERROR_FS_DRIVER_REQUIRED = 588,
+    ERROR_CANNOT_LOAD_REGISTRY_FILE = 589,
+    ERROR_DEBUG_ATTACH_FAILED = 590,
+    ERROR_SYSTEM_PROCESS_TERMINATED = 591,
+    ERROR_DATA_NOT_ACCEPTED = 592,
+    ERROR_VDM_HARD_ERROR = 593,
+    ERROR_DRIVER_CANCEL_TIMEOUT = 594,
+    ERROR_REPLY_MESSAGE_MISMATCH = 595,
+    ERROR_LOST_WRITEBEHIND_DATA = 596,
+    ERROR_CLIENT_SERVER_PARAMETERS_INVALID = 597,
+    ERROR_NOT_TINY_STREAM = 598,
+    ERROR_STACK_OVERFLOW_READ = 599,
+    ERROR_CONVERT_TO_LARGE = 600,
+    ERROR_FOUND_OUT_OF_SCOPE = 601,
+    ERROR_ALLOCATE_BUCKET = 602,
+    ERROR_MARSHALL_OVERFLOW = 603,
+    ERROR_INVALID_VARIANT = 604,
+    ERROR_BAD_COMPRESSION_BUFFER = 605,
+    ERROR_AUDIT_FAILED = 606,
+    ERROR_TIMER_RESOLUTION_NOT_SET = 607,
+    ERROR_INSUFFICIENT_LOGON_INFO = 608,
+    ERROR_BAD_DLL_ENTRYPOINT = 609,
+    ERROR_BAD_SERVICE_ENTRYPOINT = 610,
+    ERROR_IP_ADDRESS_CONFLICT1 = 611,
+    ERROR_IP_ADDRESS_CONFLICT2 = 612,
+    ERROR_REGISTRY_QUOTA_LIMIT = 613,
+    ERROR_NO_CALLBACK_ACTIVE = 614,
+    ERROR_PWD_TOO_SHORT = 615,
+    ERROR_PWD_TOO_RECENT = 616,
+    ERROR_PWD_HISTORY_CONFLICT = 617,
+    ERROR_UNSUPPORTED_COMPRESSION = 618,
+    ERROR_INVALID_HW_PROFILE = 619,
+    ERROR_INVALID_PLUGPLAY_DEVICE_PATH = 620,
+    ERROR_QUOTA_LIST_INCONSISTENT = 621,
+    ERROR_EVALUATION_EXPIRATION = 622,
+    ERROR_ILLEGAL_DLL_RELOCATION = 623,
+    ERROR_DLL_INIT_FAILED_LOGOFF = 624,
+    ERROR_VALIDATE_CONTINUE = 625,
+    ERROR_NO_MORE_MATCHES = 626,
+    ERROR_RANGE_LIST_CONFLICT = 627,
+    ERROR_SERVER_SID_MISMATCH = 628,
+    ERROR_CANT_ENABLE_DENY_ONLY = 629,
+    ERROR_FLOAT_MULTIPLE_FAULTS = 630,
+    ERROR_FLOAT_MULTIPLE_TRAPS = 631,
+    ERROR_NOINTERFACE = 632,
+    ERROR_DRIVER_FAILED_SLEEP = 633,
+    ERROR_CORRUPT_SYSTEM_FILE = 634,
+    ERROR_COMMITMENT_MINIMUM = 635,
+    ERROR_PNP_RESTART_ENUMERATION = 636,
+    ERROR_SYSTEM_IMAGE_BAD_SIGNATURE = 637,
+    ERROR_PNP_REBOOT_REQUIRED = 638,
+    ERROR_INSUFFICIENT_POWER = 639,
+    ERROR_MULTIPLE_FAULT_VIOLATION = 640,
+    ERROR_SYSTEM_SHUTDOWN = 641,
+    ERROR_PORT_NOT_SET = 642,
+    ERROR_DS_VERSION_CHECK_FAILURE = 643,
+    ERROR_RANGE_NOT_FOUND = 644,
+    ERROR_NOT_SAFE_MODE_DRIVER = 646,
+    ERROR_FAILED_DRIVER_ENTRY = 647,
+    ERROR_DEVICE_ENUMERATION_ERROR = 648,
+    ERROR_MOUNT_POINT_NOT_RESOLVED = 649,
+    ERROR_INVALID_DEVICE_OBJECT_PARAMETER = 650,
+    ERROR_MCA_OCCURED = 651,
+    ERROR_DRIVER_DATABASE_ERROR = 652,
+    ERROR_SYSTEM_HIVE_TOO_LARGE = 653,
+    ERROR_DRIVER_FAILED_PRIOR_UNLOAD = 654,
+    ERROR_VOLSNAP_PREPARE_HIBERNATE = 655,
+    ERROR_HIBERNATION_FAILURE = 656,
+    ERROR_PWD_TOO_LONG = 657,
+    ERROR_FILE_SYSTEM_LIMITATION = 665,
+    ERROR_ASSERTION_FAILURE = 668,
+    ERROR_WOW_ASSERTION = 670,
+    ERROR_WAKE_SYSTEM_DEBUGGER = 675,
+    ERROR_HANDLES_CLOSED = 676,
+    ERROR_EXTRANEOUS_INFORMATION = 677,
+    ERROR_RXACT_COMMIT_NECESSARY = 678,
+    ERROR_GUID_SUBSTITUTION_MADE = 680,
+    ERROR_STOPPED_ON_SYMLINK = 681,
+    ERROR_LONGJUMP = 682,
+    ERROR_WAKE_SYSTEM = 730,
+    ERROR_WAIT_1 = 731,
+    ERROR_WAIT_2 = 732,
+    ERROR_WAIT_3 = 733,
+    ERROR_WAIT_63 = 734,
+    ERROR_ABANDONED_WAIT_0 = 735,
+    ERROR_ABANDONED_WAIT_63 = 736,
+    ERROR_USER_APC = 737,
+    ERROR_KERNEL_APC = 738,
+    ERROR_ALERTED = 739,
+    ERROR_ELEVATION_REQUIRED = 740,
+    ERROR_REPARSE = 741,
+    ERROR_OPLOCK_BREAK_IN_PROGRESS = 742,
+    ERROR_VOLUME_MOUNTED = 743,
+    ERROR_RXACT_COMMITTED = 744,
+    ERROR_NOTIFY_CLEANUP = 745,
+    ERROR_PRIMARY_TRANSPORT_CONNECT_FAILED = 746,
+    ERROR_PAGE_FAULT_TRANSITION = 747,
+    ERROR_PAGE_FAULT_DEMAND_ZERO = 748,
+    ERROR_PAGE_FAULT_COPY_ON_WRITE = 749,
+    ERROR_PAGE_FAULT_GUARD_PAGE = 750,
+    ERROR_PAGE_FAULT_PAGING_FILE = 751,
+    ERROR_CACHE_PAGE_LOCKED = 752,
+    ERROR_CRASH_DUMP = 753,
+    ERROR_BUFFER_ALL_ZEROS = 754,
+    ERROR_REPARSE_OBJECT = 755,
+    ERROR_RESOURCE_REQUIREMENTS_CHANGED = 756,
+    ERROR_TRANSLATION_COMPLETE = 757,
+    ERROR_NOTHING_TO_TERMINATE = 758,
+    ERROR_PROCESS_NOT_IN_JOB = 759,
+    ERROR_PROCESS_IN_JOB = 760,
+    ERROR_VOLSNAP_HIBERNATE_READY = 761,
+    ERROR_FSFILTER_OP_COMPLETED_SUCCESSFULLY = 762,
+    ERROR_INTERRUPT_VECTOR_ALREADY_CONNECTED = 763,
+    ERROR_INTERRUPT_STILL_CONNECTED = 764,
+    ERROR_WAIT_FOR_OPLOCK = 765,
+    ERROR_CALLBACK_POP_STACK = 768,
+    ERROR_COMPRESSION_DISABLED = 769,
+    ERROR_NOT_CAPABLE = 775,
+    ERROR_REQUEST_OUT_OF_SEQUENCE = 776,
+    ERROR_MEMORY_HARDWARE = 779,
+    ERROR_DISK_REPAIR_DISABLED = 780,
+    ERROR_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE = 781,
+    ERROR_MCA_EXCEPTION = 784,
+    ERROR_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY = 786,
+    ERROR_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED = 788,
+    ERROR_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR = 789,
+    ERROR_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR = 790,
+    ERROR_BAD_MCFG_TABLE = 791,
+    ERROR_DISK_REPAIR_REDIRECTED = 792,
+    ERROR_DISK_REPAIR_UNSUCCESSFUL = 793,
+    ERROR_CORRUPT_LOG_OVERFULL = 794,
+    ERROR_CORRUPT_LOG_CORRUPTED = 795,
+    ERROR_CORRUPT_LOG_UNAVAILABLE = 796,
+    ERROR_CORRUPT_LOG_DELETED_FULL = 797,
+    ERROR_CORRUPT_LOG_CLEARED = 798,
+    ERROR_ORPHAN_NAME_EXHAUSTED = 799,
+    ERROR_OPLOCK_SWITCHED_TO_NEW_HANDLE = 800,
+    ERROR_CANNOT_BREAK_OPLOCK = 802,
+    ERROR_OPLOCK_HANDLE_CLOSED = 803,
+    ERROR_INVALID_ACE_CONDITION = 805,
+    ERROR_FILE_HANDLE_REVOKED = 806,
+    ERROR_ENCRYPTED_IO_NOT_POSSIBLE = 808,
+    ERROR_FILE_METADATA_OPTIMIZATION_IN_PROGRESS = 809,
+    ERROR_QUOTA_ACTIVITY = 810,
+    ERROR_HANDLE_REVOKED = 811,
+    ERROR_CALLBACK_INVOKE_INLINE = 812,
+    ERROR_CPU_SET_INVALID = 813,
+    ERROR_OPERATION_ABORTED = 995,
+    ERROR_IO_PENDING = 997,
+    ERROR_NOACCESS = 998,
+    ERROR_SWAPERROR = 999,
+    ERROR_STACK_OVERFLOW = 1001,
+    ERROR_UNRECOGNIZED_VOLUME = 1005,
+    ERROR_FILE_INVALID = 1006,
+    ERROR_FULLSCREEN_MODE = 1007,
+    ERROR_NO_TOKEN = 1008,
+    ERROR_BADDB = 1009,
+    ERROR_REGISTRY_IO_FAILED = 1016,
+    ERROR_NOT_REGISTRY_FILE = 1017,
+    ERROR_KEY_DELETED = 1018,
+    ERROR_NO_LOG_SPACE = 1019,
+    ERROR_KEY_HAS_CHILDREN = 1020,
+    ERROR_CHILD_MUST_BE_VOLATILE = 1021,
+    ERROR_NOTIFY_ENUM_DIR = 1022,
+    ERROR_SERVICE_ALREADY_RUNNING = 1056,
+    ERROR_SERVICE_DISABLED = 1058,
+    ERROR_END_OF_MEDIA = 1100,
+    ERROR_FILEMARK_DETECTED = 1101,
+    ERROR_BEGINNING_OF_MEDIA = 1102,
+    ERROR_SETMARK_DETECTED = 1103,
+    ERROR_NO_DATA_DETECTED = 1104,
+    ERROR_PARTITION_FAILURE = 1105,
+    ERROR_INVALID_BLOCK_LENGTH = 1106,
+    ERROR_DEVICE_NOT_PARTITIONED = 1107,
+    ERROR_UNABLE_TO_LOCK_MEDIA = 1108,
+    ERROR_UNABLE_TO_UNLOAD_MEDIA = 1109,
+    ERROR_MEDIA_CHANGED = 1110,
+    ERROR_BUS_RESET = 1111,
+    ERROR_NO_MEDIA_IN_DRIVE = 1112,
+    ERROR_NO_UNICODE_TRANSLATION = 1113,
+    ERROR_DLL_INIT_FAILED = 1114,
+    ERROR_SHUTDOWN_IN_PROGRESS = 1115,
+    ERROR_IO_DEVICE = 1117,
+    ERROR_SERIAL_NO_DEVICE = 1118,
+    ERROR_IRQ_BUSY = 1119,
+    ERROR_FLOPPY_ID_MARK_NOT_FOUND = 1122,
+    ERROR_FLOPPY_WRONG_CYLINDER = 1123,
+    ERROR_FLOPPY_UNKNOWN_ERROR = 1124,
+    ERROR_FLOPPY_BAD_REGISTERS = 1125,
+    ERROR_DISK_RECALIBRATE_FAILED = 1126,
+    ERROR_DISK_OPERATION_FAILED = 1127,
+    ERROR_DISK_RESET_FAILED = 1128,
+    ERROR_EOM_OVERFLOW = 1129,
+    ERROR_NOT_ENOUGH_SERVER_MEMORY = 1130,
+    ERROR_POSSIBLE_DEADLOCK = 1131,
+    ERROR_MAPPED_ALIGNMENT = 1132,
+    ERROR_TOO_MANY_LINKS = 1142,
+    ERROR_SOURCE_ELEMENT_EMPTY = 1160,
+    ERROR_DESTINATION_ELEMENT_FULL = 1161,
+    ERROR_ILLEGAL_ELEMENT_ADDRESS = 1162,
+    ERROR_MAGAZINE_NOT_PRESENT = 1163,
+    ERROR_DEVICE_REINITIALIZATION_NEEDED = 1164,
+    ERROR_DEVICE_REQUIRES_CLEANING = 1165,
+    ERROR_DEVICE_DOOR_OPEN = 1166,
+    ERROR_NOT_FOUND = 1168,
+    ERROR_NO_MATCH = 1169,
+    ERROR_SET_NOT_FOUND = 1170,
+    ERROR_NO_TRACKING_SERVICE = 1172,
+    ERROR_JOURNAL_DELETE_IN_PROGRESS = 1178,
+    ERROR_JOURNAL_NOT_ACTIVE = 1179,
+    ERROR_JOURNAL_ENTRY_DELETED = 1181,
+    ERROR_CONNECTION_UNAVAIL = 1201,
+    ERROR_INVALID_COMPUTERNAME = 1210,
+    ERROR_INVALID_NETNAME = 1214,
+    ERROR_SESSION_CREDENTIAL_CONFLICT = 1219,
+    ERROR_REMOTE_SESSION_LIMIT_EXCEEDED = 1220,
+    ERROR_USER_MAPPED_FILE = 1224,
+    ERROR_CONNECTION_REFUSED = 1225,
+    ERROR_GRACEFUL_DISCONNECT = 1226,
+    ERROR_ADDRESS_ALREADY_ASSOCIATED = 1227,
+    ERROR_ADDRESS_NOT_ASSOCIATED = 1228,
+    ERROR_CONNECTION_INVALID = 1229,
+    ERROR_CONNECTION_ACTIVE = 1230,
+    ERROR_NETWORK_UNREACHABLE = 1231,
+    ERROR_HOST_UNREACHABLE = 1232,
+    ERROR_PROTOCOL_UNREACHABLE = 1233,
+    ERROR_PORT_UNREACHABLE = 1234,
+    ERROR_REQUEST_ABORTED = 1235,
+    ERROR_CONNECTION_ABORTED = 1236,
+    ERROR_RETRY = 1237,
+    ERROR_CONNECTION_COUNT_LIMIT = 1238,
+    ERROR_LOGIN_TIME_RESTRICTION = 1239,
+    ERROR_LOGIN_WKSTA_RESTRICTION = 1240,
+    ERROR_ONLY_IF_CONNECTED = 1251,
+    ERROR_NOT_SUPPORTED_ON_SBS = 1254,
+    ERROR_SERVER_SHUTDOWN_IN_PROGRESS = 1255,
+    ERROR_HOST_DOWN = 1256,
+    ERROR_APPHELP_BLOCK = 1259,
+    ERROR_ACCESS_DISABLED_BY_POLICY = 1260,
+    ERROR_REG_NAT_CONSUMPTION = 1261,
+    ERROR_PKINIT_FAILURE = 1263,
+    ERROR_SMARTCARD_SUBSYSTEM_FAILURE = 1264,
+    ERROR_DOWNGRADE_DETECTED = 1265,
+    ERROR_DRIVER_BLOCKED = 1275,
+    ERROR_INVALID_IMPORT_OF_NON_DLL = 1276,
+    ERROR_RECOVERY_FAILURE = 1279,
+    ERROR_STACK_BUFFER_OVERRUN = 1282,
+    ERROR_PARAMETER_QUOTA_EXCEEDED = 1283,
+    ERROR_DEBUGGER_INACTIVE = 1284,
+    ERROR_DELAY_LOAD_FAILED = 1285,
+    ERROR_VDM_DISALLOWED = 1286,
+    ERROR_INVALID_CRUNTIME_PARAMETER = 1288,
+    ERROR_BEYOND_VDL = 1289,
+    ERROR_DRIVER_PROCESS_TERMINATED = 1291,
+    ERROR_IMPLEMENTATION_LIMIT = 1292,
+    ERROR_PROCESS_IS_PROTECTED = 1293,
+    ERROR_DISK_QUOTA_EXCEEDED = 1295,
+    ERROR_CONTENT_BLOCKED = 1296,
+    ERROR_INVALID_LABEL = 1299,
+    ERROR_NOT_ALL_ASSIGNED = 1300,
+    ERROR_SOME_NOT_MAPPED = 1301,
+    ERROR_NO_QUOTAS_FOR_ACCOUNT = 1302,
+    ERROR_LOCAL_USER_SESSION_KEY = 1303,
+    ERROR_NULL_LM_PASSWORD = 1304,
+    ERROR_UNKNOWN_REVISION = 1305,
+    ERROR_REVISION_MISMATCH = 1306,
+    ERROR_INVALID_OWNER = 1307,
+    ERROR_INVALID_PRIMARY_GROUP = 1308,
+    ERROR_NO_IMPERSONATION_TOKEN = 1309,
+    ERROR_CANT_DISABLE_MANDATORY = 1310,
+    ERROR_NO_LOGON_SERVERS = 1311,
+    ERROR_NO_SUCH_LOGON_SESSION = 1312,
+    ERROR_NO_SUCH_PRIVILEGE = 1313,
+    ERROR_PRIVILEGE_NOT_HELD = 1314,
+    ERROR_INVALID_ACCOUNT_NAME = 1315,
+    ERROR_USER_EXISTS = 1316,
+    ERROR_NO_SUCH_USER = 1317,
+    ERROR_GROUP_EXISTS = 1318,
+    ERROR_NO_SUCH_GROUP = 1319,
+    ERROR_MEMBER_IN_GROUP = 1320,
+    ERROR_MEMBER_NOT_IN_GROUP = 1321,
+    ERROR_LAST_ADMIN = 1322,
+    ERROR_ILL_FORMED_PASSWORD = 1324,
+    ERROR_PASSWORD_RESTRICTION = 1325,
+    ERROR_LOGON_FAILURE = 1326,
+    ERROR_ACCOUNT_RESTRICTION = 1327,
+    ERROR_INVALID_LOGON_HOURS = 1328,
+    ERROR_INVALID_WORKSTATION = 1329,
+    ERROR_PASSWORD_EXPIRED = 1330,
+    ERROR_ACCOUNT_DISABLED = 1331,
+    ERROR_NONE_MAPPED = 1332,
+    ERROR_TOO_MANY_LUIDS_REQUESTED = 1333,
+    ERROR_LUIDS_EXHAUSTED = 1334,
+    ERROR_INVALID_SUB_AUTHORITY = 1335,
+    ERROR_INVALID_ACL = 1336,
+    ERROR_INVALID_SID = 1337,
+    ERROR_INVALID_SECURITY_DESCR = 1338,
+    ERROR_BAD_INHERITANCE_ACL = 1340,
+    ERROR_SERVER_DISABLED = 1341,
+    ERROR_SERVER_NOT_DISABLED = 1342,
+    ERROR_INVALID_ID_AUTHORITY = 1343,
+    ERROR_ALLOTTED_SPACE_EXCEEDED = 1344,
+    ERROR_INVALID_GROUP_ATTRIBUTES = 1345,
+    ERROR_BAD_IMPERSONATION_LEVEL = 1346,
+    ERROR_CANT_OPEN_ANONYMOUS = 1347,
+    ERROR_BAD_VALIDATION_CLASS = 1348,
+    ERROR_BAD_TOKEN_TYPE = 1349,
+    ERROR_NO_SECURITY_ON_OBJECT = 1350,
+    ERROR_CANT_ACCESS_DOMAIN_INFO = 1351,
+    ERROR_INVALID_SERVER_STATE = 1352,
+    ERROR_INVALID_DOMAIN_STATE = 1353,
+    ERROR_INVALID_DOMAIN_ROLE = 1354,
+    ERROR_NO_SUCH_DOMAIN = 1355,
+    ERROR_DOMAIN_EXISTS = 1356,
+    ERROR_DOMAIN_LIMIT_EXCEEDED = 1357,
+    ERROR_INTERNAL_DB_CORRUPTION = 1358,
+    ERROR_INTERNAL_ERROR = 1359,
+    ERROR_GENERIC_NOT_MAPPED = 1360,
+    ERROR_BAD_DESCRIPTOR_FORMAT = 1361,
+    ERROR_NOT_LOGON_PROCESS = 1362,
+    ERROR_LOGON_SESSION_EXISTS = 1363,
+    ERROR_NO_SUCH_PACKAGE = 1364,
+    ERROR_BAD_LOGON_SESSION_STATE = 1365,
+    ERROR_LOGON_SESSION_COLLISION = 1366,
+    ERROR_INVALID_LOGON_TYPE = 1367,
+    ERROR_CANNOT_IMPERSONATE = 1368,
+    ERROR_RXACT_INVALID_STATE = 1369,
+    ERROR_RXACT_COMMIT_FAILURE = 1370,
+    ERROR_SPECIAL_ACCOUNT = 1371,
+    ERROR_SPECIAL_GROUP = 1372,
+    ERROR_SPECIAL_USER = 1373,
+    ERROR_MEMBERS_PRIMARY_GROUP = 1374,
+    ERROR_TOKEN_ALREADY_IN_USE = 1375,
+    ERROR_NO_SUCH_ALIAS = 1376,
+    ERROR_MEMBER_NOT_IN_ALIAS = 1377,
+    ERROR_MEMBER_IN_ALIAS = 1378,
+    ERROR_ALIAS_EXISTS = 1379,
+    ERROR_LOGON_NOT_GRANTED = 1380,
+    ERROR_TOO_MANY_SECRETS = 1381,
+    ERROR_SECRET_TOO_LONG = 1382,
+    ERROR_INTERNAL_DB_ERROR = 1383,
+    ERROR_TOO_MANY_CONTEXT_IDS = 1384,
+    ERROR_LOGON_TYPE_NOT_GRANTED = 1385,
+    ERROR_NT_CROSS_ENCRYPTION_REQUIRED = 1386,
+    ERROR_NO_SUCH_MEMBER = 1387,
+    ERROR_INVALID_MEMBER = 1388,
+    ERROR_TOO_MANY_SIDS = 1389,
+    ERROR_LM_CROSS_ENCRYPTION_REQUIRED = 1390,
+    ERROR_NO_INHERITANCE = 1391,
+    ERROR_FILE_CORRUPT = 1392,
+    ERROR_DISK_CORRUPT = 1393,
+    ERROR_NO_USER_SESSION_KEY = 1394,
+    ERROR_LICENSE_QUOTA_EXCEEDED = 1395,
+    ERROR_MUTUAL_AUTH_FAILED = 1397,
+    ERROR_TIME_SKEW = 1398,
+    ERROR_CURRENT_DOMAIN_NOT_ALLOWED = 1399,
+    ERROR_NO_SYSTEM_RESOURCES = 1450,
+    ERROR_WORKING_SET_QUOTA = 1453,
+    ERROR_PAGEFILE_QUOTA = 1454,
+    ERROR_COMMITMENT_LIMIT = 1455,
+    ERROR_TIMEOUT = 1460,
+    ERROR_SYMLINK_CLASS_DISABLED = 1463,
+    ERROR_XML_PARSE_ERROR = 1465,
+    ERROR_XMLDSIG_ERROR = 1466,
+    ERROR_WRONG_COMPARTMENT = 1468,
+    ERROR_AUTHIP_FAILURE = 1469,
+    ERROR_EVENTLOG_FILE_CORRUPT = 1500,
+    ERROR_EVENTLOG_CANT_START = 1501,
+    ERROR_LOG_FILE_FULL = 1502,
+    ERROR_EVENTLOG_FILE_CHANGED = 1503,
+    ERROR_DEVICE_REMOVED = 1617,
+    ERROR_FAIL_FAST_EXCEPTION = 1653,
+    ERROR_IMAGE_CERT_REVOKED = 1654,
+    ERROR_DYNAMIC_CODE_BLOCKED = 1655,
+    ERROR_IMAGE_CERT_EXPIRED = 1656,
+    RPC_S_INVALID_STRING_BINDING = 1700,
+    RPC_S_WRONG_KIND_OF_BINDING = 1701,
+    RPC_S_INVALID_BINDING = 1702,
+    RPC_S_PROTSEQ_NOT_SUPPORTED = 1703,
+    RPC_S_INVALID_RPC_PROTSEQ = 1704,
+    RPC_S_INVALID_STRING_UUID = 1705,
+    RPC_S_INVALID_ENDPOINT_FORMAT = 1706,
+    RPC_S_INVALID_NET_ADDR = 1707,
+    RPC_S_NO_ENDPOINT_FOUND = 1708,
+    RPC_S_INVALID_TIMEOUT = 1709,
+    RPC_S_OBJECT_NOT_FOUND = 1710,
+    RPC_S_ALREADY_REGISTERED = 1711,
+    RPC_S_TYPE_ALREADY_REGISTERED = 1712,
+    RPC_S_ALREADY_LISTENING = 1713,
+    RPC_S_NO_PROTSEQS_REGISTERED = 1714,
+    RPC_S_NOT_LISTENING = 1715,
+    RPC_S_UNKNOWN_MGR_TYPE = 1716,
+    RPC_S_UNKNOWN_IF = 1717,
+    RPC_S_NO_BINDINGS = 1718,
+    RPC_S_NO_PROTSEQS = 1719,
+    RPC_S_CANT_CREATE_ENDPOINT = 1720,
+    RPC_S_OUT_OF_RESOURCES = 1721,
+    RPC_S_SERVER_UNAVAILABLE = 1722,
+    RPC_S_SERVER_TOO_BUSY = 1723,
+    RPC_S_INVALID_NETWORK_OPTIONS = 1724,
+    RPC_S_NO_CALL_ACTIVE = 1725,
+    RPC_S_CALL_FAILED = 1726,
+    RPC_S_CALL_FAILED_DNE = 1727,
+    RPC_S_PROTOCOL_ERROR = 1728,
+    RPC_S_PROXY_ACCESS_DENIED = 1729,
+    RPC_S_UNSUPPORTED_TRANS_SYN = 1730,
+    RPC_S_UNSUPPORTED_TYPE = 1732,
+    RPC_S_INVALID_TAG = 1733,
+    RPC_S_INVALID_BOUND = 1734,
+    RPC_S_NO_ENTRY_NAME = 1735,
+    RPC_S_INVALID_NAME_SYNTAX = 1736,
+    RPC_S_UNSUPPORTED_NAME_SYNTAX = 1737,
+    RPC_S_UUID_NO_ADDRESS = 1739,
+    RPC_S_DUPLICATE_ENDPOINT = 1740,
+    RPC_S_UNKNOWN_AUTHN_TYPE = 1741,
+    RPC_S_MAX_CALLS_TOO_SMALL = 1742,
+    RPC_S_STRING_TOO_LONG = 1743,
+    RPC_S_PROTSEQ_NOT_FOUND = 1744,
+    RPC_S_PROCNUM_OUT_OF_RANGE = 1745,
+    RPC_S_BINDING_HAS_NO_AUTH = 1746,
+    RPC_S_UNKNOWN_AUTHN_SERVICE = 1747,
+    RPC_S_UNKNOWN_AUTHN_LEVEL = 1748,
+    RPC_S_INVALID_AUTH_IDENTITY = 1749,
+    RPC_S_UNKNOWN_AUTHZ_SERVICE = 1750,
+    EPT_S_INVALID_ENTRY = 1751,
+    EPT_S_CANT_PERFORM_OP = 1752,
+    EPT_S_NOT_REGISTERED = 1753,
+    RPC_S_NOTHING_TO_EXPORT = 1754,
+    RPC_S_INCOMPLETE_NAME = 1755,
+    RPC_S_INVALID_VERS_OPTION = 1756,
+    RPC_S_NO_MORE_MEMBERS = 1757,
+    RPC_S_NOT_ALL_OBJS_UNEXPORTED = 1758,
+    RPC_S_INTERFACE_NOT_FOUND = 1759,
+    RPC_S_ENTRY_ALREADY_EXISTS = 1760,
+    RPC_S_ENTRY_NOT_FOUND = 1761,
+    RPC_S_NAME_SERVICE_UNAVAILABLE = 1762,
+    RPC_S_INVALID_NAF_ID = 1763,
+    RPC_S_CANNOT_SUPPORT = 1764,
+    RPC_S_NO_CONTEXT_AVAILABLE = 1765,
+    RPC_S_INTERNAL_ERROR = 1766,
+    RPC_S_ZERO_DIVIDE = 1767,
+    RPC_S_ADDRESS_ERROR = 1768,
+    RPC_S_FP_DIV_ZERO = 1769,
+    RPC_S_FP_UNDERFLOW = 1770,
+    RPC_S_FP_OVERFLOW = 1771,
+    RPC_X_NO_MORE_ENTRIES = 1772,
+    RPC_X_SS_CHAR_TRANS_OPEN_FAIL = 1773,
+    RPC_X_SS_CHAR_TRANS_SHORT_FILE = 1774,
+    RPC_X_SS_IN_NULL_CONTEXT = 1775,
+    RPC_X_SS_CONTEXT_DAMAGED = 1777,
+    RPC_X_SS_HANDLES_MISMATCH = 1778,
+    RPC_X_SS_CANNOT_GET_CALL_HANDLE = 1779,
+    RPC_X_NULL_REF_POINTER = 1780,
+    RPC_X_ENUM_VALUE_OUT_OF_RANGE = 1781,
+    RPC_X_BYTE_COUNT_TOO_SMALL = 1782,
+    RPC_X_BAD_STUB_DATA = 1783,
+    ERROR_INVALID_USER_BUFFER = 1784,
+    ERROR_UNRECOGNIZED_MEDIA = 1785,
+    ERROR_NO_TRUST_LSA_SECRET = 1786,
+    ERROR_NO_TRUST_SAM_ACCOUNT = 1787,
+    ERROR_TRUSTED_DOMAIN_FAILURE = 1788,
+    ERROR_TRUSTED_RELATIONSHIP_FAILURE = 1789,
+    ERROR_TRUST_FAILURE = 1790,
+    RPC_S_CALL_IN_PROGRESS = 1791,
+    ERROR_NETLOGON_NOT_STARTED = 1792,
+    ERROR_ACCOUNT_EXPIRED = 1793,
+    RPC_S_NO_MORE_BINDINGS = 1806,
+    ERROR_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT = 1807,
+    ERROR_NOLOGON_WORKSTATION_TRUST_ACCOUNT = 1808,
+    ERROR_NOLOGON_SERVER_TRUST_ACCOUNT = 1809,
+    ERROR_DOMAIN_TRUST_INCONSISTENT = 1810,
+    ERROR_RESOURCE_DATA_NOT_FOUND = 1812,
+    ERROR_RESOURCE_TYPE_NOT_FOUND = 1813,
+    ERROR_RESOURCE_NAME_NOT_FOUND = 1814,
+    ERROR_RESOURCE_LANG_NOT_FOUND = 1815,
+    ERROR_NOT_ENOUGH_QUOTA = 1816,
+    RPC_S_NO_INTERFACES = 1817,
+    RPC_S_CALL_CANCELLED = 1818,
+    RPC_S_BINDING_INCOMPLETE = 1819,
+    RPC_S_COMM_FAILURE = 1820,
+    RPC_S_UNSUPPORTED_AUTHN_LEVEL = 1821,
+    RPC_S_NO_PRINC_NAME = 1822,
+    RPC_S_NOT_RPC_ERROR = 1823,
+    RPC_S_UUID_LOCAL_ONLY = 1824,
+    RPC_S_SEC_PKG_ERROR = 1825,
+    RPC_S_NOT_CANCELLED = 1826,
+    RPC_X_INVALID_ES_ACTION = 1827,
+    RPC_X_WRONG_ES_VERSION = 1828,
+    RPC_X_WRONG_STUB_VERSION = 1829,
+    RPC_X_INVALID_PIPE_OBJECT = 1830,
+    RPC_X_WRONG_PIPE_ORDER = 1831,
+    RPC_X_WRONG_PIPE_VERSION = 1832,
+    RPC_S_COOKIE_AUTH_FAILED = 1833,
+    RPC_S_GROUP_MEMBER_NOT_FOUND = 1898,
+    EPT_S_CANT_CREATE = 1899,
+    RPC_S_INVALID_OBJECT = 1900,
+    ERROR_PASSWORD_MUST_CHANGE = 1907,
+    ERROR_DOMAIN_CONTROLLER_NOT_FOUND = 1908,
+    ERROR_ACCOUNT_LOCKED_OUT = 1909,
+    RPC_S_SEND_INCOMPLETE = 1913,
+    RPC_S_INVALID_ASYNC_HANDLE = 1914,
+    RPC_S_INVALID_ASYNC_CALL = 1915,
+    RPC_X_PIPE_CLOSED = 1916,
+    RPC_X_PIPE_DISCIPLINE_ERROR = 1917,
+    RPC_X_PIPE_EMPTY = 1918,
+    ERROR_CANT_ACCESS_FILE = 1920,
+    ERROR_CANT_RESOLVE_FILENAME = 1921,
+    ERROR_PER_USER_TRUST_QUOTA_EXCEEDED = 1932,
+    ERROR_ALL_USER_TRUST_QUOTA_EXCEEDED = 1933,
+    ERROR_USER_DELETE_TRUST_QUOTA_EXCEEDED = 1934,
+    ERROR_AUTHENTICATION_FIREWALL_FAILED = 1935,
+    ERROR_NTLM_BLOCKED = 1937,
+    ERROR_PASSWORD_CHANGE_REQUIRED = 1938,
+    ERROR_BAD_DRIVER = 2001,
+    ERROR_OPEN_FILES = 2401,
+    ERROR_ACTIVE_CONNECTIONS = 2402,
+    ERROR_DEVICE_IN_USE = 2404,
+    ERROR_WMI_GUID_NOT_FOUND = 4200,
+    ERROR_WMI_INSTANCE_NOT_FOUND = 4201,
+    ERROR_WMI_ITEMID_NOT_FOUND = 4202,
+    ERROR_WMI_TRY_AGAIN = 4203,
+    ERROR_WMI_ALREADY_ENABLED = 4206,
+    ERROR_WMI_GUID_DISCONNECTED = 4207,
+    ERROR_WMI_ALREADY_DISABLED = 4212,
+    ERROR_WMI_READ_ONLY = 4213,
+    ERROR_WMI_SET_FAILURE = 4214,
+    ERROR_NOT_APPCONTAINER = 4250,
+    ERROR_NOT_SUPPORTED_IN_APPCONTAINER = 4252,
+    ERROR_INVALID_PACKAGE_SID_LENGTH = 4253,
+    ERROR_TRANSPORT_FULL = 4328,
+    ERROR_FILE_OFFLINE = 4350,
+    ERROR_REMOTE_STORAGE_NOT_ACTIVE = 4351,
+    ERROR_REMOTE_STORAGE_MEDIA_ERROR = 4352,
+    ERROR_NOT_A_REPARSE_POINT = 4390,
+    ERROR_REPARSE_ATTRIBUTE_CONFLICT = 4391,
+    ERROR_INVALID_REPARSE_DATA = 4392,
+    ERROR_REPARSE_TAG_INVALID = 4393,
+    ERROR_REPARSE_TAG_MISMATCH = 4394,
+    ERROR_OFFLOAD_READ_FLT_NOT_SUPPORTED = 4440,
+    ERROR_OFFLOAD_WRITE_FLT_NOT_SUPPORTED = 4441,
+    ERROR_OFFLOAD_READ_FILE_NOT_SUPPORTED = 4442,
+    ERROR_OFFLOAD_WRITE_FILE_NOT_SUPPORTED = 4443,
+    ERROR_FILE_ENCRYPTED = 6002,
+    ERROR_FILE_NOT_ENCRYPTED = 6007,
+    ERROR_NOT_EXPORT_FORMAT = 6008,
+    ERROR_EFS_ALG_BLOB_TOO_BIG = 6013,
+    ERROR_CS_ENCRYPTION_INVALID_SERVER_RESPONSE = 6017,
+    ERROR_CS_ENCRYPTION_UNSUPPORTED_SERVER = 6018,
+    ERROR_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE = 6019,
+    ERROR_CS_ENCRYPTION_NEW_ENCRYPTED_FILE = 6020,
+    ERROR_CS_ENCRYPTION_FILE_NOT_CSE = 6021,
+    ERROR_NO_BROWSER_SERVERS_FOUND = 6118,
+    ERROR_BAD_CLUSTERS = 6849,
+    ERROR_VOLUME_DIRTY = 6851,
+    ERROR_DS_MEMBERSHIP_EVALUATED_LOCALLY = 8201,
+    ERROR_DS_NO_ATTRIBUTE_OR_VALUE = 8202,
+    ERROR_DS_INVALID_ATTRIBUTE_SYNTAX = 8203,
+    ERROR_DS_ATTRIBUTE_TYPE_UNDEFINED = 8204,
+    ERROR_DS_ATTRIBUTE_OR_VALUE_EXISTS = 8205,
+    ERROR_DS_BUSY = 8206,
+    ERROR_DS_UNAVAILABLE = 8207,
+    ERROR_DS_NO_RIDS_ALLOCATED = 8208,
+    ERROR_DS_NO_MORE_RIDS = 8209,
+    ERROR_DS_INCORRECT_ROLE_OWNER = 8210,
+    ERROR_DS_RIDMGR_INIT_ERROR = 8211,
+    ERROR_DS_OBJ_CLASS_VIOLATION = 8212,
+    ERROR_DS_CANT_ON_NON_LEAF = 8213,
+    ERROR_DS_CANT_ON_RDN = 8214,
+    ERROR_DS_CANT_MOD_OBJ_CLASS = 8215,
+    ERROR_DS_CROSS_DOM_MOVE_ERROR = 8216,
+    ERROR_DS_GC_NOT_AVAILABLE = 8217,
+    ERROR_SHARED_POLICY = 8218,
+    ERROR_POLICY_OBJECT_NOT_FOUND = 8219,
+    ERROR_POLICY_ONLY_IN_DS = 8220,
+    ERROR_DS_ADMIN_LIMIT_EXCEEDED = 8228,
+    ERROR_DS_DS_REQUIRED = 8478,
+    ERROR_DS_SAM_INIT_FAILURE = 8504,
+    ERROR_DS_SENSITIVE_GROUP_VIOLATION = 8505,
+    ERROR_DS_CANT_MOD_PRIMARYGROUPID = 8506,
+    ERROR_DS_INVALID_GROUP_TYPE = 8513,
+    ERROR_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN = 8514,
+    ERROR_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN = 8515,
+    ERROR_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER = 8516,
+    ERROR_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER = 8517,
+    ERROR_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER = 8518,
+    ERROR_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER = 8519,
+    ERROR_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER = 8520,
+    ERROR_DS_HAVE_PRIMARY_MEMBERS = 8521,
+    ERROR_DS_SAM_NEED_BOOTKEY_PASSWORD = 8529,
+    ERROR_DS_SAM_NEED_BOOTKEY_FLOPPY = 8530,
+    ERROR_DS_CANT_START = 8531,
+    ERROR_DS_INIT_FAILURE = 8532,
+    ERROR_DS_SRC_SID_EXISTS_IN_FOREST = 8539,
+    ERROR_SAM_INIT_FAILURE = 8541,
+    ERROR_DS_GC_REQUIRED = 8547,
+    ERROR_DS_LOCAL_MEMBER_OF_LOCAL_ONLY = 8548,
+    ERROR_DS_NO_FPO_IN_UNIVERSAL_GROUPS = 8549,
+    ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED = 8557,
+    ERROR_DS_INIT_FAILURE_CONSOLE = 8561,
+    ERROR_DS_SAM_INIT_FAILURE_CONSOLE = 8562,
+    ERROR_AMBIGUOUS_SYSTEM_DEVICE = 15250,
+    ERROR_SYSTEM_DEVICE_NOT_FOUND = 15299,
+    STG_E_STATUS_COPY_PROTECTION_FAILURE = 0x80030305,
+    STG_E_CSS_AUTHENTICATION_FAILURE = 0x80030306,
+    STG_E_CSS_KEY_NOT_PRESENT = 0x80030307,
+    STG_E_CSS_KEY_NOT_ESTABLISHED = 0x80030308,
+    STG_E_CSS_SCRAMBLED_SECTOR = 0x80030309,
+    STG_E_CSS_REGION_MISMATCH = 0x8003030A,
+    STG_E_RESETS_EXHAUSTED = 0x8003030B,
+    NTE_NO_KEY = 0x8009000D,
+    NTE_BAD_KEYSET = 0x80090016,
+    SEC_E_KDC_INVALID_REQUEST = 0x80090340,
+    SEC_E_KDC_UNABLE_TO_REFER = 0x80090341,
+    SEC_E_KDC_UNKNOWN_ETYPE = 0x80090342,
+    SEC_E_UNSUPPORTED_PREAUTH = 0x80090343,
+    SEC_E_BAD_BINDINGS = 0x80090346,
+    SEC_E_NO_KERB_KEY = 0x80090348,
+    SEC_E_SMARTCARD_CERT_REVOKED = 0x80090351,
+    SEC_E_ISSUING_CA_UNTRUSTED = 0x80090352,
+    SEC_E_REVOCATION_OFFLINE_C = 0x80090353,
+    SEC_E_PKINIT_CLIENT_FAILURE = 0x80090354,
+    SEC_E_SMARTCARD_CERT_EXPIRED = 0x80090355,
+    SEC_E_NO_S4U_PROT_SUPPORT = 0x80090356,
+    SEC_E_CROSSREALM_DELEGATION_FAILURE = 0x80090357,
+    SEC_E_REVOCATION_OFFLINE_KDC = 0x80090358,
+    SEC_E_ISSUING_CA_UNTRUSTED_KDC = 0x80090359,
+    SEC_E_KDC_CERT_EXPIRED = 0x8009035A,
+    SEC_E_KDC_CERT_REVOKED = 0x8009035B,
+    SEC_E_PKU2U_CERT_FAILURE = 0x80090361,
+    SEC_E_UNFINISHED_CONTEXT_DELETED = 0x80090367,
+    SEC_E_NO_TGT_REPLY = 0x80090369,
+    SEC_E_NO_IP_ADDRESSES = 0x8009036A,
+    SEC_E_WRONG_CREDENTIAL_HANDLE = 0x8009036B,
+    SEC_E_CRYPTO_SYSTEM_INVALID = 0x8009036C,
+    SEC_E_MAX_REFERRALS_EXCEEDED = 0x8009036D,
+    SEC_E_MUST_BE_KDC = 0x8009036E,
+    SEC_E_STRONG_CRYPTO_NOT_SUPPORTED = 0x8009036F,
+    SEC_E_TOO_MANY_PRINCIPALS = 0x80090370,
+    SEC_E_NO_PA_DATA = 0x80090371,
+    SEC_E_PKINIT_NAME_MISMATCH = 0x80090372,
+    SEC_E_SMARTCARD_LOGON_REQUIRED = 0x80090373,
+    SCARD_E_NO_SMARTCARD = 0x8010000C,
+    SCARD_E_NO_SUCH_CERTIFICATE = 0x8010002C,
+    SCARD_E_COMM_DATA_LOST = 0x8010002F,
+    SCARD_W_WRONG_CHV = 0x8010006B,
+    SCARD_W_CHV_BLOCKED = 0x8010006C,
+    SCARD_W_CARD_NOT_AUTHENTICATED = 0x8010006F,
+    ERROR_HUNG_DISPLAY_DRIVER_THREAD = 0x80260001,
+    ERROR_AUDITING_DISABLED = 0xC0090001,
+    ERROR_ALL_SIDS_FILTERED = 0xC0090002,
+}
+
+impl Win32Error {
+    #[inline(always)]
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    #[inline(always)]
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// The status that maps to this code, if exactly one does.
+    ///
+    /// Codes shared by several statuses (e.g. `ERROR_ACCESS_DENIED`) and codes outside the
+    /// mapping table yield `None`.
+    ///
+    /// Ambiguity is only judged against the facilities the table maps, the generic, RPC and EPT
+    /// ones. A code this returns a status for may also be produced by a status of an unmapped
+    /// facility on Windows, so the result is a likely origin rather than the only one.
+    pub const fn to_ntstatus(&self) -> Option<RawNtStatus> {
+        if self.0 == Self::ERROR_SUCCESS.0 || self.0 & CUSTOMER_BIT != 0 {
+            return Some(RawNtStatus::new(self.0 as _));
+        }
+
+        let mut found = None;
+        let mut i = 0;
+        while i < NTSTATUS_TO_WIN32.len() {
+            let (status, error) = NTSTATUS_TO_WIN32[i];
+            if error.0 == self.0 {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(RawNtStatus::new(status as _));
+            }
+            i += 1;
+        }
+        found
+    }
+}
+
+#[cfg(not(feature = "nosym"))]
+impl core::fmt::Debug for Win32Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => f.debug_tuple("Win32Error").field(&self.0).finish(),
+        }
+    }
+}
+
+impl From<u32> for Win32Error {
+    #[inline(always)]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Win32Error> for u32 {
+    #[inline(always)]
+    fn from(value: Win32Error) -> Self {
+        value.0
+    }
+}
+
+impl From<RawNtStatus> for Win32Error {
+    #[inline(always)]
+    fn from(value: RawNtStatus) -> Self {
+        value.to_win32_error()
+    }
+}
+
+impl From<NtStatus> for Win32Error {
+    #[inline(always)]
+    fn from(value: NtStatus) -> Self {
+        value.to_win32_error()
+    }
+}
+
+const CUSTOMER_BIT: u32 = 0x2000_0000;
+
+/// Mirrors `RtlNtStatusToDosError` for the statuses in [`NTSTATUS_TO_WIN32`].
+///
+/// Statuses of the facilities missing from the table give `ERROR_MR_MID_NOT_FOUND`, even where
+/// Windows has a specific code.
+pub(crate) const fn ntstatus_to_win32(status: u32) -> Win32Error {
+    if status == 0 || status & CUSTOMER_BIT != 0 {
+        return Win32Error(status);
+    }
+
+    // 0xD... is treated as 0xC..., the reserved bit is ignored
+    let status = if status & 0xF000_0000 == 0xD000_0000 {
+        status & !0x1000_0000
+    } else {
+        status
+    };
+
+    let (mut lo, mut hi) = (0, NTSTATUS_TO_WIN32.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let (key, error) = NTSTATUS_TO_WIN32[mid];
+        if key == status {
+            return error;
+        } else if key < status {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    // FACILITY_NTWIN32 statuses carry the Win32 code in their low word
+    match status >> 16 {
+        0x8007 | 0xC007 => Win32Error(status & 0xFFFF),
+        _ => Win32Error::ERROR_MR_MID_NOT_FOUND,
+    }
+}
+
+/// `RtlNtStatusToDosError` mapping table, sorted by status.
+///
+/// Exception codes map to themselves, as they do on Windows. Every generic `STATUS_*` code has a
+/// row, as do the `RPC_NT_*`/`EPT_NT_*` families; generic codes Windows has no specific error for
+/// are listed with `ERROR_MR_MID_NOT_FOUND`. The other facilities, e.g. the CTX, TPM and VHD
+/// families, are unmapped for now.
+#[rustfmt::skip]
+const NTSTATUS_TO_WIN32: &[(u32, Win32Error)] = &[
+    (0x00000000, Win32Error::ERROR_SUCCESS), // STATUS_SUCCESS
+    (0x00000001, Win32Error::ERROR_WAIT_1), // STATUS_WAIT_1
+    (0x00000002, Win32Error::ERROR_WAIT_2), // STATUS_WAIT_2
+    (0x00000003, Win32Error::ERROR_WAIT_3), // STATUS_WAIT_3
+    (0x0000003F, Win32Error::ERROR_WAIT_63), // STATUS_WAIT_63
+    (0x00000080, Win32Error::ERROR_ABANDONED_WAIT_0), // STATUS_ABANDONED
+    (0x000000BF, Win32Error::ERROR_ABANDONED_WAIT_63), // STATUS_ABANDONED_WAIT_63
+    (0x000000C0, Win32Error::ERROR_USER_APC), // STATUS_USER_APC
+    (0x000000FF, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_ALREADY_COMPLETE
+    (0x00000100, Win32Error::ERROR_KERNEL_APC), // STATUS_KERNEL_APC
+    (0x00000101, Win32Error::ERROR_ALERTED), // STATUS_ALERTED
+    (0x00000102, Win32Error::ERROR_TIMEOUT), // STATUS_TIMEOUT
+    (0x00000103, Win32Error::ERROR_IO_PENDING), // STATUS_PENDING
+    (0x00000104, Win32Error::ERROR_REPARSE), // STATUS_REPARSE
+    (0x00000105, Win32Error::ERROR_MORE_DATA), // STATUS_MORE_ENTRIES
+    (0x00000106, Win32Error::ERROR_NOT_ALL_ASSIGNED), // STATUS_NOT_ALL_ASSIGNED
+    (0x00000107, Win32Error::ERROR_SOME_NOT_MAPPED), // STATUS_SOME_NOT_MAPPED
+    (0x00000108, Win32Error::ERROR_OPLOCK_BREAK_IN_PROGRESS), // STATUS_OPLOCK_BREAK_IN_PROGRESS
+    (0x00000109, Win32Error::ERROR_VOLUME_MOUNTED), // STATUS_VOLUME_MOUNTED
+    (0x0000010A, Win32Error::ERROR_RXACT_COMMITTED), // STATUS_RXACT_COMMITTED
+    (0x0000010B, Win32Error::ERROR_NOTIFY_CLEANUP), // STATUS_NOTIFY_CLEANUP
+    (0x0000010C, Win32Error::ERROR_NOTIFY_ENUM_DIR), // STATUS_NOTIFY_ENUM_DIR
+    (0x0000010D, Win32Error::ERROR_NO_QUOTAS_FOR_ACCOUNT), // STATUS_NO_QUOTAS_FOR_ACCOUNT
+    (0x0000010E, Win32Error::ERROR_PRIMARY_TRANSPORT_CONNECT_FAILED), // STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED
+    (0x00000110, Win32Error::ERROR_PAGE_FAULT_TRANSITION), // STATUS_PAGE_FAULT_TRANSITION
+    (0x00000111, Win32Error::ERROR_PAGE_FAULT_DEMAND_ZERO), // STATUS_PAGE_FAULT_DEMAND_ZERO
+    (0x00000112, Win32Error::ERROR_PAGE_FAULT_COPY_ON_WRITE), // STATUS_PAGE_FAULT_COPY_ON_WRITE
+    (0x00000113, Win32Error::ERROR_PAGE_FAULT_GUARD_PAGE), // STATUS_PAGE_FAULT_GUARD_PAGE
+    (0x00000114, Win32Error::ERROR_PAGE_FAULT_PAGING_FILE), // STATUS_PAGE_FAULT_PAGING_FILE
+    (0x00000115, Win32Error::ERROR_CACHE_PAGE_LOCKED), // STATUS_CACHE_PAGE_LOCKED
+    (0x00000116, Win32Error::ERROR_CRASH_DUMP), // STATUS_CRASH_DUMP
+    (0x00000117, Win32Error::ERROR_BUFFER_ALL_ZEROS), // STATUS_BUFFER_ALL_ZEROS
+    (0x00000118, Win32Error::ERROR_REPARSE_OBJECT), // STATUS_REPARSE_OBJECT
+    (0x00000119, Win32Error::ERROR_RESOURCE_REQUIREMENTS_CHANGED), // STATUS_RESOURCE_REQUIREMENTS_CHANGED
+    (0x00000120, Win32Error::ERROR_TRANSLATION_COMPLETE), // STATUS_TRANSLATION_COMPLETE
+    (0x00000121, Win32Error::ERROR_DS_MEMBERSHIP_EVALUATED_LOCALLY), // STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY
+    (0x00000122, Win32Error::ERROR_NOTHING_TO_TERMINATE), // STATUS_NOTHING_TO_TERMINATE
+    (0x00000123, Win32Error::ERROR_PROCESS_NOT_IN_JOB), // STATUS_PROCESS_NOT_IN_JOB
+    (0x00000124, Win32Error::ERROR_PROCESS_IN_JOB), // STATUS_PROCESS_IN_JOB
+    (0x00000125, Win32Error::ERROR_VOLSNAP_HIBERNATE_READY), // STATUS_VOLSNAP_HIBERNATE_READY
+    (0x00000126, Win32Error::ERROR_FSFILTER_OP_COMPLETED_SUCCESSFULLY), // STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY
+    (0x00000127, Win32Error::ERROR_INTERRUPT_VECTOR_ALREADY_CONNECTED), // STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED
+    (0x00000128, Win32Error::ERROR_INTERRUPT_STILL_CONNECTED), // STATUS_INTERRUPT_STILL_CONNECTED
+    (0x00000129, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PROCESS_CLONED
+    (0x0000012A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FILE_LOCKED_WITH_ONLY_READERS
+    (0x0000012B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FILE_LOCKED_WITH_WRITERS
+    (0x0000012C, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_VALID_IMAGE_HASH
+    (0x0000012D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_VALID_CATALOG_HASH
+    (0x0000012E, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_VALID_STRONG_CODE_HASH
+    (0x0000012F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GHOSTED
+    (0x00000202, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RESOURCEMANAGER_READ_ONLY
+    (0x00000210, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RING_PREVIOUSLY_EMPTY
+    (0x00000211, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RING_PREVIOUSLY_FULL
+    (0x00000212, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RING_PREVIOUSLY_ABOVE_QUOTA
+    (0x00000213, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RING_NEWLY_EMPTY
+    (0x00000214, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RING_SIGNAL_OPPOSITE_ENDPOINT
+    (0x00000215, Win32Error::ERROR_OPLOCK_SWITCHED_TO_NEW_HANDLE), // STATUS_OPLOCK_SWITCHED_TO_NEW_HANDLE
+    (0x00000216, Win32Error::ERROR_OPLOCK_HANDLE_CLOSED), // STATUS_OPLOCK_HANDLE_CLOSED
+    (0x00000367, Win32Error::ERROR_WAIT_FOR_OPLOCK), // STATUS_WAIT_FOR_OPLOCK
+    (0x00000368, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_REPARSE_GLOBAL
+    (0x40000006, Win32Error::ERROR_LOCAL_USER_SESSION_KEY), // STATUS_LOCAL_USER_SESSION_KEY
+    (0x4000000D, Win32Error::ERROR_NULL_LM_PASSWORD), // STATUS_NULL_LM_PASSWORD
+    (0x40000294, Win32Error::ERROR_WAKE_SYSTEM), // STATUS_WAKE_SYSTEM
+    (0x40000370, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_SHUTTING_DOWN
+    (0x40000807, Win32Error::ERROR_DISK_REPAIR_REDIRECTED), // STATUS_DISK_REPAIR_REDIRECTED
+    (0x4000A144, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SERVICES_FAILED_AUTOSTART
+    (0x40020056, Win32Error::RPC_S_UUID_LOCAL_ONLY), // RPC_NT_UUID_LOCAL_ONLY
+    (0x400200AF, Win32Error::RPC_S_SEND_INCOMPLETE), // RPC_NT_SEND_INCOMPLETE
+    (0x80000001, Win32Error(0x80000001)), // STATUS_GUARD_PAGE_VIOLATION
+    (0x80000002, Win32Error::ERROR_NOACCESS), // STATUS_DATATYPE_MISALIGNMENT
+    (0x80000003, Win32Error(0x80000003)), // STATUS_BREAKPOINT
+    (0x80000004, Win32Error(0x80000004)), // STATUS_SINGLE_STEP
+    (0x80000005, Win32Error::ERROR_MORE_DATA), // STATUS_BUFFER_OVERFLOW
+    (0x80000006, Win32Error::ERROR_NO_MORE_FILES), // STATUS_NO_MORE_FILES
+    (0x80000007, Win32Error::ERROR_WAKE_SYSTEM_DEBUGGER), // STATUS_WAKE_SYSTEM_DEBUGGER
+    (0x8000000A, Win32Error::ERROR_HANDLES_CLOSED), // STATUS_HANDLES_CLOSED
+    (0x8000000B, Win32Error::ERROR_NO_INHERITANCE), // STATUS_NO_INHERITANCE
+    (0x8000000C, Win32Error::ERROR_GUID_SUBSTITUTION_MADE), // STATUS_GUID_SUBSTITUTION_MADE
+    (0x8000000D, Win32Error::ERROR_PARTIAL_COPY), // STATUS_PARTIAL_COPY
+    (0x8000000E, Win32Error::ERROR_OUT_OF_PAPER), // STATUS_DEVICE_PAPER_EMPTY
+    (0x8000000F, Win32Error::ERROR_NOT_READY), // STATUS_DEVICE_POWERED_OFF
+    (0x80000010, Win32Error::ERROR_NOT_READY), // STATUS_DEVICE_OFF_LINE
+    (0x80000011, Win32Error::ERROR_BUSY), // STATUS_DEVICE_BUSY
+    (0x80000012, Win32Error::ERROR_NO_MORE_ITEMS), // STATUS_NO_MORE_EAS
+    (0x80000013, Win32Error::ERROR_INVALID_EA_NAME), // STATUS_INVALID_EA_NAME
+    (0x80000014, Win32Error::ERROR_EA_LIST_INCONSISTENT), // STATUS_EA_LIST_INCONSISTENT
+    (0x80000015, Win32Error::ERROR_EA_LIST_INCONSISTENT), // STATUS_INVALID_EA_FLAG
+    (0x80000016, Win32Error::ERROR_MEDIA_CHANGED), // STATUS_VERIFY_REQUIRED
+    (0x80000017, Win32Error::ERROR_EXTRANEOUS_INFORMATION), // STATUS_EXTRANEOUS_INFORMATION
+    (0x80000018, Win32Error::ERROR_RXACT_COMMIT_NECESSARY), // STATUS_RXACT_COMMIT_NECESSARY
+    (0x8000001A, Win32Error::ERROR_NO_MORE_ITEMS), // STATUS_NO_MORE_ENTRIES
+    (0x8000001B, Win32Error::ERROR_FILEMARK_DETECTED), // STATUS_FILEMARK_DETECTED
+    (0x8000001C, Win32Error::ERROR_MEDIA_CHANGED), // STATUS_MEDIA_CHANGED
+    (0x8000001D, Win32Error::ERROR_BUS_RESET), // STATUS_BUS_RESET
+    (0x8000001E, Win32Error::ERROR_END_OF_MEDIA), // STATUS_END_OF_MEDIA
+    (0x8000001F, Win32Error::ERROR_BEGINNING_OF_MEDIA), // STATUS_BEGINNING_OF_MEDIA
+    (0x80000021, Win32Error::ERROR_SETMARK_DETECTED), // STATUS_SETMARK_DETECTED
+    (0x80000022, Win32Error::ERROR_NO_DATA_DETECTED), // STATUS_NO_DATA_DETECTED
+    (0x80000025, Win32Error::ERROR_ACTIVE_CONNECTIONS), // STATUS_ALREADY_DISCONNECTED
+    (0x80000026, Win32Error::ERROR_LONGJUMP), // STATUS_LONGJUMP
+    (0x8000002D, Win32Error::ERROR_STOPPED_ON_SYMLINK), // STATUS_STOPPED_ON_SYMLINK
+    (0x80000288, Win32Error::ERROR_DEVICE_REQUIRES_CLEANING), // STATUS_DEVICE_REQUIRES_CLEANING
+    (0x80000289, Win32Error::ERROR_DEVICE_DOOR_OPEN), // STATUS_DEVICE_DOOR_OPEN
+    (0x80000803, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DATA_LOST_REPAIR
+    (0x8000A127, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GPIO_INTERRUPT_ALREADY_UNMASKED
+    (0x8000CF04, Win32Error::ERROR_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE), // STATUS_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE
+    (0xC0000001, Win32Error::ERROR_GEN_FAILURE), // STATUS_UNSUCCESSFUL
+    (0xC0000002, Win32Error::ERROR_INVALID_FUNCTION), // STATUS_NOT_IMPLEMENTED
+    (0xC0000003, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_INFO_CLASS
+    (0xC0000004, Win32Error::ERROR_BAD_LENGTH), // STATUS_INFO_LENGTH_MISMATCH
+    (0xC0000005, Win32Error::ERROR_NOACCESS), // STATUS_ACCESS_VIOLATION
+    (0xC0000006, Win32Error::ERROR_SWAPERROR), // STATUS_IN_PAGE_ERROR
+    (0xC0000007, Win32Error::ERROR_PAGEFILE_QUOTA), // STATUS_PAGEFILE_QUOTA
+    (0xC0000008, Win32Error::ERROR_INVALID_HANDLE), // STATUS_INVALID_HANDLE
+    (0xC0000009, Win32Error::ERROR_STACK_OVERFLOW), // STATUS_BAD_INITIAL_STACK
+    (0xC000000A, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_BAD_INITIAL_PC
+    (0xC000000B, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_CID
+    (0xC000000C, Win32Error::ERROR_TIMER_NOT_CANCELED), // STATUS_TIMER_NOT_CANCELED
+    (0xC000000D, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER
+    (0xC000000E, Win32Error::ERROR_FILE_NOT_FOUND), // STATUS_NO_SUCH_DEVICE
+    (0xC000000F, Win32Error::ERROR_FILE_NOT_FOUND), // STATUS_NO_SUCH_FILE
+    (0xC0000010, Win32Error::ERROR_INVALID_FUNCTION), // STATUS_INVALID_DEVICE_REQUEST
+    (0xC0000011, Win32Error::ERROR_HANDLE_EOF), // STATUS_END_OF_FILE
+    (0xC0000012, Win32Error::ERROR_WRONG_DISK), // STATUS_WRONG_VOLUME
+    (0xC0000013, Win32Error::ERROR_NOT_READY), // STATUS_NO_MEDIA_IN_DEVICE
+    (0xC0000014, Win32Error::ERROR_UNRECOGNIZED_MEDIA), // STATUS_UNRECOGNIZED_MEDIA
+    (0xC0000015, Win32Error::ERROR_SECTOR_NOT_FOUND), // STATUS_NONEXISTENT_SECTOR
+    (0xC0000016, Win32Error::ERROR_MORE_DATA), // STATUS_MORE_PROCESSING_REQUIRED
+    (0xC0000017, Win32Error::ERROR_NOT_ENOUGH_MEMORY), // STATUS_NO_MEMORY
+    (0xC0000018, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_CONFLICTING_ADDRESSES
+    (0xC0000019, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_NOT_MAPPED_VIEW
+    (0xC000001A, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_UNABLE_TO_FREE_VM
+    (0xC000001B, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_UNABLE_TO_DELETE_SECTION
+    (0xC000001C, Win32Error::ERROR_INVALID_FUNCTION), // STATUS_INVALID_SYSTEM_SERVICE
+    (0xC000001D, Win32Error(0xC000001D)), // STATUS_ILLEGAL_INSTRUCTION
+    (0xC000001E, Win32Error::ERROR_ACCESS_DENIED), // STATUS_INVALID_LOCK_SEQUENCE
+    (0xC000001F, Win32Error::ERROR_ACCESS_DENIED), // STATUS_INVALID_VIEW_SIZE
+    (0xC0000020, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_FILE_FOR_SECTION
+    (0xC0000021, Win32Error::ERROR_ACCESS_DENIED), // STATUS_ALREADY_COMMITTED
+    (0xC0000022, Win32Error::ERROR_ACCESS_DENIED), // STATUS_ACCESS_DENIED
+    (0xC0000023, Win32Error::ERROR_INSUFFICIENT_BUFFER), // STATUS_BUFFER_TOO_SMALL
+    (0xC0000024, Win32Error::ERROR_INVALID_HANDLE), // STATUS_OBJECT_TYPE_MISMATCH
+    (0xC0000025, Win32Error(0xC0000025)), // STATUS_NONCONTINUABLE_EXCEPTION
+    (0xC0000026, Win32Error(0xC0000026)), // STATUS_INVALID_DISPOSITION
+    (0xC0000027, Win32Error::ERROR_UNWIND), // STATUS_UNWIND
+    (0xC0000028, Win32Error::ERROR_BAD_STACK), // STATUS_BAD_STACK
+    (0xC0000029, Win32Error::ERROR_INVALID_UNWIND_TARGET), // STATUS_INVALID_UNWIND_TARGET
+    (0xC000002A, Win32Error::ERROR_NOT_LOCKED), // STATUS_NOT_LOCKED
+    (0xC000002B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PARITY_ERROR
+    (0xC000002C, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_UNABLE_TO_DECOMMIT_VM
+    (0xC000002D, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_NOT_COMMITTED
+    (0xC000002E, Win32Error::ERROR_INVALID_PORT_ATTRIBUTES), // STATUS_INVALID_PORT_ATTRIBUTES
+    (0xC000002F, Win32Error::ERROR_PORT_MESSAGE_TOO_LONG), // STATUS_PORT_MESSAGE_TOO_LONG
+    (0xC0000030, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_MIX
+    (0xC0000031, Win32Error::ERROR_INVALID_QUOTA_LOWER), // STATUS_INVALID_QUOTA_LOWER
+    (0xC0000032, Win32Error::ERROR_DISK_CORRUPT), // STATUS_DISK_CORRUPT_ERROR
+    (0xC0000033, Win32Error::ERROR_INVALID_NAME), // STATUS_OBJECT_NAME_INVALID
+    (0xC0000034, Win32Error::ERROR_FILE_NOT_FOUND), // STATUS_OBJECT_NAME_NOT_FOUND
+    (0xC0000035, Win32Error::ERROR_ALREADY_EXISTS), // STATUS_OBJECT_NAME_COLLISION
+    (0xC0000036, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PORT_DO_NOT_DISTURB
+    (0xC0000037, Win32Error::ERROR_INVALID_HANDLE), // STATUS_PORT_DISCONNECTED
+    (0xC0000038, Win32Error::ERROR_DEVICE_ALREADY_ATTACHED), // STATUS_DEVICE_ALREADY_ATTACHED
+    (0xC0000039, Win32Error::ERROR_BAD_PATHNAME), // STATUS_OBJECT_PATH_INVALID
+    (0xC000003A, Win32Error::ERROR_PATH_NOT_FOUND), // STATUS_OBJECT_PATH_NOT_FOUND
+    (0xC000003B, Win32Error::ERROR_BAD_PATHNAME), // STATUS_OBJECT_PATH_SYNTAX_BAD
+    (0xC000003C, Win32Error::ERROR_IO_DEVICE), // STATUS_DATA_OVERRUN
+    (0xC000003D, Win32Error::ERROR_IO_DEVICE), // STATUS_DATA_LATE_ERROR
+    (0xC000003E, Win32Error::ERROR_CRC), // STATUS_DATA_ERROR
+    (0xC000003F, Win32Error::ERROR_CRC), // STATUS_CRC_ERROR
+    (0xC0000040, Win32Error::ERROR_NOT_ENOUGH_MEMORY), // STATUS_SECTION_TOO_BIG
+    (0xC0000041, Win32Error::ERROR_ACCESS_DENIED), // STATUS_PORT_CONNECTION_REFUSED
+    (0xC0000042, Win32Error::ERROR_INVALID_HANDLE), // STATUS_INVALID_PORT_HANDLE
+    (0xC0000043, Win32Error::ERROR_SHARING_VIOLATION), // STATUS_SHARING_VIOLATION
+    (0xC0000044, Win32Error::ERROR_NOT_ENOUGH_QUOTA), // STATUS_QUOTA_EXCEEDED
+    (0xC0000045, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PAGE_PROTECTION
+    (0xC0000046, Win32Error::ERROR_NOT_OWNER), // STATUS_MUTANT_NOT_OWNED
+    (0xC0000047, Win32Error::ERROR_TOO_MANY_POSTS), // STATUS_SEMAPHORE_LIMIT_EXCEEDED
+    (0xC0000048, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_PORT_ALREADY_SET
+    (0xC0000049, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_SECTION_NOT_IMAGE
+    (0xC000004A, Win32Error::ERROR_SIGNAL_REFUSED), // STATUS_SUSPEND_COUNT_EXCEEDED
+    (0xC000004B, Win32Error::ERROR_ACCESS_DENIED), // STATUS_THREAD_IS_TERMINATING
+    (0xC000004C, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_BAD_WORKING_SET_LIMIT
+    (0xC000004D, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INCOMPATIBLE_FILE_MAP
+    (0xC000004E, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_SECTION_PROTECTION
+    (0xC000004F, Win32Error::ERROR_EAS_NOT_SUPPORTED), // STATUS_EAS_NOT_SUPPORTED
+    (0xC0000050, Win32Error::ERROR_EA_LIST_INCONSISTENT), // STATUS_EA_TOO_LARGE
+    (0xC0000051, Win32Error::ERROR_FILE_CORRUPT), // STATUS_NONEXISTENT_EA_ENTRY
+    (0xC0000052, Win32Error::ERROR_FILE_CORRUPT), // STATUS_NO_EAS_ON_FILE
+    (0xC0000053, Win32Error::ERROR_FILE_CORRUPT), // STATUS_EA_CORRUPT_ERROR
+    (0xC0000054, Win32Error::ERROR_LOCK_VIOLATION), // STATUS_FILE_LOCK_CONFLICT
+    (0xC0000055, Win32Error::ERROR_LOCK_VIOLATION), // STATUS_LOCK_NOT_GRANTED
+    (0xC0000056, Win32Error::ERROR_ACCESS_DENIED), // STATUS_DELETE_PENDING
+    (0xC0000057, Win32Error::ERROR_NOT_SUPPORTED), // STATUS_CTL_FILE_NOT_SUPPORTED
+    (0xC0000058, Win32Error::ERROR_UNKNOWN_REVISION), // STATUS_UNKNOWN_REVISION
+    (0xC0000059, Win32Error::ERROR_REVISION_MISMATCH), // STATUS_REVISION_MISMATCH
+    (0xC000005A, Win32Error::ERROR_INVALID_OWNER), // STATUS_INVALID_OWNER
+    (0xC000005B, Win32Error::ERROR_INVALID_PRIMARY_GROUP), // STATUS_INVALID_PRIMARY_GROUP
+    (0xC000005C, Win32Error::ERROR_NO_IMPERSONATION_TOKEN), // STATUS_NO_IMPERSONATION_TOKEN
+    (0xC000005D, Win32Error::ERROR_CANT_DISABLE_MANDATORY), // STATUS_CANT_DISABLE_MANDATORY
+    (0xC000005E, Win32Error::ERROR_NO_LOGON_SERVERS), // STATUS_NO_LOGON_SERVERS
+    (0xC000005F, Win32Error::ERROR_NO_SUCH_LOGON_SESSION), // STATUS_NO_SUCH_LOGON_SESSION
+    (0xC0000060, Win32Error::ERROR_NO_SUCH_PRIVILEGE), // STATUS_NO_SUCH_PRIVILEGE
+    (0xC0000061, Win32Error::ERROR_PRIVILEGE_NOT_HELD), // STATUS_PRIVILEGE_NOT_HELD
+    (0xC0000062, Win32Error::ERROR_INVALID_ACCOUNT_NAME), // STATUS_INVALID_ACCOUNT_NAME
+    (0xC0000063, Win32Error::ERROR_USER_EXISTS), // STATUS_USER_EXISTS
+    (0xC0000064, Win32Error::ERROR_NO_SUCH_USER), // STATUS_NO_SUCH_USER
+    (0xC0000065, Win32Error::ERROR_GROUP_EXISTS), // STATUS_GROUP_EXISTS
+    (0xC0000066, Win32Error::ERROR_NO_SUCH_GROUP), // STATUS_NO_SUCH_GROUP
+    (0xC0000067, Win32Error::ERROR_MEMBER_IN_GROUP), // STATUS_MEMBER_IN_GROUP
+    (0xC0000068, Win32Error::ERROR_MEMBER_NOT_IN_GROUP), // STATUS_MEMBER_NOT_IN_GROUP
+    (0xC0000069, Win32Error::ERROR_LAST_ADMIN), // STATUS_LAST_ADMIN
+    (0xC000006A, Win32Error::ERROR_INVALID_PASSWORD), // STATUS_WRONG_PASSWORD
+    (0xC000006B, Win32Error::ERROR_ILL_FORMED_PASSWORD), // STATUS_ILL_FORMED_PASSWORD
+    (0xC000006C, Win32Error::ERROR_PASSWORD_RESTRICTION), // STATUS_PASSWORD_RESTRICTION
+    (0xC000006D, Win32Error::ERROR_LOGON_FAILURE), // STATUS_LOGON_FAILURE
+    (0xC000006E, Win32Error::ERROR_ACCOUNT_RESTRICTION), // STATUS_ACCOUNT_RESTRICTION
+    (0xC000006F, Win32Error::ERROR_INVALID_LOGON_HOURS), // STATUS_INVALID_LOGON_HOURS
+    (0xC0000070, Win32Error::ERROR_INVALID_WORKSTATION), // STATUS_INVALID_WORKSTATION
+    (0xC0000071, Win32Error::ERROR_PASSWORD_EXPIRED), // STATUS_PASSWORD_EXPIRED
+    (0xC0000072, Win32Error::ERROR_ACCOUNT_DISABLED), // STATUS_ACCOUNT_DISABLED
+    (0xC0000073, Win32Error::ERROR_NONE_MAPPED), // STATUS_NONE_MAPPED
+    (0xC0000074, Win32Error::ERROR_TOO_MANY_LUIDS_REQUESTED), // STATUS_TOO_MANY_LUIDS_REQUESTED
+    (0xC0000075, Win32Error::ERROR_LUIDS_EXHAUSTED), // STATUS_LUIDS_EXHAUSTED
+    (0xC0000076, Win32Error::ERROR_INVALID_SUB_AUTHORITY), // STATUS_INVALID_SUB_AUTHORITY
+    (0xC0000077, Win32Error::ERROR_INVALID_ACL), // STATUS_INVALID_ACL
+    (0xC0000078, Win32Error::ERROR_INVALID_SID), // STATUS_INVALID_SID
+    (0xC0000079, Win32Error::ERROR_INVALID_SECURITY_DESCR), // STATUS_INVALID_SECURITY_DESCR
+    (0xC000007A, Win32Error::ERROR_PROC_NOT_FOUND), // STATUS_PROCEDURE_NOT_FOUND
+    (0xC000007B, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_FORMAT
+    (0xC000007C, Win32Error::ERROR_NO_TOKEN), // STATUS_NO_TOKEN
+    (0xC000007D, Win32Error::ERROR_BAD_INHERITANCE_ACL), // STATUS_BAD_INHERITANCE_ACL
+    (0xC000007E, Win32Error::ERROR_NOT_LOCKED), // STATUS_RANGE_NOT_LOCKED
+    (0xC000007F, Win32Error::ERROR_DISK_FULL), // STATUS_DISK_FULL
+    (0xC0000080, Win32Error::ERROR_SERVER_DISABLED), // STATUS_SERVER_DISABLED
+    (0xC0000081, Win32Error::ERROR_SERVER_NOT_DISABLED), // STATUS_SERVER_NOT_DISABLED
+    (0xC0000082, Win32Error::ERROR_TOO_MANY_NAMES), // STATUS_TOO_MANY_GUIDS_REQUESTED
+    (0xC0000083, Win32Error::ERROR_NO_MORE_ITEMS), // STATUS_GUIDS_EXHAUSTED
+    (0xC0000084, Win32Error::ERROR_INVALID_ID_AUTHORITY), // STATUS_INVALID_ID_AUTHORITY
+    (0xC0000085, Win32Error::ERROR_NO_MORE_ITEMS), // STATUS_AGENTS_EXHAUSTED
+    (0xC0000086, Win32Error::ERROR_LABEL_TOO_LONG), // STATUS_INVALID_VOLUME_LABEL
+    (0xC0000087, Win32Error::ERROR_OUTOFMEMORY), // STATUS_SECTION_NOT_EXTENDED
+    (0xC0000088, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_NOT_MAPPED_DATA
+    (0xC0000089, Win32Error::ERROR_RESOURCE_DATA_NOT_FOUND), // STATUS_RESOURCE_DATA_NOT_FOUND
+    (0xC000008A, Win32Error::ERROR_RESOURCE_TYPE_NOT_FOUND), // STATUS_RESOURCE_TYPE_NOT_FOUND
+    (0xC000008B, Win32Error::ERROR_RESOURCE_NAME_NOT_FOUND), // STATUS_RESOURCE_NAME_NOT_FOUND
+    (0xC000008C, Win32Error(0xC000008C)), // STATUS_ARRAY_BOUNDS_EXCEEDED
+    (0xC000008D, Win32Error(0xC000008D)), // STATUS_FLOAT_DENORMAL_OPERAND
+    (0xC000008E, Win32Error(0xC000008E)), // STATUS_FLOAT_DIVIDE_BY_ZERO
+    (0xC000008F, Win32Error(0xC000008F)), // STATUS_FLOAT_INEXACT_RESULT
+    (0xC0000090, Win32Error(0xC0000090)), // STATUS_FLOAT_INVALID_OPERATION
+    (0xC0000091, Win32Error(0xC0000091)), // STATUS_FLOAT_OVERFLOW
+    (0xC0000092, Win32Error(0xC0000092)), // STATUS_FLOAT_STACK_CHECK
+    (0xC0000093, Win32Error(0xC0000093)), // STATUS_FLOAT_UNDERFLOW
+    (0xC0000094, Win32Error(0xC0000094)), // STATUS_INTEGER_DIVIDE_BY_ZERO
+    (0xC0000095, Win32Error(0xC0000095)), // STATUS_INTEGER_OVERFLOW
+    (0xC0000096, Win32Error(0xC0000096)), // STATUS_PRIVILEGED_INSTRUCTION
+    (0xC0000097, Win32Error::ERROR_NOT_ENOUGH_MEMORY), // STATUS_TOO_MANY_PAGING_FILES
+    (0xC0000098, Win32Error::ERROR_FILE_INVALID), // STATUS_FILE_INVALID
+    (0xC0000099, Win32Error::ERROR_ALLOTTED_SPACE_EXCEEDED), // STATUS_ALLOTTED_SPACE_EXCEEDED
+    (0xC000009A, Win32Error::ERROR_NO_SYSTEM_RESOURCES), // STATUS_INSUFFICIENT_RESOURCES
+    (0xC000009B, Win32Error::ERROR_PATH_NOT_FOUND), // STATUS_DFS_EXIT_PATH_FOUND
+    (0xC000009C, Win32Error::ERROR_CRC), // STATUS_DEVICE_DATA_ERROR
+    (0xC000009D, Win32Error::ERROR_NOT_READY), // STATUS_DEVICE_NOT_CONNECTED
+    (0xC000009E, Win32Error::ERROR_NOT_READY), // STATUS_DEVICE_POWER_FAILURE
+    (0xC000009F, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_FREE_VM_NOT_AT_BASE
+    (0xC00000A0, Win32Error::ERROR_INVALID_ADDRESS), // STATUS_MEMORY_NOT_ALLOCATED
+    (0xC00000A1, Win32Error::ERROR_WORKING_SET_QUOTA), // STATUS_WORKING_SET_QUOTA
+    (0xC00000A2, Win32Error::ERROR_WRITE_PROTECT), // STATUS_MEDIA_WRITE_PROTECTED
+    (0xC00000A3, Win32Error::ERROR_NOT_READY), // STATUS_DEVICE_NOT_READY
+    (0xC00000A4, Win32Error::ERROR_INVALID_GROUP_ATTRIBUTES), // STATUS_INVALID_GROUP_ATTRIBUTES
+    (0xC00000A5, Win32Error::ERROR_BAD_IMPERSONATION_LEVEL), // STATUS_BAD_IMPERSONATION_LEVEL
+    (0xC00000A6, Win32Error::ERROR_CANT_OPEN_ANONYMOUS), // STATUS_CANT_OPEN_ANONYMOUS
+    (0xC00000A7, Win32Error::ERROR_BAD_VALIDATION_CLASS), // STATUS_BAD_VALIDATION_CLASS
+    (0xC00000A8, Win32Error::ERROR_BAD_TOKEN_TYPE), // STATUS_BAD_TOKEN_TYPE
+    (0xC00000A9, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_BAD_MASTER_BOOT_RECORD
+    (0xC00000AA, Win32Error::ERROR_INSTRUCTION_MISALIGNMENT), // STATUS_INSTRUCTION_MISALIGNMENT
+    (0xC00000AB, Win32Error::ERROR_PIPE_BUSY), // STATUS_INSTANCE_NOT_AVAILABLE
+    (0xC00000AC, Win32Error::ERROR_PIPE_BUSY), // STATUS_PIPE_NOT_AVAILABLE
+    (0xC00000AD, Win32Error::ERROR_BAD_PIPE), // STATUS_INVALID_PIPE_STATE
+    (0xC00000AE, Win32Error::ERROR_PIPE_BUSY), // STATUS_PIPE_BUSY
+    (0xC00000AF, Win32Error::ERROR_INVALID_FUNCTION), // STATUS_ILLEGAL_FUNCTION
+    (0xC00000B0, Win32Error::ERROR_PIPE_NOT_CONNECTED), // STATUS_PIPE_DISCONNECTED
+    (0xC00000B1, Win32Error::ERROR_NO_DATA), // STATUS_PIPE_CLOSING
+    (0xC00000B2, Win32Error::ERROR_PIPE_CONNECTED), // STATUS_PIPE_CONNECTED
+    (0xC00000B3, Win32Error::ERROR_PIPE_LISTENING), // STATUS_PIPE_LISTENING
+    (0xC00000B4, Win32Error::ERROR_BAD_PIPE), // STATUS_INVALID_READ_MODE
+    (0xC00000B5, Win32Error::ERROR_SEM_TIMEOUT), // STATUS_IO_TIMEOUT
+    (0xC00000B6, Win32Error::ERROR_HANDLE_EOF), // STATUS_FILE_FORCED_CLOSED
+    (0xC00000B7, Win32Error::ERROR_PROFILING_NOT_STARTED), // STATUS_PROFILING_NOT_STARTED
+    (0xC00000B8, Win32Error::ERROR_PROFILING_NOT_STOPPED), // STATUS_PROFILING_NOT_STOPPED
+    (0xC00000B9, Win32Error::ERROR_COULD_NOT_INTERPRET), // STATUS_COULD_NOT_INTERPRET
+    (0xC00000BA, Win32Error::ERROR_ACCESS_DENIED), // STATUS_FILE_IS_A_DIRECTORY
+    (0xC00000BB, Win32Error::ERROR_NOT_SUPPORTED), // STATUS_NOT_SUPPORTED
+    (0xC00000BC, Win32Error::ERROR_REM_NOT_LIST), // STATUS_REMOTE_NOT_LISTENING
+    (0xC00000BD, Win32Error::ERROR_DUP_NAME), // STATUS_DUPLICATE_NAME
+    (0xC00000BE, Win32Error::ERROR_BAD_NETPATH), // STATUS_BAD_NETWORK_PATH
+    (0xC00000BF, Win32Error::ERROR_NETWORK_BUSY), // STATUS_NETWORK_BUSY
+    (0xC00000C0, Win32Error::ERROR_DEV_NOT_EXIST), // STATUS_DEVICE_DOES_NOT_EXIST
+    (0xC00000C1, Win32Error::ERROR_TOO_MANY_CMDS), // STATUS_TOO_MANY_COMMANDS
+    (0xC00000C2, Win32Error::ERROR_ADAP_HDW_ERR), // STATUS_ADAPTER_HARDWARE_ERROR
+    (0xC00000C3, Win32Error::ERROR_BAD_NET_RESP), // STATUS_INVALID_NETWORK_RESPONSE
+    (0xC00000C4, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_UNEXPECTED_NETWORK_ERROR
+    (0xC00000C5, Win32Error::ERROR_BAD_REM_ADAP), // STATUS_BAD_REMOTE_ADAPTER
+    (0xC00000C6, Win32Error::ERROR_PRINTQ_FULL), // STATUS_PRINT_QUEUE_FULL
+    (0xC00000C7, Win32Error::ERROR_NO_SPOOL_SPACE), // STATUS_NO_SPOOL_SPACE
+    (0xC00000C8, Win32Error::ERROR_PRINT_CANCELLED), // STATUS_PRINT_CANCELLED
+    (0xC00000C9, Win32Error::ERROR_NETNAME_DELETED), // STATUS_NETWORK_NAME_DELETED
+    (0xC00000CA, Win32Error::ERROR_NETWORK_ACCESS_DENIED), // STATUS_NETWORK_ACCESS_DENIED
+    (0xC00000CB, Win32Error::ERROR_BAD_DEV_TYPE), // STATUS_BAD_DEVICE_TYPE
+    (0xC00000CC, Win32Error::ERROR_BAD_NET_NAME), // STATUS_BAD_NETWORK_NAME
+    (0xC00000CD, Win32Error::ERROR_TOO_MANY_NAMES), // STATUS_TOO_MANY_NAMES
+    (0xC00000CE, Win32Error::ERROR_TOO_MANY_SESS), // STATUS_TOO_MANY_SESSIONS
+    (0xC00000CF, Win32Error::ERROR_SHARING_PAUSED), // STATUS_SHARING_PAUSED
+    (0xC00000D0, Win32Error::ERROR_REQ_NOT_ACCEP), // STATUS_REQUEST_NOT_ACCEPTED
+    (0xC00000D1, Win32Error::ERROR_REDIR_PAUSED), // STATUS_REDIRECTOR_PAUSED
+    (0xC00000D2, Win32Error::ERROR_NET_WRITE_FAULT), // STATUS_NET_WRITE_FAULT
+    (0xC00000D3, Win32Error::ERROR_PROFILING_AT_LIMIT), // STATUS_PROFILING_AT_LIMIT
+    (0xC00000D4, Win32Error::ERROR_NOT_SAME_DEVICE), // STATUS_NOT_SAME_DEVICE
+    (0xC00000D5, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FILE_RENAMED
+    (0xC00000D6, Win32Error::ERROR_VC_DISCONNECTED), // STATUS_VIRTUAL_CIRCUIT_CLOSED
+    (0xC00000D7, Win32Error::ERROR_NO_SECURITY_ON_OBJECT), // STATUS_NO_SECURITY_ON_OBJECT
+    (0xC00000D8, Win32Error::ERROR_CANT_WAIT), // STATUS_CANT_WAIT
+    (0xC00000D9, Win32Error::ERROR_NO_DATA), // STATUS_PIPE_EMPTY
+    (0xC00000DA, Win32Error::ERROR_CANT_ACCESS_DOMAIN_INFO), // STATUS_CANT_ACCESS_DOMAIN_INFO
+    (0xC00000DB, Win32Error::ERROR_CANT_TERMINATE_SELF), // STATUS_CANT_TERMINATE_SELF
+    (0xC00000DC, Win32Error::ERROR_INVALID_SERVER_STATE), // STATUS_INVALID_SERVER_STATE
+    (0xC00000DD, Win32Error::ERROR_INVALID_DOMAIN_STATE), // STATUS_INVALID_DOMAIN_STATE
+    (0xC00000DE, Win32Error::ERROR_INVALID_DOMAIN_ROLE), // STATUS_INVALID_DOMAIN_ROLE
+    (0xC00000DF, Win32Error::ERROR_NO_SUCH_DOMAIN), // STATUS_NO_SUCH_DOMAIN
+    (0xC00000E0, Win32Error::ERROR_DOMAIN_EXISTS), // STATUS_DOMAIN_EXISTS
+    (0xC00000E1, Win32Error::ERROR_DOMAIN_LIMIT_EXCEEDED), // STATUS_DOMAIN_LIMIT_EXCEEDED
+    (0xC00000E2, Win32Error::ERROR_OPLOCK_NOT_GRANTED), // STATUS_OPLOCK_NOT_GRANTED
+    (0xC00000E3, Win32Error::ERROR_INVALID_OPLOCK_PROTOCOL), // STATUS_INVALID_OPLOCK_PROTOCOL
+    (0xC00000E4, Win32Error::ERROR_INTERNAL_DB_CORRUPTION), // STATUS_INTERNAL_DB_CORRUPTION
+    (0xC00000E5, Win32Error::ERROR_INTERNAL_ERROR), // STATUS_INTERNAL_ERROR
+    (0xC00000E6, Win32Error::ERROR_GENERIC_NOT_MAPPED), // STATUS_GENERIC_NOT_MAPPED
+    (0xC00000E7, Win32Error::ERROR_BAD_DESCRIPTOR_FORMAT), // STATUS_BAD_DESCRIPTOR_FORMAT
+    (0xC00000E8, Win32Error::ERROR_INVALID_USER_BUFFER), // STATUS_INVALID_USER_BUFFER
+    (0xC00000E9, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_UNEXPECTED_IO_ERROR
+    (0xC00000EA, Win32Error::ERROR_UNEXPECTED_MM_CREATE_ERR), // STATUS_UNEXPECTED_MM_CREATE_ERR
+    (0xC00000EB, Win32Error::ERROR_UNEXPECTED_MM_MAP_ERROR), // STATUS_UNEXPECTED_MM_MAP_ERROR
+    (0xC00000EC, Win32Error::ERROR_UNEXPECTED_MM_EXTEND_ERR), // STATUS_UNEXPECTED_MM_EXTEND_ERR
+    (0xC00000ED, Win32Error::ERROR_NOT_LOGON_PROCESS), // STATUS_NOT_LOGON_PROCESS
+    (0xC00000EE, Win32Error::ERROR_LOGON_SESSION_EXISTS), // STATUS_LOGON_SESSION_EXISTS
+    (0xC00000EF, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_1
+    (0xC00000F0, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_2
+    (0xC00000F1, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_3
+    (0xC00000F2, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_4
+    (0xC00000F3, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_5
+    (0xC00000F4, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_6
+    (0xC00000F5, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_7
+    (0xC00000F6, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_8
+    (0xC00000F7, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_9
+    (0xC00000F8, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_10
+    (0xC00000F9, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_11
+    (0xC00000FA, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_INVALID_PARAMETER_12
+    (0xC00000FB, Win32Error::ERROR_PATH_NOT_FOUND), // STATUS_REDIRECTOR_NOT_STARTED
+    (0xC00000FC, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_REDIRECTOR_STARTED
+    (0xC00000FD, Win32Error::ERROR_STACK_OVERFLOW), // STATUS_STACK_OVERFLOW
+    (0xC00000FE, Win32Error::ERROR_NO_SUCH_PACKAGE), // STATUS_NO_SUCH_PACKAGE
+    (0xC00000FF, Win32Error::ERROR_BAD_FUNCTION_TABLE), // STATUS_BAD_FUNCTION_TABLE
+    (0xC0000100, Win32Error::ERROR_ENVVAR_NOT_FOUND), // STATUS_VARIABLE_NOT_FOUND
+    (0xC0000101, Win32Error::ERROR_DIR_NOT_EMPTY), // STATUS_DIRECTORY_NOT_EMPTY
+    (0xC0000102, Win32Error::ERROR_FILE_CORRUPT), // STATUS_FILE_CORRUPT_ERROR
+    (0xC0000103, Win32Error::ERROR_DIRECTORY), // STATUS_NOT_A_DIRECTORY
+    (0xC0000104, Win32Error::ERROR_BAD_LOGON_SESSION_STATE), // STATUS_BAD_LOGON_SESSION_STATE
+    (0xC0000105, Win32Error::ERROR_LOGON_SESSION_COLLISION), // STATUS_LOGON_SESSION_COLLISION
+    (0xC0000106, Win32Error::ERROR_FILENAME_EXCED_RANGE), // STATUS_NAME_TOO_LONG
+    (0xC0000107, Win32Error::ERROR_OPEN_FILES), // STATUS_FILES_OPEN
+    (0xC0000108, Win32Error::ERROR_DEVICE_IN_USE), // STATUS_CONNECTION_IN_USE
+    (0xC0000109, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_MESSAGE_NOT_FOUND
+    (0xC000010A, Win32Error::ERROR_ACCESS_DENIED), // STATUS_PROCESS_IS_TERMINATING
+    (0xC000010B, Win32Error::ERROR_INVALID_LOGON_TYPE), // STATUS_INVALID_LOGON_TYPE
+    (0xC000010C, Win32Error::ERROR_NO_GUID_TRANSLATION), // STATUS_NO_GUID_TRANSLATION
+    (0xC000010D, Win32Error::ERROR_CANNOT_IMPERSONATE), // STATUS_CANNOT_IMPERSONATE
+    (0xC000010E, Win32Error::ERROR_SERVICE_ALREADY_RUNNING), // STATUS_IMAGE_ALREADY_LOADED
+    (0xC000010F, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_NOT_PRESENT
+    (0xC0000110, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_LID_NOT_EXIST
+    (0xC0000111, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_LID_ALREADY_OWNED
+    (0xC0000112, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_NOT_LID_OWNER
+    (0xC0000113, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_INVALID_COMMAND
+    (0xC0000114, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_INVALID_LID
+    (0xC0000115, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_SELECTOR_NOT_AVAILABLE
+    (0xC0000116, Win32Error::ERROR_ABIOS_ERROR), // STATUS_ABIOS_INVALID_SELECTOR
+    (0xC0000117, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NO_LDT
+    (0xC0000118, Win32Error::ERROR_INVALID_LDT_SIZE), // STATUS_INVALID_LDT_SIZE
+    (0xC0000119, Win32Error::ERROR_INVALID_LDT_OFFSET), // STATUS_INVALID_LDT_OFFSET
+    (0xC000011A, Win32Error::ERROR_INVALID_LDT_DESCRIPTOR), // STATUS_INVALID_LDT_DESCRIPTOR
+    (0xC000011B, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_NE_FORMAT
+    (0xC000011C, Win32Error::ERROR_RXACT_INVALID_STATE), // STATUS_RXACT_INVALID_STATE
+    (0xC000011D, Win32Error::ERROR_RXACT_COMMIT_FAILURE), // STATUS_RXACT_COMMIT_FAILURE
+    (0xC000011E, Win32Error::ERROR_FILE_INVALID), // STATUS_MAPPED_FILE_SIZE_ZERO
+    (0xC000011F, Win32Error::ERROR_TOO_MANY_OPEN_FILES), // STATUS_TOO_MANY_OPENED_FILES
+    (0xC0000120, Win32Error::ERROR_OPERATION_ABORTED), // STATUS_CANCELLED
+    (0xC0000121, Win32Error::ERROR_ACCESS_DENIED), // STATUS_CANNOT_DELETE
+    (0xC0000122, Win32Error::ERROR_INVALID_COMPUTERNAME), // STATUS_INVALID_COMPUTER_NAME
+    (0xC0000123, Win32Error::ERROR_ACCESS_DENIED), // STATUS_FILE_DELETED
+    (0xC0000124, Win32Error::ERROR_SPECIAL_ACCOUNT), // STATUS_SPECIAL_ACCOUNT
+    (0xC0000125, Win32Error::ERROR_SPECIAL_GROUP), // STATUS_SPECIAL_GROUP
+    (0xC0000126, Win32Error::ERROR_SPECIAL_USER), // STATUS_SPECIAL_USER
+    (0xC0000127, Win32Error::ERROR_MEMBERS_PRIMARY_GROUP), // STATUS_MEMBERS_PRIMARY_GROUP
+    (0xC0000128, Win32Error::ERROR_INVALID_HANDLE), // STATUS_FILE_CLOSED
+    (0xC0000129, Win32Error::ERROR_TOO_MANY_THREADS), // STATUS_TOO_MANY_THREADS
+    (0xC000012A, Win32Error::ERROR_THREAD_NOT_IN_PROCESS), // STATUS_THREAD_NOT_IN_PROCESS
+    (0xC000012B, Win32Error::ERROR_TOKEN_ALREADY_IN_USE), // STATUS_TOKEN_ALREADY_IN_USE
+    (0xC000012C, Win32Error::ERROR_PAGEFILE_QUOTA_EXCEEDED), // STATUS_PAGEFILE_QUOTA_EXCEEDED
+    (0xC000012D, Win32Error::ERROR_COMMITMENT_LIMIT), // STATUS_COMMITMENT_LIMIT
+    (0xC000012E, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_LE_FORMAT
+    (0xC000012F, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_NOT_MZ
+    (0xC0000130, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_PROTECT
+    (0xC0000131, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_WIN_16
+    (0xC0000132, Win32Error::ERROR_LOGON_SERVER_CONFLICT), // STATUS_LOGON_SERVER_CONFLICT
+    (0xC0000133, Win32Error::ERROR_TIME_SKEW), // STATUS_TIME_DIFFERENCE_AT_DC
+    (0xC0000134, Win32Error::ERROR_SYNCHRONIZATION_REQUIRED), // STATUS_SYNCHRONIZATION_REQUIRED
+    (0xC0000135, Win32Error::ERROR_MOD_NOT_FOUND), // STATUS_DLL_NOT_FOUND
+    (0xC0000136, Win32Error::ERROR_NET_OPEN_FAILED), // STATUS_OPEN_FAILED
+    (0xC0000137, Win32Error::ERROR_IO_PRIVILEGE_FAILED), // STATUS_IO_PRIVILEGE_FAILED
+    (0xC0000138, Win32Error::ERROR_INVALID_ORDINAL), // STATUS_ORDINAL_NOT_FOUND
+    (0xC0000139, Win32Error::ERROR_PROC_NOT_FOUND), // STATUS_ENTRYPOINT_NOT_FOUND
+    (0xC000013A, Win32Error::ERROR_CONTROL_C_EXIT), // STATUS_CONTROL_C_EXIT
+    (0xC000013B, Win32Error::ERROR_NETNAME_DELETED), // STATUS_LOCAL_DISCONNECT
+    (0xC000013C, Win32Error::ERROR_NETNAME_DELETED), // STATUS_REMOTE_DISCONNECT
+    (0xC000013D, Win32Error::ERROR_REM_NOT_LIST), // STATUS_REMOTE_RESOURCES
+    (0xC000013E, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_LINK_FAILED
+    (0xC000013F, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_LINK_TIMEOUT
+    (0xC0000140, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_INVALID_CONNECTION
+    (0xC0000141, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_INVALID_ADDRESS
+    (0xC0000142, Win32Error::ERROR_DLL_INIT_FAILED), // STATUS_DLL_INIT_FAILED
+    (0xC0000143, Win32Error::ERROR_MISSING_SYSTEMFILE), // STATUS_MISSING_SYSTEMFILE
+    (0xC0000144, Win32Error::ERROR_UNHANDLED_EXCEPTION), // STATUS_UNHANDLED_EXCEPTION
+    (0xC0000145, Win32Error::ERROR_APP_INIT_FAILURE), // STATUS_APP_INIT_FAILURE
+    (0xC0000146, Win32Error::ERROR_PAGEFILE_CREATE_FAILED), // STATUS_PAGEFILE_CREATE_FAILED
+    (0xC0000147, Win32Error::ERROR_NO_PAGEFILE), // STATUS_NO_PAGEFILE
+    (0xC0000148, Win32Error::ERROR_INVALID_LEVEL), // STATUS_INVALID_LEVEL
+    (0xC0000149, Win32Error::ERROR_INVALID_PASSWORD), // STATUS_WRONG_PASSWORD_CORE
+    (0xC000014A, Win32Error::ERROR_ILLEGAL_FLOAT_CONTEXT), // STATUS_ILLEGAL_FLOAT_CONTEXT
+    (0xC000014B, Win32Error::ERROR_BROKEN_PIPE), // STATUS_PIPE_BROKEN
+    (0xC000014C, Win32Error::ERROR_BADDB), // STATUS_REGISTRY_CORRUPT
+    (0xC000014D, Win32Error::ERROR_REGISTRY_IO_FAILED), // STATUS_REGISTRY_IO_FAILED
+    (0xC000014E, Win32Error::ERROR_NO_EVENT_PAIR), // STATUS_NO_EVENT_PAIR
+    (0xC000014F, Win32Error::ERROR_UNRECOGNIZED_VOLUME), // STATUS_UNRECOGNIZED_VOLUME
+    (0xC0000150, Win32Error::ERROR_SERIAL_NO_DEVICE), // STATUS_SERIAL_NO_DEVICE_INITED
+    (0xC0000151, Win32Error::ERROR_NO_SUCH_ALIAS), // STATUS_NO_SUCH_ALIAS
+    (0xC0000152, Win32Error::ERROR_MEMBER_NOT_IN_ALIAS), // STATUS_MEMBER_NOT_IN_ALIAS
+    (0xC0000153, Win32Error::ERROR_MEMBER_IN_ALIAS), // STATUS_MEMBER_IN_ALIAS
+    (0xC0000154, Win32Error::ERROR_ALIAS_EXISTS), // STATUS_ALIAS_EXISTS
+    (0xC0000155, Win32Error::ERROR_LOGON_NOT_GRANTED), // STATUS_LOGON_NOT_GRANTED
+    (0xC0000156, Win32Error::ERROR_TOO_MANY_SECRETS), // STATUS_TOO_MANY_SECRETS
+    (0xC0000157, Win32Error::ERROR_SECRET_TOO_LONG), // STATUS_SECRET_TOO_LONG
+    (0xC0000158, Win32Error::ERROR_INTERNAL_DB_ERROR), // STATUS_INTERNAL_DB_ERROR
+    (0xC0000159, Win32Error::ERROR_FULLSCREEN_MODE), // STATUS_FULLSCREEN_MODE
+    (0xC000015A, Win32Error::ERROR_TOO_MANY_CONTEXT_IDS), // STATUS_TOO_MANY_CONTEXT_IDS
+    (0xC000015B, Win32Error::ERROR_LOGON_TYPE_NOT_GRANTED), // STATUS_LOGON_TYPE_NOT_GRANTED
+    (0xC000015C, Win32Error::ERROR_NOT_REGISTRY_FILE), // STATUS_NOT_REGISTRY_FILE
+    (0xC000015D, Win32Error::ERROR_NT_CROSS_ENCRYPTION_REQUIRED), // STATUS_NT_CROSS_ENCRYPTION_REQUIRED
+    (0xC000015E, Win32Error::ERROR_DOMAIN_CTRLR_CONFIG_ERROR), // STATUS_DOMAIN_CTRLR_CONFIG_ERROR
+    (0xC000015F, Win32Error::ERROR_IO_DEVICE), // STATUS_FT_MISSING_MEMBER
+    (0xC0000160, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_ILL_FORMED_SERVICE_ENTRY
+    (0xC0000161, Win32Error::ERROR_ILLEGAL_CHARACTER), // STATUS_ILLEGAL_CHARACTER
+    (0xC0000162, Win32Error::ERROR_NO_UNICODE_TRANSLATION), // STATUS_UNMAPPABLE_CHARACTER
+    (0xC0000163, Win32Error::ERROR_UNDEFINED_CHARACTER), // STATUS_UNDEFINED_CHARACTER
+    (0xC0000164, Win32Error::ERROR_FLOPPY_VOLUME), // STATUS_FLOPPY_VOLUME
+    (0xC0000165, Win32Error::ERROR_FLOPPY_ID_MARK_NOT_FOUND), // STATUS_FLOPPY_ID_MARK_NOT_FOUND
+    (0xC0000166, Win32Error::ERROR_FLOPPY_WRONG_CYLINDER), // STATUS_FLOPPY_WRONG_CYLINDER
+    (0xC0000167, Win32Error::ERROR_FLOPPY_UNKNOWN_ERROR), // STATUS_FLOPPY_UNKNOWN_ERROR
+    (0xC0000168, Win32Error::ERROR_FLOPPY_BAD_REGISTERS), // STATUS_FLOPPY_BAD_REGISTERS
+    (0xC0000169, Win32Error::ERROR_DISK_RECALIBRATE_FAILED), // STATUS_DISK_RECALIBRATE_FAILED
+    (0xC000016A, Win32Error::ERROR_DISK_OPERATION_FAILED), // STATUS_DISK_OPERATION_FAILED
+    (0xC000016B, Win32Error::ERROR_DISK_RESET_FAILED), // STATUS_DISK_RESET_FAILED
+    (0xC000016C, Win32Error::ERROR_IRQ_BUSY), // STATUS_SHARED_IRQ_BUSY
+    (0xC000016D, Win32Error::ERROR_IO_DEVICE), // STATUS_FT_ORPHANING
+    (0xC000016E, Win32Error::ERROR_BIOS_FAILED_TO_CONNECT_INTERRUPT), // STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT
+    (0xC0000172, Win32Error::ERROR_PARTITION_FAILURE), // STATUS_PARTITION_FAILURE
+    (0xC0000173, Win32Error::ERROR_INVALID_BLOCK_LENGTH), // STATUS_INVALID_BLOCK_LENGTH
+    (0xC0000174, Win32Error::ERROR_DEVICE_NOT_PARTITIONED), // STATUS_DEVICE_NOT_PARTITIONED
+    (0xC0000175, Win32Error::ERROR_UNABLE_TO_LOCK_MEDIA), // STATUS_UNABLE_TO_LOCK_MEDIA
+    (0xC0000176, Win32Error::ERROR_UNABLE_TO_UNLOAD_MEDIA), // STATUS_UNABLE_TO_UNLOAD_MEDIA
+    (0xC0000177, Win32Error::ERROR_EOM_OVERFLOW), // STATUS_EOM_OVERFLOW
+    (0xC0000178, Win32Error::ERROR_NO_MEDIA_IN_DRIVE), // STATUS_NO_MEDIA
+    (0xC000017A, Win32Error::ERROR_NO_SUCH_MEMBER), // STATUS_NO_SUCH_MEMBER
+    (0xC000017B, Win32Error::ERROR_INVALID_MEMBER), // STATUS_INVALID_MEMBER
+    (0xC000017C, Win32Error::ERROR_KEY_DELETED), // STATUS_KEY_DELETED
+    (0xC000017D, Win32Error::ERROR_NO_LOG_SPACE), // STATUS_NO_LOG_SPACE
+    (0xC000017E, Win32Error::ERROR_TOO_MANY_SIDS), // STATUS_TOO_MANY_SIDS
+    (0xC000017F, Win32Error::ERROR_LM_CROSS_ENCRYPTION_REQUIRED), // STATUS_LM_CROSS_ENCRYPTION_REQUIRED
+    (0xC0000180, Win32Error::ERROR_KEY_HAS_CHILDREN), // STATUS_KEY_HAS_CHILDREN
+    (0xC0000181, Win32Error::ERROR_CHILD_MUST_BE_VOLATILE), // STATUS_CHILD_MUST_BE_VOLATILE
+    (0xC0000182, Win32Error::ERROR_INVALID_PARAMETER), // STATUS_DEVICE_CONFIGURATION_ERROR
+    (0xC0000183, Win32Error::ERROR_IO_DEVICE), // STATUS_DRIVER_INTERNAL_ERROR
+    (0xC0000184, Win32Error::ERROR_BAD_COMMAND), // STATUS_INVALID_DEVICE_STATE
+    (0xC0000185, Win32Error::ERROR_IO_DEVICE), // STATUS_IO_DEVICE_ERROR
+    (0xC0000186, Win32Error::ERROR_IO_DEVICE), // STATUS_DEVICE_PROTOCOL_ERROR
+    (0xC0000187, Win32Error::ERROR_BACKUP_CONTROLLER), // STATUS_BACKUP_CONTROLLER
+    (0xC0000188, Win32Error::ERROR_LOG_FILE_FULL), // STATUS_LOG_FILE_FULL
+    (0xC0000189, Win32Error::ERROR_WRITE_PROTECT), // STATUS_TOO_LATE
+    (0xC000018A, Win32Error::ERROR_NO_TRUST_LSA_SECRET), // STATUS_NO_TRUST_LSA_SECRET
+    (0xC000018B, Win32Error::ERROR_NO_TRUST_SAM_ACCOUNT), // STATUS_NO_TRUST_SAM_ACCOUNT
+    (0xC000018C, Win32Error::ERROR_TRUSTED_DOMAIN_FAILURE), // STATUS_TRUSTED_DOMAIN_FAILURE
+    (0xC000018D, Win32Error::ERROR_TRUSTED_RELATIONSHIP_FAILURE), // STATUS_TRUSTED_RELATIONSHIP_FAILURE
+    (0xC000018E, Win32Error::ERROR_EVENTLOG_FILE_CORRUPT), // STATUS_EVENTLOG_FILE_CORRUPT
+    (0xC000018F, Win32Error::ERROR_EVENTLOG_CANT_START), // STATUS_EVENTLOG_CANT_START
+    (0xC0000190, Win32Error::ERROR_TRUST_FAILURE), // STATUS_TRUST_FAILURE
+    (0xC0000191, Win32Error::ERROR_MUTANT_LIMIT_EXCEEDED), // STATUS_MUTANT_LIMIT_EXCEEDED
+    (0xC0000192, Win32Error::ERROR_NETLOGON_NOT_STARTED), // STATUS_NETLOGON_NOT_STARTED
+    (0xC0000193, Win32Error::ERROR_ACCOUNT_EXPIRED), // STATUS_ACCOUNT_EXPIRED
+    (0xC0000194, Win32Error::ERROR_POSSIBLE_DEADLOCK), // STATUS_POSSIBLE_DEADLOCK
+    (0xC0000195, Win32Error::ERROR_SESSION_CREDENTIAL_CONFLICT), // STATUS_NETWORK_CREDENTIAL_CONFLICT
+    (0xC0000196, Win32Error::ERROR_REMOTE_SESSION_LIMIT_EXCEEDED), // STATUS_REMOTE_SESSION_LIMIT
+    (0xC0000197, Win32Error::ERROR_EVENTLOG_FILE_CHANGED), // STATUS_EVENTLOG_FILE_CHANGED
+    (0xC0000198, Win32Error::ERROR_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT), // STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT
+    (0xC0000199, Win32Error::ERROR_NOLOGON_WORKSTATION_TRUST_ACCOUNT), // STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT
+    (0xC000019A, Win32Error::ERROR_NOLOGON_SERVER_TRUST_ACCOUNT), // STATUS_NOLOGON_SERVER_TRUST_ACCOUNT
+    (0xC000019B, Win32Error::ERROR_DOMAIN_TRUST_INCONSISTENT), // STATUS_DOMAIN_TRUST_INCONSISTENT
+    (0xC000019C, Win32Error::ERROR_FS_DRIVER_REQUIRED), // STATUS_FS_DRIVER_REQUIRED
+    (0xC000019D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_IMAGE_ALREADY_LOADED_AS_DLL
+    (0xC000019E, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING
+    (0xC000019F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME
+    (0xC00001A0, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SECURITY_STREAM_IS_INCONSISTENT
+    (0xC00001A1, Win32Error::ERROR_INVALID_LOCK_RANGE), // STATUS_INVALID_LOCK_RANGE
+    (0xC00001A2, Win32Error::ERROR_INVALID_ACE_CONDITION), // STATUS_INVALID_ACE_CONDITION
+    (0xC00001A3, Win32Error::ERROR_IMAGE_SUBSYSTEM_NOT_PRESENT), // STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT
+    (0xC00001A4, Win32Error::ERROR_NOTIFICATION_GUID_ALREADY_DEFINED), // STATUS_NOTIFICATION_GUID_ALREADY_DEFINED
+    (0xC00001A5, Win32Error::ERROR_INVALID_EXCEPTION_HANDLER), // STATUS_INVALID_EXCEPTION_HANDLER
+    (0xC00001A6, Win32Error::ERROR_DUPLICATE_PRIVILEGES), // STATUS_DUPLICATE_PRIVILEGES
+    (0xC00001A7, Win32Error::ERROR_NOT_ALLOWED_ON_SYSTEM_FILE), // STATUS_NOT_ALLOWED_ON_SYSTEM_FILE
+    (0xC00001A8, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_REPAIR_NEEDED
+    (0xC00001A9, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_QUOTA_NOT_ENABLED
+    (0xC00001AA, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NO_APPLICATION_PACKAGE
+    (0xC00001AB, Win32Error::ERROR_FILE_METADATA_OPTIMIZATION_IN_PROGRESS), // STATUS_FILE_METADATA_OPTIMIZATION_IN_PROGRESS
+    (0xC00001AC, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NOT_SAME_OBJECT
+    (0xC00001AD, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FATAL_MEMORY_EXHAUSTION
+    (0xC00001AE, Win32Error::ERROR_PROCESS_NOT_IN_JOB), // STATUS_ERROR_PROCESS_NOT_IN_JOB
+    (0xC00001AF, Win32Error::ERROR_CPU_SET_INVALID), // STATUS_CPU_SET_INVALID
+    (0xC0000201, Win32Error::ERROR_NETWORK_ACCESS_DENIED), // STATUS_NETWORK_OPEN_RESTRICTION
+    (0xC0000202, Win32Error::ERROR_NO_USER_SESSION_KEY), // STATUS_NO_USER_SESSION_KEY
+    (0xC0000203, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_USER_SESSION_DELETED
+    (0xC0000204, Win32Error::ERROR_RESOURCE_LANG_NOT_FOUND), // STATUS_RESOURCE_LANG_NOT_FOUND
+    (0xC0000205, Win32Error::ERROR_NOT_ENOUGH_SERVER_MEMORY), // STATUS_INSUFF_SERVER_RESOURCES
+    (0xC0000206, Win32Error::ERROR_INVALID_USER_BUFFER), // STATUS_INVALID_BUFFER_SIZE
+    (0xC0000207, Win32Error::ERROR_INVALID_NETNAME), // STATUS_INVALID_ADDRESS_COMPONENT
+    (0xC0000208, Win32Error::ERROR_INVALID_NETNAME), // STATUS_INVALID_ADDRESS_WILDCARD
+    (0xC0000209, Win32Error::ERROR_TOO_MANY_NAMES), // STATUS_TOO_MANY_ADDRESSES
+    (0xC000020A, Win32Error::ERROR_DUP_NAME), // STATUS_ADDRESS_ALREADY_EXISTS
+    (0xC000020B, Win32Error::ERROR_NETNAME_DELETED), // STATUS_ADDRESS_CLOSED
+    (0xC000020C, Win32Error::ERROR_NETNAME_DELETED), // STATUS_CONNECTION_DISCONNECTED
+    (0xC000020D, Win32Error::ERROR_NETNAME_DELETED), // STATUS_CONNECTION_RESET
+    (0xC000020E, Win32Error::ERROR_TOO_MANY_NAMES), // STATUS_TOO_MANY_NODES
+    (0xC000020F, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_ABORTED
+    (0xC0000210, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_TIMED_OUT
+    (0xC0000211, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_NO_RELEASE
+    (0xC0000212, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_NO_MATCH
+    (0xC0000213, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_RESPONDED
+    (0xC0000214, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_INVALID_ID
+    (0xC0000215, Win32Error::ERROR_UNEXP_NET_ERR), // STATUS_TRANSACTION_INVALID_TYPE
+    (0xC0000216, Win32Error::ERROR_NOT_SUPPORTED), // STATUS_NOT_SERVER_SESSION
+    (0xC0000217, Win32Error::ERROR_NOT_SUPPORTED), // STATUS_NOT_CLIENT_SESSION
+    (0xC0000218, Win32Error::ERROR_CANNOT_LOAD_REGISTRY_FILE), // STATUS_CANNOT_LOAD_REGISTRY_FILE
+    (0xC0000219, Win32Error::ERROR_DEBUG_ATTACH_FAILED), // STATUS_DEBUG_ATTACH_FAILED
+    (0xC000021A, Win32Error::ERROR_SYSTEM_PROCESS_TERMINATED), // STATUS_SYSTEM_PROCESS_TERMINATED
+    (0xC000021B, Win32Error::ERROR_DATA_NOT_ACCEPTED), // STATUS_DATA_NOT_ACCEPTED
+    (0xC000021C, Win32Error::ERROR_NO_BROWSER_SERVERS_FOUND), // STATUS_NO_BROWSER_SERVERS_FOUND
+    (0xC000021D, Win32Error::ERROR_VDM_HARD_ERROR), // STATUS_VDM_HARD_ERROR
+    (0xC000021E, Win32Error::ERROR_DRIVER_CANCEL_TIMEOUT), // STATUS_DRIVER_CANCEL_TIMEOUT
+    (0xC000021F, Win32Error::ERROR_REPLY_MESSAGE_MISMATCH), // STATUS_REPLY_MESSAGE_MISMATCH
+    (0xC0000220, Win32Error::ERROR_MAPPED_ALIGNMENT), // STATUS_MAPPED_ALIGNMENT
+    (0xC0000221, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_IMAGE_CHECKSUM_MISMATCH
+    (0xC0000222, Win32Error::ERROR_LOST_WRITEBEHIND_DATA), // STATUS_LOST_WRITEBEHIND_DATA
+    (0xC0000223, Win32Error::ERROR_CLIENT_SERVER_PARAMETERS_INVALID), // STATUS_CLIENT_SERVER_PARAMETERS_INVALID
+    (0xC0000224, Win32Error::ERROR_PASSWORD_MUST_CHANGE), // STATUS_PASSWORD_MUST_CHANGE
+    (0xC0000225, Win32Error::ERROR_NOT_FOUND), // STATUS_NOT_FOUND
+    (0xC0000226, Win32Error::ERROR_NOT_TINY_STREAM), // STATUS_NOT_TINY_STREAM
+    (0xC0000227, Win32Error::ERROR_RECOVERY_FAILURE), // STATUS_RECOVERY_FAILURE
+    (0xC0000228, Win32Error::ERROR_STACK_OVERFLOW_READ), // STATUS_STACK_OVERFLOW_READ
+    (0xC0000229, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FAIL_CHECK
+    (0xC000022A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DUPLICATE_OBJECTID
+    (0xC000022B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_OBJECTID_EXISTS
+    (0xC000022C, Win32Error::ERROR_CONVERT_TO_LARGE), // STATUS_CONVERT_TO_LARGE
+    (0xC000022D, Win32Error::ERROR_RETRY), // STATUS_RETRY
+    (0xC000022E, Win32Error::ERROR_FOUND_OUT_OF_SCOPE), // STATUS_FOUND_OUT_OF_SCOPE
+    (0xC000022F, Win32Error::ERROR_ALLOCATE_BUCKET), // STATUS_ALLOCATE_BUCKET
+    (0xC0000230, Win32Error::ERROR_SET_NOT_FOUND), // STATUS_PROPSET_NOT_FOUND
+    (0xC0000231, Win32Error::ERROR_MARSHALL_OVERFLOW), // STATUS_MARSHALL_OVERFLOW
+    (0xC0000232, Win32Error::ERROR_INVALID_VARIANT), // STATUS_INVALID_VARIANT
+    (0xC0000233, Win32Error::ERROR_DOMAIN_CONTROLLER_NOT_FOUND), // STATUS_DOMAIN_CONTROLLER_NOT_FOUND
+    (0xC0000234, Win32Error::ERROR_ACCOUNT_LOCKED_OUT), // STATUS_ACCOUNT_LOCKED_OUT
+    (0xC0000235, Win32Error::ERROR_INVALID_HANDLE), // STATUS_HANDLE_NOT_CLOSABLE
+    (0xC0000236, Win32Error::ERROR_CONNECTION_REFUSED), // STATUS_CONNECTION_REFUSED
+    (0xC0000237, Win32Error::ERROR_GRACEFUL_DISCONNECT), // STATUS_GRACEFUL_DISCONNECT
+    (0xC0000238, Win32Error::ERROR_ADDRESS_ALREADY_ASSOCIATED), // STATUS_ADDRESS_ALREADY_ASSOCIATED
+    (0xC0000239, Win32Error::ERROR_ADDRESS_NOT_ASSOCIATED), // STATUS_ADDRESS_NOT_ASSOCIATED
+    (0xC000023A, Win32Error::ERROR_CONNECTION_INVALID), // STATUS_CONNECTION_INVALID
+    (0xC000023B, Win32Error::ERROR_CONNECTION_ACTIVE), // STATUS_CONNECTION_ACTIVE
+    (0xC000023C, Win32Error::ERROR_NETWORK_UNREACHABLE), // STATUS_NETWORK_UNREACHABLE
+    (0xC000023D, Win32Error::ERROR_HOST_UNREACHABLE), // STATUS_HOST_UNREACHABLE
+    (0xC000023E, Win32Error::ERROR_PROTOCOL_UNREACHABLE), // STATUS_PROTOCOL_UNREACHABLE
+    (0xC000023F, Win32Error::ERROR_PORT_UNREACHABLE), // STATUS_PORT_UNREACHABLE
+    (0xC0000240, Win32Error::ERROR_REQUEST_ABORTED), // STATUS_REQUEST_ABORTED
+    (0xC0000241, Win32Error::ERROR_CONNECTION_ABORTED), // STATUS_CONNECTION_ABORTED
+    (0xC0000242, Win32Error::ERROR_BAD_COMPRESSION_BUFFER), // STATUS_BAD_COMPRESSION_BUFFER
+    (0xC0000243, Win32Error::ERROR_USER_MAPPED_FILE), // STATUS_USER_MAPPED_FILE
+    (0xC0000244, Win32Error::ERROR_AUDIT_FAILED), // STATUS_AUDIT_FAILED
+    (0xC0000245, Win32Error::ERROR_TIMER_RESOLUTION_NOT_SET), // STATUS_TIMER_RESOLUTION_NOT_SET
+    (0xC0000246, Win32Error::ERROR_CONNECTION_COUNT_LIMIT), // STATUS_CONNECTION_COUNT_LIMIT
+    (0xC0000247, Win32Error::ERROR_LOGIN_TIME_RESTRICTION), // STATUS_LOGIN_TIME_RESTRICTION
+    (0xC0000248, Win32Error::ERROR_LOGIN_WKSTA_RESTRICTION), // STATUS_LOGIN_WKSTA_RESTRICTION
+    (0xC0000249, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_IMAGE_MP_UP_MISMATCH
+    (0xC0000250, Win32Error::ERROR_INSUFFICIENT_LOGON_INFO), // STATUS_INSUFFICIENT_LOGON_INFO
+    (0xC0000251, Win32Error::ERROR_BAD_DLL_ENTRYPOINT), // STATUS_BAD_DLL_ENTRYPOINT
+    (0xC0000252, Win32Error::ERROR_BAD_SERVICE_ENTRYPOINT), // STATUS_BAD_SERVICE_ENTRYPOINT
+    (0xC0000253, Win32Error::ERROR_CONNECTION_ABORTED), // STATUS_LPC_REPLY_LOST
+    (0xC0000254, Win32Error::ERROR_IP_ADDRESS_CONFLICT1), // STATUS_IP_ADDRESS_CONFLICT1
+    (0xC0000255, Win32Error::ERROR_IP_ADDRESS_CONFLICT2), // STATUS_IP_ADDRESS_CONFLICT2
+    (0xC0000256, Win32Error::ERROR_REGISTRY_QUOTA_LIMIT), // STATUS_REGISTRY_QUOTA_LIMIT
+    (0xC0000257, Win32Error::ERROR_HOST_UNREACHABLE), // STATUS_PATH_NOT_COVERED
+    (0xC0000258, Win32Error::ERROR_NO_CALLBACK_ACTIVE), // STATUS_NO_CALLBACK_ACTIVE
+    (0xC0000259, Win32Error::ERROR_LICENSE_QUOTA_EXCEEDED), // STATUS_LICENSE_QUOTA_EXCEEDED
+    (0xC000025A, Win32Error::ERROR_PWD_TOO_SHORT), // STATUS_PWD_TOO_SHORT
+    (0xC000025B, Win32Error::ERROR_PWD_TOO_RECENT), // STATUS_PWD_TOO_RECENT
+    (0xC000025C, Win32Error::ERROR_PWD_HISTORY_CONFLICT), // STATUS_PWD_HISTORY_CONFLICT
+    (0xC000025E, Win32Error::ERROR_SERVICE_DISABLED), // STATUS_PLUGPLAY_NO_DEVICE
+    (0xC000025F, Win32Error::ERROR_UNSUPPORTED_COMPRESSION), // STATUS_UNSUPPORTED_COMPRESSION
+    (0xC0000260, Win32Error::ERROR_INVALID_HW_PROFILE), // STATUS_INVALID_HW_PROFILE
+    (0xC0000261, Win32Error::ERROR_INVALID_PLUGPLAY_DEVICE_PATH), // STATUS_INVALID_PLUGPLAY_DEVICE_PATH
+    (0xC0000262, Win32Error::ERROR_INVALID_ORDINAL), // STATUS_DRIVER_ORDINAL_NOT_FOUND
+    (0xC0000263, Win32Error::ERROR_PROC_NOT_FOUND), // STATUS_DRIVER_ENTRYPOINT_NOT_FOUND
+    (0xC0000264, Win32Error::ERROR_NOT_OWNER), // STATUS_RESOURCE_NOT_OWNED
+    (0xC0000265, Win32Error::ERROR_TOO_MANY_LINKS), // STATUS_TOO_MANY_LINKS
+    (0xC0000266, Win32Error::ERROR_QUOTA_LIST_INCONSISTENT), // STATUS_QUOTA_LIST_INCONSISTENT
+    (0xC0000267, Win32Error::ERROR_FILE_OFFLINE), // STATUS_FILE_IS_OFFLINE
+    (0xC0000268, Win32Error::ERROR_EVALUATION_EXPIRATION), // STATUS_EVALUATION_EXPIRATION
+    (0xC0000269, Win32Error::ERROR_ILLEGAL_DLL_RELOCATION), // STATUS_ILLEGAL_DLL_RELOCATION
+    (0xC000026A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_LICENSE_VIOLATION
+    (0xC000026B, Win32Error::ERROR_DLL_INIT_FAILED_LOGOFF), // STATUS_DLL_INIT_FAILED_LOGOFF
+    (0xC000026C, Win32Error::ERROR_BAD_DRIVER), // STATUS_DRIVER_UNABLE_TO_LOAD
+    (0xC000026D, Win32Error::ERROR_CONNECTION_UNAVAIL), // STATUS_DFS_UNAVAILABLE
+    (0xC000026E, Win32Error::ERROR_NOT_READY), // STATUS_VOLUME_DISMOUNTED
+    (0xC000026F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_WX86_INTERNAL_ERROR
+    (0xC0000270, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_WX86_FLOAT_STACK_CHECK
+    (0xC0000271, Win32Error::ERROR_VALIDATE_CONTINUE), // STATUS_VALIDATE_CONTINUE
+    (0xC0000272, Win32Error::ERROR_NO_MATCH), // STATUS_NO_MATCH
+    (0xC0000273, Win32Error::ERROR_NO_MORE_MATCHES), // STATUS_NO_MORE_MATCHES
+    (0xC0000275, Win32Error::ERROR_NOT_A_REPARSE_POINT), // STATUS_NOT_A_REPARSE_POINT
+    (0xC0000276, Win32Error::ERROR_REPARSE_TAG_INVALID), // STATUS_IO_REPARSE_TAG_INVALID
+    (0xC0000277, Win32Error::ERROR_REPARSE_TAG_MISMATCH), // STATUS_IO_REPARSE_TAG_MISMATCH
+    (0xC0000278, Win32Error::ERROR_INVALID_REPARSE_DATA), // STATUS_IO_REPARSE_DATA_INVALID
+    (0xC0000279, Win32Error::ERROR_CANT_ACCESS_FILE), // STATUS_IO_REPARSE_TAG_NOT_HANDLED
+    (0xC000027A, Win32Error::ERROR_PWD_TOO_LONG), // STATUS_PWD_TOO_LONG
+    (0xC000027B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_STOWED_EXCEPTION
+    (0xC0000280, Win32Error::ERROR_CANT_RESOLVE_FILENAME), // STATUS_REPARSE_POINT_NOT_RESOLVED
+    (0xC0000281, Win32Error::ERROR_BAD_PATHNAME), // STATUS_DIRECTORY_IS_A_REPARSE_POINT
+    (0xC0000282, Win32Error::ERROR_RANGE_LIST_CONFLICT), // STATUS_RANGE_LIST_CONFLICT
+    (0xC0000283, Win32Error::ERROR_SOURCE_ELEMENT_EMPTY), // STATUS_SOURCE_ELEMENT_EMPTY
+    (0xC0000284, Win32Error::ERROR_DESTINATION_ELEMENT_FULL), // STATUS_DESTINATION_ELEMENT_FULL
+    (0xC0000285, Win32Error::ERROR_ILLEGAL_ELEMENT_ADDRESS), // STATUS_ILLEGAL_ELEMENT_ADDRESS
+    (0xC0000286, Win32Error::ERROR_MAGAZINE_NOT_PRESENT), // STATUS_MAGAZINE_NOT_PRESENT
+    (0xC0000287, Win32Error::ERROR_DEVICE_REINITIALIZATION_NEEDED), // STATUS_REINITIALIZATION_NEEDED
+    (0xC000028A, Win32Error::ERROR_ACCESS_DENIED), // STATUS_ENCRYPTION_FAILED
+    (0xC000028B, Win32Error::ERROR_ACCESS_DENIED), // STATUS_DECRYPTION_FAILED
+    (0xC000028C, Win32Error::ERROR_RANGE_NOT_FOUND), // STATUS_RANGE_NOT_FOUND
+    (0xC000028D, Win32Error::ERROR_ACCESS_DENIED), // STATUS_NO_RECOVERY_POLICY
+    (0xC000028E, Win32Error::ERROR_ACCESS_DENIED), // STATUS_NO_EFS
+    (0xC000028F, Win32Error::ERROR_ACCESS_DENIED), // STATUS_WRONG_EFS
+    (0xC0000290, Win32Error::ERROR_ACCESS_DENIED), // STATUS_NO_USER_KEYS
+    (0xC0000291, Win32Error::ERROR_FILE_NOT_ENCRYPTED), // STATUS_FILE_NOT_ENCRYPTED
+    (0xC0000292, Win32Error::ERROR_NOT_EXPORT_FORMAT), // STATUS_NOT_EXPORT_FORMAT
+    (0xC0000293, Win32Error::ERROR_FILE_ENCRYPTED), // STATUS_FILE_ENCRYPTED
+    (0xC0000295, Win32Error::ERROR_WMI_GUID_NOT_FOUND), // STATUS_WMI_GUID_NOT_FOUND
+    (0xC0000296, Win32Error::ERROR_WMI_INSTANCE_NOT_FOUND), // STATUS_WMI_INSTANCE_NOT_FOUND
+    (0xC0000297, Win32Error::ERROR_WMI_ITEMID_NOT_FOUND), // STATUS_WMI_ITEMID_NOT_FOUND
+    (0xC0000298, Win32Error::ERROR_WMI_TRY_AGAIN), // STATUS_WMI_TRY_AGAIN
+    (0xC0000299, Win32Error::ERROR_SHARED_POLICY), // STATUS_SHARED_POLICY
+    (0xC000029A, Win32Error::ERROR_POLICY_OBJECT_NOT_FOUND), // STATUS_POLICY_OBJECT_NOT_FOUND
+    (0xC000029B, Win32Error::ERROR_POLICY_ONLY_IN_DS), // STATUS_POLICY_ONLY_IN_DS
+    (0xC000029C, Win32Error::ERROR_INVALID_FUNCTION), // STATUS_VOLUME_NOT_UPGRADED
+    (0xC000029D, Win32Error::ERROR_REMOTE_STORAGE_NOT_ACTIVE), // STATUS_REMOTE_STORAGE_NOT_ACTIVE
+    (0xC000029E, Win32Error::ERROR_REMOTE_STORAGE_MEDIA_ERROR), // STATUS_REMOTE_STORAGE_MEDIA_ERROR
+    (0xC000029F, Win32Error::ERROR_NO_TRACKING_SERVICE), // STATUS_NO_TRACKING_SERVICE
+    (0xC00002A0, Win32Error::ERROR_SERVER_SID_MISMATCH), // STATUS_SERVER_SID_MISMATCH
+    (0xC00002A1, Win32Error::ERROR_DS_NO_ATTRIBUTE_OR_VALUE), // STATUS_DS_NO_ATTRIBUTE_OR_VALUE
+    (0xC00002A2, Win32Error::ERROR_DS_INVALID_ATTRIBUTE_SYNTAX), // STATUS_DS_INVALID_ATTRIBUTE_SYNTAX
+    (0xC00002A3, Win32Error::ERROR_DS_ATTRIBUTE_TYPE_UNDEFINED), // STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED
+    (0xC00002A4, Win32Error::ERROR_DS_ATTRIBUTE_OR_VALUE_EXISTS), // STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS
+    (0xC00002A5, Win32Error::ERROR_DS_BUSY), // STATUS_DS_BUSY
+    (0xC00002A6, Win32Error::ERROR_DS_UNAVAILABLE), // STATUS_DS_UNAVAILABLE
+    (0xC00002A7, Win32Error::ERROR_DS_NO_RIDS_ALLOCATED), // STATUS_DS_NO_RIDS_ALLOCATED
+    (0xC00002A8, Win32Error::ERROR_DS_NO_MORE_RIDS), // STATUS_DS_NO_MORE_RIDS
+    (0xC00002A9, Win32Error::ERROR_DS_INCORRECT_ROLE_OWNER), // STATUS_DS_INCORRECT_ROLE_OWNER
+    (0xC00002AA, Win32Error::ERROR_DS_RIDMGR_INIT_ERROR), // STATUS_DS_RIDMGR_INIT_ERROR
+    (0xC00002AB, Win32Error::ERROR_DS_OBJ_CLASS_VIOLATION), // STATUS_DS_OBJ_CLASS_VIOLATION
+    (0xC00002AC, Win32Error::ERROR_DS_CANT_ON_NON_LEAF), // STATUS_DS_CANT_ON_NON_LEAF
+    (0xC00002AD, Win32Error::ERROR_DS_CANT_ON_RDN), // STATUS_DS_CANT_ON_RDN
+    (0xC00002AE, Win32Error::ERROR_DS_CANT_MOD_OBJ_CLASS), // STATUS_DS_CANT_MOD_OBJ_CLASS
+    (0xC00002AF, Win32Error::ERROR_DS_CROSS_DOM_MOVE_ERROR), // STATUS_DS_CROSS_DOM_MOVE_FAILED
+    (0xC00002B0, Win32Error::ERROR_DS_GC_NOT_AVAILABLE), // STATUS_DS_GC_NOT_AVAILABLE
+    (0xC00002B1, Win32Error::ERROR_DS_DS_REQUIRED), // STATUS_DIRECTORY_SERVICE_REQUIRED
+    (0xC00002B2, Win32Error::ERROR_REPARSE_ATTRIBUTE_CONFLICT), // STATUS_REPARSE_ATTRIBUTE_CONFLICT
+    (0xC00002B3, Win32Error::ERROR_CANT_ENABLE_DENY_ONLY), // STATUS_CANT_ENABLE_DENY_ONLY
+    (0xC00002B4, Win32Error::ERROR_FLOAT_MULTIPLE_FAULTS), // STATUS_FLOAT_MULTIPLE_FAULTS
+    (0xC00002B5, Win32Error::ERROR_FLOAT_MULTIPLE_TRAPS), // STATUS_FLOAT_MULTIPLE_TRAPS
+    (0xC00002B6, Win32Error::ERROR_DEVICE_REMOVED), // STATUS_DEVICE_REMOVED
+    (0xC00002B7, Win32Error::ERROR_JOURNAL_DELETE_IN_PROGRESS), // STATUS_JOURNAL_DELETE_IN_PROGRESS
+    (0xC00002B8, Win32Error::ERROR_JOURNAL_NOT_ACTIVE), // STATUS_JOURNAL_NOT_ACTIVE
+    (0xC00002B9, Win32Error::ERROR_NOINTERFACE), // STATUS_NOINTERFACE
+    (0xC00002BA, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_RIDMGR_DISABLED
+    (0xC00002C1, Win32Error::ERROR_DS_ADMIN_LIMIT_EXCEEDED), // STATUS_DS_ADMIN_LIMIT_EXCEEDED
+    (0xC00002C2, Win32Error::ERROR_DRIVER_FAILED_SLEEP), // STATUS_DRIVER_FAILED_SLEEP
+    (0xC00002C3, Win32Error::ERROR_MUTUAL_AUTH_FAILED), // STATUS_MUTUAL_AUTHENTICATION_FAILED
+    (0xC00002C4, Win32Error::ERROR_CORRUPT_SYSTEM_FILE), // STATUS_CORRUPT_SYSTEM_FILE
+    (0xC00002C5, Win32Error::ERROR_NOACCESS), // STATUS_DATATYPE_MISALIGNMENT_ERROR
+    (0xC00002C6, Win32Error::ERROR_WMI_READ_ONLY), // STATUS_WMI_READ_ONLY
+    (0xC00002C7, Win32Error::ERROR_WMI_SET_FAILURE), // STATUS_WMI_SET_FAILURE
+    (0xC00002C8, Win32Error::ERROR_COMMITMENT_MINIMUM), // STATUS_COMMITMENT_MINIMUM
+    (0xC00002C9, Win32Error::ERROR_REG_NAT_CONSUMPTION), // STATUS_REG_NAT_CONSUMPTION
+    (0xC00002CA, Win32Error::ERROR_TRANSPORT_FULL), // STATUS_TRANSPORT_FULL
+    (0xC00002CB, Win32Error::ERROR_DS_SAM_INIT_FAILURE), // STATUS_DS_SAM_INIT_FAILURE
+    (0xC00002CC, Win32Error::ERROR_ONLY_IF_CONNECTED), // STATUS_ONLY_IF_CONNECTED
+    (0xC00002CD, Win32Error::ERROR_DS_SENSITIVE_GROUP_VIOLATION), // STATUS_DS_SENSITIVE_GROUP_VIOLATION
+    (0xC00002CE, Win32Error::ERROR_PNP_RESTART_ENUMERATION), // STATUS_PNP_RESTART_ENUMERATION
+    (0xC00002CF, Win32Error::ERROR_JOURNAL_ENTRY_DELETED), // STATUS_JOURNAL_ENTRY_DELETED
+    (0xC00002D0, Win32Error::ERROR_DS_CANT_MOD_PRIMARYGROUPID), // STATUS_DS_CANT_MOD_PRIMARYGROUPID
+    (0xC00002D1, Win32Error::ERROR_SYSTEM_IMAGE_BAD_SIGNATURE), // STATUS_SYSTEM_IMAGE_BAD_SIGNATURE
+    (0xC00002D2, Win32Error::ERROR_PNP_REBOOT_REQUIRED), // STATUS_PNP_REBOOT_REQUIRED
+    (0xC00002D3, Win32Error::ERROR_NOT_READY), // STATUS_POWER_STATE_INVALID
+    (0xC00002D4, Win32Error::ERROR_DS_INVALID_GROUP_TYPE), // STATUS_DS_INVALID_GROUP_TYPE
+    (0xC00002D5, Win32Error::ERROR_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN), // STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN
+    (0xC00002D6, Win32Error::ERROR_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN), // STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN
+    (0xC00002D7, Win32Error::ERROR_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER), // STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER
+    (0xC00002D8, Win32Error::ERROR_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER), // STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER
+    (0xC00002D9, Win32Error::ERROR_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER), // STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER
+    (0xC00002DA, Win32Error::ERROR_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER), // STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER
+    (0xC00002DB, Win32Error::ERROR_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER), // STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER
+    (0xC00002DC, Win32Error::ERROR_DS_HAVE_PRIMARY_MEMBERS), // STATUS_DS_HAVE_PRIMARY_MEMBERS
+    (0xC00002DD, Win32Error::ERROR_NOT_SUPPORTED), // STATUS_WMI_NOT_SUPPORTED
+    (0xC00002DE, Win32Error::ERROR_INSUFFICIENT_POWER), // STATUS_INSUFFICIENT_POWER
+    (0xC00002DF, Win32Error::ERROR_DS_SAM_NEED_BOOTKEY_PASSWORD), // STATUS_SAM_NEED_BOOTKEY_PASSWORD
+    (0xC00002E0, Win32Error::ERROR_DS_SAM_NEED_BOOTKEY_FLOPPY), // STATUS_SAM_NEED_BOOTKEY_FLOPPY
+    (0xC00002E1, Win32Error::ERROR_DS_CANT_START), // STATUS_DS_CANT_START
+    (0xC00002E2, Win32Error::ERROR_DS_INIT_FAILURE), // STATUS_DS_INIT_FAILURE
+    (0xC00002E3, Win32Error::ERROR_SAM_INIT_FAILURE), // STATUS_SAM_INIT_FAILURE
+    (0xC00002E4, Win32Error::ERROR_DS_GC_REQUIRED), // STATUS_DS_GC_REQUIRED
+    (0xC00002E5, Win32Error::ERROR_DS_LOCAL_MEMBER_OF_LOCAL_ONLY), // STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY
+    (0xC00002E6, Win32Error::ERROR_DS_NO_FPO_IN_UNIVERSAL_GROUPS), // STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS
+    (0xC00002E7, Win32Error::ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED), // STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED
+    (0xC00002E8, Win32Error::ERROR_MULTIPLE_FAULT_VIOLATION), // STATUS_MULTIPLE_FAULT_VIOLATION
+    (0xC00002E9, Win32Error::ERROR_CURRENT_DOMAIN_NOT_ALLOWED), // STATUS_CURRENT_DOMAIN_NOT_ALLOWED
+    (0xC00002EA, Win32Error::ERROR_CANNOT_MAKE), // STATUS_CANNOT_MAKE
+    (0xC00002EB, Win32Error::ERROR_SYSTEM_SHUTDOWN), // STATUS_SYSTEM_SHUTDOWN
+    (0xC00002EC, Win32Error::ERROR_DS_INIT_FAILURE_CONSOLE), // STATUS_DS_INIT_FAILURE_CONSOLE
+    (0xC00002ED, Win32Error::ERROR_DS_SAM_INIT_FAILURE_CONSOLE), // STATUS_DS_SAM_INIT_FAILURE_CONSOLE
+    (0xC00002EE, Win32Error::SEC_E_UNFINISHED_CONTEXT_DELETED), // STATUS_UNFINISHED_CONTEXT_DELETED
+    (0xC00002EF, Win32Error::SEC_E_NO_TGT_REPLY), // STATUS_NO_TGT_REPLY
+    (0xC00002F0, Win32Error::ERROR_FILE_NOT_FOUND), // STATUS_OBJECTID_NOT_FOUND
+    (0xC00002F1, Win32Error::SEC_E_NO_IP_ADDRESSES), // STATUS_NO_IP_ADDRESSES
+    (0xC00002F2, Win32Error::SEC_E_WRONG_CREDENTIAL_HANDLE), // STATUS_WRONG_CREDENTIAL_HANDLE
+    (0xC00002F3, Win32Error::SEC_E_CRYPTO_SYSTEM_INVALID), // STATUS_CRYPTO_SYSTEM_INVALID
+    (0xC00002F4, Win32Error::SEC_E_MAX_REFERRALS_EXCEEDED), // STATUS_MAX_REFERRALS_EXCEEDED
+    (0xC00002F5, Win32Error::SEC_E_MUST_BE_KDC), // STATUS_MUST_BE_KDC
+    (0xC00002F6, Win32Error::SEC_E_STRONG_CRYPTO_NOT_SUPPORTED), // STATUS_STRONG_CRYPTO_NOT_SUPPORTED
+    (0xC00002F7, Win32Error::SEC_E_TOO_MANY_PRINCIPALS), // STATUS_TOO_MANY_PRINCIPALS
+    (0xC00002F8, Win32Error::SEC_E_NO_PA_DATA), // STATUS_NO_PA_DATA
+    (0xC00002F9, Win32Error::SEC_E_PKINIT_NAME_MISMATCH), // STATUS_PKINIT_NAME_MISMATCH
+    (0xC00002FA, Win32Error::SEC_E_SMARTCARD_LOGON_REQUIRED), // STATUS_SMARTCARD_LOGON_REQUIRED
+    (0xC00002FB, Win32Error::SEC_E_KDC_INVALID_REQUEST), // STATUS_KDC_INVALID_REQUEST
+    (0xC00002FC, Win32Error::SEC_E_KDC_UNABLE_TO_REFER), // STATUS_KDC_UNABLE_TO_REFER
+    (0xC00002FD, Win32Error::SEC_E_KDC_UNKNOWN_ETYPE), // STATUS_KDC_UNKNOWN_ETYPE
+    (0xC00002FE, Win32Error::ERROR_SHUTDOWN_IN_PROGRESS), // STATUS_SHUTDOWN_IN_PROGRESS
+    (0xC00002FF, Win32Error::ERROR_SERVER_SHUTDOWN_IN_PROGRESS), // STATUS_SERVER_SHUTDOWN_IN_PROGRESS
+    (0xC0000300, Win32Error::ERROR_NOT_SUPPORTED_ON_SBS), // STATUS_NOT_SUPPORTED_ON_SBS
+    (0xC0000301, Win32Error::ERROR_WMI_GUID_DISCONNECTED), // STATUS_WMI_GUID_DISCONNECTED
+    (0xC0000302, Win32Error::ERROR_WMI_ALREADY_DISABLED), // STATUS_WMI_ALREADY_DISABLED
+    (0xC0000303, Win32Error::ERROR_WMI_ALREADY_ENABLED), // STATUS_WMI_ALREADY_ENABLED
+    (0xC0000304, Win32Error::ERROR_DISK_TOO_FRAGMENTED), // STATUS_MFT_TOO_FRAGMENTED
+    (0xC0000305, Win32Error::STG_E_STATUS_COPY_PROTECTION_FAILURE), // STATUS_COPY_PROTECTION_FAILURE
+    (0xC0000306, Win32Error::STG_E_CSS_AUTHENTICATION_FAILURE), // STATUS_CSS_AUTHENTICATION_FAILURE
+    (0xC0000307, Win32Error::STG_E_CSS_KEY_NOT_PRESENT), // STATUS_CSS_KEY_NOT_PRESENT
+    (0xC0000308, Win32Error::STG_E_CSS_KEY_NOT_ESTABLISHED), // STATUS_CSS_KEY_NOT_ESTABLISHED
+    (0xC0000309, Win32Error::STG_E_CSS_SCRAMBLED_SECTOR), // STATUS_CSS_SCRAMBLED_SECTOR
+    (0xC000030A, Win32Error::STG_E_CSS_REGION_MISMATCH), // STATUS_CSS_REGION_MISMATCH
+    (0xC000030B, Win32Error::STG_E_RESETS_EXHAUSTED), // STATUS_CSS_RESETS_EXHAUSTED
+    (0xC000030C, Win32Error::ERROR_PASSWORD_CHANGE_REQUIRED), // STATUS_PASSWORD_CHANGE_REQUIRED
+    (0xC0000320, Win32Error::ERROR_PKINIT_FAILURE), // STATUS_PKINIT_FAILURE
+    (0xC0000321, Win32Error::ERROR_SMARTCARD_SUBSYSTEM_FAILURE), // STATUS_SMARTCARD_SUBSYSTEM_FAILURE
+    (0xC0000322, Win32Error::SEC_E_NO_KERB_KEY), // STATUS_NO_KERB_KEY
+    (0xC0000350, Win32Error::ERROR_HOST_DOWN), // STATUS_HOST_DOWN
+    (0xC0000351, Win32Error::SEC_E_UNSUPPORTED_PREAUTH), // STATUS_UNSUPPORTED_PREAUTH
+    (0xC0000352, Win32Error::ERROR_EFS_ALG_BLOB_TOO_BIG), // STATUS_EFS_ALG_BLOB_TOO_BIG
+    (0xC0000353, Win32Error::ERROR_PORT_NOT_SET), // STATUS_PORT_NOT_SET
+    (0xC0000354, Win32Error::ERROR_DEBUGGER_INACTIVE), // STATUS_DEBUGGER_INACTIVE
+    (0xC0000355, Win32Error::ERROR_DS_VERSION_CHECK_FAILURE), // STATUS_DS_VERSION_CHECK_FAILURE
+    (0xC0000356, Win32Error::ERROR_AUDITING_DISABLED), // STATUS_AUDITING_DISABLED
+    (0xC0000357, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PRENT4_MACHINE_ACCOUNT
+    (0xC0000358, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER
+    (0xC0000359, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_WIN_32
+    (0xC000035A, Win32Error::ERROR_BAD_EXE_FORMAT), // STATUS_INVALID_IMAGE_WIN_64
+    (0xC000035B, Win32Error::SEC_E_BAD_BINDINGS), // STATUS_BAD_BINDINGS
+    (0xC000035C, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NETWORK_SESSION_EXPIRED
+    (0xC000035D, Win32Error::ERROR_APPHELP_BLOCK), // STATUS_APPHELP_BLOCK
+    (0xC000035E, Win32Error::ERROR_ALL_SIDS_FILTERED), // STATUS_ALL_SIDS_FILTERED
+    (0xC000035F, Win32Error::ERROR_NOT_SAFE_MODE_DRIVER), // STATUS_NOT_SAFE_MODE_DRIVER
+    (0xC0000361, Win32Error::ERROR_ACCESS_DISABLED_BY_POLICY), // STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT
+    (0xC0000362, Win32Error::ERROR_ACCESS_DISABLED_BY_POLICY), // STATUS_ACCESS_DISABLED_BY_POLICY_PATH
+    (0xC0000363, Win32Error::ERROR_ACCESS_DISABLED_BY_POLICY), // STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER
+    (0xC0000364, Win32Error::ERROR_ACCESS_DISABLED_BY_POLICY), // STATUS_ACCESS_DISABLED_BY_POLICY_OTHER
+    (0xC0000365, Win32Error::ERROR_FAILED_DRIVER_ENTRY), // STATUS_FAILED_DRIVER_ENTRY
+    (0xC0000366, Win32Error::ERROR_DEVICE_ENUMERATION_ERROR), // STATUS_DEVICE_ENUMERATION_ERROR
+    (0xC0000368, Win32Error::ERROR_MOUNT_POINT_NOT_RESOLVED), // STATUS_MOUNT_POINT_NOT_RESOLVED
+    (0xC0000369, Win32Error::ERROR_INVALID_DEVICE_OBJECT_PARAMETER), // STATUS_INVALID_DEVICE_OBJECT_PARAMETER
+    (0xC000036A, Win32Error::ERROR_MCA_OCCURED), // STATUS_MCA_OCCURED
+    (0xC000036B, Win32Error::ERROR_DRIVER_BLOCKED), // STATUS_DRIVER_BLOCKED_CRITICAL
+    (0xC000036C, Win32Error::ERROR_DRIVER_BLOCKED), // STATUS_DRIVER_BLOCKED
+    (0xC000036D, Win32Error::ERROR_DRIVER_DATABASE_ERROR), // STATUS_DRIVER_DATABASE_ERROR
+    (0xC000036E, Win32Error::ERROR_SYSTEM_HIVE_TOO_LARGE), // STATUS_SYSTEM_HIVE_TOO_LARGE
+    (0xC000036F, Win32Error::ERROR_INVALID_IMPORT_OF_NON_DLL), // STATUS_INVALID_IMPORT_OF_NON_DLL
+    (0xC0000371, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NO_SECRETS
+    (0xC0000372, Win32Error::ERROR_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY), // STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY
+    (0xC0000373, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FAILED_STACK_SWITCH
+    (0xC0000374, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_HEAP_CORRUPTION
+    (0xC0000380, Win32Error::SCARD_W_WRONG_CHV), // STATUS_SMARTCARD_WRONG_PIN
+    (0xC0000381, Win32Error::SCARD_W_CHV_BLOCKED), // STATUS_SMARTCARD_CARD_BLOCKED
+    (0xC0000382, Win32Error::SCARD_W_CARD_NOT_AUTHENTICATED), // STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED
+    (0xC0000383, Win32Error::SCARD_E_NO_SMARTCARD), // STATUS_SMARTCARD_NO_CARD
+    (0xC0000384, Win32Error::NTE_NO_KEY), // STATUS_SMARTCARD_NO_KEY_CONTAINER
+    (0xC0000385, Win32Error::SCARD_E_NO_SUCH_CERTIFICATE), // STATUS_SMARTCARD_NO_CERTIFICATE
+    (0xC0000386, Win32Error::NTE_BAD_KEYSET), // STATUS_SMARTCARD_NO_KEYSET
+    (0xC0000387, Win32Error::SCARD_E_COMM_DATA_LOST), // STATUS_SMARTCARD_IO_ERROR
+    (0xC0000388, Win32Error::ERROR_DOWNGRADE_DETECTED), // STATUS_DOWNGRADE_DETECTED
+    (0xC0000389, Win32Error::SEC_E_SMARTCARD_CERT_REVOKED), // STATUS_SMARTCARD_CERT_REVOKED
+    (0xC000038A, Win32Error::SEC_E_ISSUING_CA_UNTRUSTED), // STATUS_ISSUING_CA_UNTRUSTED
+    (0xC000038B, Win32Error::SEC_E_REVOCATION_OFFLINE_C), // STATUS_REVOCATION_OFFLINE_C
+    (0xC000038C, Win32Error::SEC_E_PKINIT_CLIENT_FAILURE), // STATUS_PKINIT_CLIENT_FAILURE
+    (0xC000038D, Win32Error::SEC_E_SMARTCARD_CERT_EXPIRED), // STATUS_SMARTCARD_CERT_EXPIRED
+    (0xC000038E, Win32Error::ERROR_DRIVER_FAILED_PRIOR_UNLOAD), // STATUS_DRIVER_FAILED_PRIOR_UNLOAD
+    (0xC000038F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SMARTCARD_SILENT_CONTEXT
+    (0xC0000401, Win32Error::ERROR_PER_USER_TRUST_QUOTA_EXCEEDED), // STATUS_PER_USER_TRUST_QUOTA_EXCEEDED
+    (0xC0000402, Win32Error::ERROR_ALL_USER_TRUST_QUOTA_EXCEEDED), // STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED
+    (0xC0000403, Win32Error::ERROR_USER_DELETE_TRUST_QUOTA_EXCEEDED), // STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED
+    (0xC0000404, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_NAME_NOT_UNIQUE
+    (0xC0000405, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_DUPLICATE_ID_FOUND
+    (0xC0000406, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_GROUP_CONVERSION_ERROR
+    (0xC0000407, Win32Error::ERROR_VOLSNAP_PREPARE_HIBERNATE), // STATUS_VOLSNAP_PREPARE_HIBERNATE
+    (0xC0000408, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_USER2USER_REQUIRED
+    (0xC0000409, Win32Error::ERROR_STACK_BUFFER_OVERRUN), // STATUS_STACK_BUFFER_OVERRUN
+    (0xC000040A, Win32Error::SEC_E_NO_S4U_PROT_SUPPORT), // STATUS_NO_S4U_PROT_SUPPORT
+    (0xC000040B, Win32Error::SEC_E_CROSSREALM_DELEGATION_FAILURE), // STATUS_CROSSREALM_DELEGATION_FAILURE
+    (0xC000040C, Win32Error::SEC_E_REVOCATION_OFFLINE_KDC), // STATUS_REVOCATION_OFFLINE_KDC
+    (0xC000040D, Win32Error::SEC_E_ISSUING_CA_UNTRUSTED_KDC), // STATUS_ISSUING_CA_UNTRUSTED_KDC
+    (0xC000040E, Win32Error::SEC_E_KDC_CERT_EXPIRED), // STATUS_KDC_CERT_EXPIRED
+    (0xC000040F, Win32Error::SEC_E_KDC_CERT_REVOKED), // STATUS_KDC_CERT_REVOKED
+    (0xC0000410, Win32Error::ERROR_PARAMETER_QUOTA_EXCEEDED), // STATUS_PARAMETER_QUOTA_EXCEEDED
+    (0xC0000411, Win32Error::ERROR_HIBERNATION_FAILURE), // STATUS_HIBERNATION_FAILURE
+    (0xC0000412, Win32Error::ERROR_DELAY_LOAD_FAILED), // STATUS_DELAY_LOAD_FAILED
+    (0xC0000413, Win32Error::ERROR_AUTHENTICATION_FIREWALL_FAILED), // STATUS_AUTHENTICATION_FIREWALL_FAILED
+    (0xC0000414, Win32Error::ERROR_VDM_DISALLOWED), // STATUS_VDM_DISALLOWED
+    (0xC0000415, Win32Error::ERROR_HUNG_DISPLAY_DRIVER_THREAD), // STATUS_HUNG_DISPLAY_DRIVER_THREAD
+    (0xC0000416, Win32Error::ERROR_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE), // STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE
+    (0xC0000417, Win32Error::ERROR_INVALID_CRUNTIME_PARAMETER), // STATUS_INVALID_CRUNTIME_PARAMETER
+    (0xC0000418, Win32Error::ERROR_NTLM_BLOCKED), // STATUS_NTLM_BLOCKED
+    (0xC0000419, Win32Error::ERROR_DS_SRC_SID_EXISTS_IN_FOREST), // STATUS_DS_SRC_SID_EXISTS_IN_FOREST
+    (0xC000041A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST
+    (0xC000041B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST
+    (0xC000041C, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_USER_PRINCIPAL_NAME
+    (0xC000041D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FATAL_USER_CALLBACK_EXCEPTION
+    (0xC0000420, Win32Error::ERROR_ASSERTION_FAILURE), // STATUS_ASSERTION_FAILURE
+    (0xC0000421, Win32Error::ERROR_VERIFIER_STOP), // STATUS_VERIFIER_STOP
+    (0xC0000423, Win32Error::ERROR_CALLBACK_POP_STACK), // STATUS_CALLBACK_POP_STACK
+    (0xC0000424, Win32Error::ERROR_DRIVER_BLOCKED), // STATUS_INCOMPATIBLE_DRIVER_BLOCKED
+    (0xC0000425, Win32Error::ERROR_KEY_DELETED), // STATUS_HIVE_UNLOADED
+    (0xC0000426, Win32Error::ERROR_COMPRESSION_DISABLED), // STATUS_COMPRESSION_DISABLED
+    (0xC0000427, Win32Error::ERROR_FILE_SYSTEM_LIMITATION), // STATUS_FILE_SYSTEM_LIMITATION
+    (0xC0000428, Win32Error::ERROR_INVALID_IMAGE_HASH), // STATUS_INVALID_IMAGE_HASH
+    (0xC0000429, Win32Error::ERROR_NOT_CAPABLE), // STATUS_NOT_CAPABLE
+    (0xC000042A, Win32Error::ERROR_REQUEST_OUT_OF_SEQUENCE), // STATUS_REQUEST_OUT_OF_SEQUENCE
+    (0xC000042B, Win32Error::ERROR_IMPLEMENTATION_LIMIT), // STATUS_IMPLEMENTATION_LIMIT
+    (0xC000042C, Win32Error::ERROR_ELEVATION_REQUIRED), // STATUS_ELEVATION_REQUIRED
+    (0xC000042D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NO_SECURITY_CONTEXT
+    (0xC000042F, Win32Error::SEC_E_PKU2U_CERT_FAILURE), // STATUS_PKU2U_CERT_FAILURE
+    (0xC0000432, Win32Error::ERROR_BEYOND_VDL), // STATUS_BEYOND_VDL
+    (0xC0000433, Win32Error::ERROR_BUSY), // STATUS_ENCOUNTERED_WRITE_IN_PROGRESS
+    (0xC0000434, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PTE_CHANGED
+    (0xC0000435, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PURGE_FAILED
+    (0xC0000440, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CRED_REQUIRES_CONFIRMATION
+    (0xC0000441, Win32Error::ERROR_CS_ENCRYPTION_INVALID_SERVER_RESPONSE), // STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE
+    (0xC0000442, Win32Error::ERROR_CS_ENCRYPTION_UNSUPPORTED_SERVER), // STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER
+    (0xC0000443, Win32Error::ERROR_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE), // STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE
+    (0xC0000444, Win32Error::ERROR_CS_ENCRYPTION_NEW_ENCRYPTED_FILE), // STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE
+    (0xC0000445, Win32Error::ERROR_CS_ENCRYPTION_FILE_NOT_CSE), // STATUS_CS_ENCRYPTION_FILE_NOT_CSE
+    (0xC0000446, Win32Error::ERROR_INVALID_LABEL), // STATUS_INVALID_LABEL
+    (0xC0000450, Win32Error::ERROR_DRIVER_PROCESS_TERMINATED), // STATUS_DRIVER_PROCESS_TERMINATED
+    (0xC0000451, Win32Error::ERROR_AMBIGUOUS_SYSTEM_DEVICE), // STATUS_AMBIGUOUS_SYSTEM_DEVICE
+    (0xC0000452, Win32Error::ERROR_SYSTEM_DEVICE_NOT_FOUND), // STATUS_SYSTEM_DEVICE_NOT_FOUND
+    (0xC0000453, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RESTART_BOOT_APPLICATION
+    (0xC0000454, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INSUFFICIENT_NVRAM_RESOURCES
+    (0xC0000455, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_SESSION
+    (0xC0000456, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREAD_ALREADY_IN_SESSION
+    (0xC0000457, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREAD_NOT_IN_SESSION
+    (0xC0000458, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_WEIGHT
+    (0xC0000459, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_REQUEST_PAUSED
+    (0xC0000460, Win32Error::ERROR_NO_RANGES_PROCESSED), // STATUS_NO_RANGES_PROCESSED
+    (0xC0000461, Win32Error::ERROR_DISK_RESOURCES_EXHAUSTED), // STATUS_DISK_RESOURCES_EXHAUSTED
+    (0xC0000462, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NEEDS_REMEDIATION
+    (0xC0000463, Win32Error::ERROR_DEVICE_FEATURE_NOT_SUPPORTED), // STATUS_DEVICE_FEATURE_NOT_SUPPORTED
+    (0xC0000464, Win32Error::ERROR_DEVICE_UNREACHABLE), // STATUS_DEVICE_UNREACHABLE
+    (0xC0000465, Win32Error::ERROR_INVALID_TOKEN), // STATUS_INVALID_TOKEN
+    (0xC0000466, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SERVER_UNAVAILABLE
+    (0xC0000467, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FILE_NOT_AVAILABLE
+    (0xC0000468, Win32Error::ERROR_DEVICE_NO_RESOURCES), // STATUS_DEVICE_INSUFFICIENT_RESOURCES
+    (0xC0000469, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PACKAGE_UPDATING
+    (0xC000046A, Win32Error::ERROR_NOT_READ_FROM_COPY), // STATUS_NOT_READ_FROM_COPY
+    (0xC000046B, Win32Error::ERROR_FT_WRITE_FAILURE), // STATUS_FT_WRITE_FAILURE
+    (0xC000046C, Win32Error::ERROR_FT_DI_SCAN_REQUIRED), // STATUS_FT_DI_SCAN_REQUIRED
+    (0xC000046D, Win32Error::ERROR_OBJECT_NOT_EXTERNALLY_BACKED), // STATUS_OBJECT_NOT_EXTERNALLY_BACKED
+    (0xC000046E, Win32Error::ERROR_EXTERNAL_BACKING_PROVIDER_UNKNOWN), // STATUS_EXTERNAL_BACKING_PROVIDER_UNKNOWN
+    (0xC000046F, Win32Error::ERROR_COMPRESSION_NOT_BENEFICIAL), // STATUS_COMPRESSION_NOT_BENEFICIAL
+    (0xC0000470, Win32Error::ERROR_DATA_CHECKSUM_ERROR), // STATUS_DATA_CHECKSUM_ERROR
+    (0xC0000471, Win32Error::ERROR_INTERMIXED_KERNEL_EA_OPERATION), // STATUS_INTERMIXED_KERNEL_EA_OPERATION
+    (0xC0000472, Win32Error::ERROR_FILE_LEVEL_TRIM_NOT_SUPPORTED), // STATUS_TRIM_READ_ZERO_NOT_SUPPORTED
+    (0xC0000473, Win32Error::ERROR_TOO_MANY_DESCRIPTORS), // STATUS_TOO_MANY_SEGMENT_DESCRIPTORS
+    (0xC0000474, Win32Error::ERROR_OFFSET_ALIGNMENT_VIOLATION), // STATUS_INVALID_OFFSET_ALIGNMENT
+    (0xC0000475, Win32Error::ERROR_INVALID_FIELD_IN_PARAMETER_LIST), // STATUS_INVALID_FIELD_IN_PARAMETER_LIST
+    (0xC0000476, Win32Error::ERROR_OPERATION_IN_PROGRESS), // STATUS_OPERATION_IN_PROGRESS
+    (0xC0000477, Win32Error::ERROR_BAD_DEVICE_PATH), // STATUS_INVALID_INITIATOR_TARGET_PATH
+    (0xC0000478, Win32Error::ERROR_SCRUB_DATA_DISABLED), // STATUS_SCRUB_DATA_DISABLED
+    (0xC0000479, Win32Error::ERROR_NOT_REDUNDANT_STORAGE), // STATUS_NOT_REDUNDANT_STORAGE
+    (0xC000047A, Win32Error::ERROR_RESIDENT_FILE_NOT_SUPPORTED), // STATUS_RESIDENT_FILE_NOT_SUPPORTED
+    (0xC000047B, Win32Error::ERROR_COMPRESSED_FILE_NOT_SUPPORTED), // STATUS_COMPRESSED_FILE_NOT_SUPPORTED
+    (0xC000047C, Win32Error::ERROR_DIRECTORY_NOT_SUPPORTED), // STATUS_DIRECTORY_NOT_SUPPORTED
+    (0xC000047D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_IO_OPERATION_TIMEOUT
+    (0xC000047E, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SYSTEM_NEEDS_REMEDIATION
+    (0xC000047F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APPX_INTEGRITY_FAILURE_CLR_NGEN
+    (0xC0000480, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SHARE_UNAVAILABLE
+    (0xC0000481, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APISET_NOT_HOSTED
+    (0xC0000482, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APISET_NOT_PRESENT
+    (0xC0000483, Win32Error::ERROR_DEVICE_HARDWARE_ERROR), // STATUS_DEVICE_HARDWARE_ERROR
+    (0xC0000484, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FIRMWARE_SLOT_INVALID
+    (0xC0000485, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FIRMWARE_IMAGE_INVALID
+    (0xC0000486, Win32Error::ERROR_STORAGE_TOPOLOGY_ID_MISMATCH), // STATUS_STORAGE_TOPOLOGY_ID_MISMATCH
+    (0xC0000487, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_WIM_NOT_BOOTABLE
+    (0xC0000488, Win32Error::ERROR_BLOCKED_BY_PARENTAL_CONTROLS), // STATUS_BLOCKED_BY_PARENTAL_CONTROLS
+    (0xC0000489, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NEEDS_REGISTRATION
+    (0xC000048A, Win32Error::ERROR_QUOTA_ACTIVITY), // STATUS_QUOTA_ACTIVITY
+    (0xC000048B, Win32Error::ERROR_CALLBACK_INVOKE_INLINE), // STATUS_CALLBACK_INVOKE_INLINE
+    (0xC000048C, Win32Error::ERROR_BLOCK_TOO_MANY_REFERENCES), // STATUS_BLOCK_TOO_MANY_REFERENCES
+    (0xC000048D, Win32Error::ERROR_MARKED_TO_DISALLOW_WRITES), // STATUS_MARKED_TO_DISALLOW_WRITES
+    (0xC000048E, Win32Error::ERROR_NETWORK_ACCESS_DENIED_EDP), // STATUS_NETWORK_ACCESS_DENIED_EDP
+    (0xC000048F, Win32Error::ERROR_ENCLAVE_FAILURE), // STATUS_ENCLAVE_FAILURE
+    (0xC0000490, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PNP_NO_COMPAT_DRIVERS
+    (0xC0000491, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PNP_DRIVER_PACKAGE_NOT_FOUND
+    (0xC0000492, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PNP_DRIVER_CONFIGURATION_NOT_FOUND
+    (0xC0000493, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PNP_DRIVER_CONFIGURATION_INCOMPLETE
+    (0xC0000494, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PNP_FUNCTION_DRIVER_REQUIRED
+    (0xC0000495, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PNP_DEVICE_CONFIGURATION_PENDING
+    (0xC0000496, Win32Error::ERROR_DEVICE_HINT_NAME_BUFFER_TOO_SMALL), // STATUS_DEVICE_HINT_NAME_BUFFER_TOO_SMALL
+    (0xC0000497, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PACKAGE_NOT_AVAILABLE
+    (0xC0000499, Win32Error::ERROR_DEVICE_IN_MAINTENANCE), // STATUS_DEVICE_IN_MAINTENANCE
+    (0xC000049A, Win32Error::ERROR_NOT_SUPPORTED_ON_DAX), // STATUS_NOT_SUPPORTED_ON_DAX
+    (0xC000049B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FREE_SPACE_TOO_FRAGMENTED
+    (0xC000049C, Win32Error::ERROR_DAX_MAPPING_EXISTS), // STATUS_DAX_MAPPING_EXISTS
+    (0xC000049D, Win32Error::ERROR_CHILD_PROCESS_BLOCKED), // STATUS_CHILD_PROCESS_BLOCKED
+    (0xC000049E, Win32Error::ERROR_STORAGE_LOST_DATA_PERSISTENCE), // STATUS_STORAGE_LOST_DATA_PERSISTENCE
+    (0xC0000500, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_TASK_NAME
+    (0xC0000501, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_TASK_INDEX
+    (0xC0000502, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREAD_ALREADY_IN_TASK
+    (0xC0000503, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_BYPASS
+    (0xC0000504, Win32Error::ERROR_UNDEFINED_SCOPE), // STATUS_UNDEFINED_SCOPE
+    (0xC0000505, Win32Error::ERROR_INVALID_CAP), // STATUS_INVALID_CAP
+    (0xC0000506, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NOT_GUI_PROCESS
+    (0xC0000507, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DEVICE_HUNG
+    (0xC0000508, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CONTAINER_ASSIGNED
+    (0xC0000509, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_JOB_NO_CONTAINER
+    (0xC000050A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DEVICE_UNRESPONSIVE
+    (0xC000050B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_REPARSE_POINT_ENCOUNTERED
+    (0xC0000602, Win32Error::ERROR_FAIL_FAST_EXCEPTION), // STATUS_FAIL_FAST_EXCEPTION
+    (0xC0000603, Win32Error::ERROR_IMAGE_CERT_REVOKED), // STATUS_IMAGE_CERT_REVOKED
+    (0xC0000604, Win32Error::ERROR_DYNAMIC_CODE_BLOCKED), // STATUS_DYNAMIC_CODE_BLOCKED
+    (0xC0000605, Win32Error::ERROR_IMAGE_CERT_EXPIRED), // STATUS_IMAGE_CERT_EXPIRED
+    (0xC0000700, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PORT_CLOSED
+    (0xC0000701, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_MESSAGE_LOST
+    (0xC0000702, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_MESSAGE
+    (0xC0000703, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_REQUEST_CANCELED
+    (0xC0000704, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RECURSIVE_DISPATCH
+    (0xC0000705, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_LPC_RECEIVE_BUFFER_EXPECTED
+    (0xC0000706, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_LPC_INVALID_CONNECTION_USAGE
+    (0xC0000707, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_LPC_REQUESTS_NOT_ALLOWED
+    (0xC0000708, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RESOURCE_IN_USE
+    (0xC0000709, Win32Error::ERROR_MEMORY_HARDWARE), // STATUS_HARDWARE_MEMORY_ERROR
+    (0xC000070A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREADPOOL_HANDLE_EXCEPTION
+    (0xC000070B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED
+    (0xC000070C, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED
+    (0xC000070D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED
+    (0xC000070E, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED
+    (0xC000070F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_THREADPOOL_RELEASED_DURING_OPERATION
+    (0xC0000710, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING
+    (0xC0000711, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APC_RETURNED_WHILE_IMPERSONATING
+    (0xC0000712, Win32Error::ERROR_PROCESS_IS_PROTECTED), // STATUS_PROCESS_IS_PROTECTED
+    (0xC0000713, Win32Error::ERROR_MCA_EXCEPTION), // STATUS_MCA_EXCEPTION
+    (0xC0000714, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE
+    (0xC0000715, Win32Error::ERROR_SYMLINK_CLASS_DISABLED), // STATUS_SYMLINK_CLASS_DISABLED
+    (0xC0000716, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_IDN_NORMALIZATION
+    (0xC0000717, Win32Error::ERROR_NO_UNICODE_TRANSLATION), // STATUS_NO_UNICODE_TRANSLATION
+    (0xC0000718, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_ALREADY_REGISTERED
+    (0xC0000719, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CONTEXT_MISMATCH
+    (0xC000071A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PORT_ALREADY_HAS_COMPLETION_LIST
+    (0xC000071B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_THREAD_PRIORITY
+    (0xC000071C, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_THREAD
+    (0xC000071D, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_TRANSACTION
+    (0xC000071E, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_LDR_LOCK
+    (0xC000071F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_LANG
+    (0xC0000720, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_PRI_BACK
+    (0xC0000721, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CALLBACK_RETURNED_THREAD_AFFINITY
+    (0xC0000722, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_LPC_HANDLE_COUNT_EXCEEDED
+    (0xC0000800, Win32Error::ERROR_DISK_REPAIR_DISABLED), // STATUS_DISK_REPAIR_DISABLED
+    (0xC0000801, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_DOMAIN_RENAME_IN_PROGRESS
+    (0xC0000802, Win32Error::ERROR_DISK_QUOTA_EXCEEDED), // STATUS_DISK_QUOTA_EXCEEDED
+    (0xC0000804, Win32Error::ERROR_CONTENT_BLOCKED), // STATUS_CONTENT_BLOCKED
+    (0xC0000805, Win32Error::ERROR_BAD_CLUSTERS), // STATUS_BAD_CLUSTERS
+    (0xC0000806, Win32Error::ERROR_VOLUME_DIRTY), // STATUS_VOLUME_DIRTY
+    (0xC0000808, Win32Error::ERROR_DISK_REPAIR_UNSUCCESSFUL), // STATUS_DISK_REPAIR_UNSUCCESSFUL
+    (0xC0000809, Win32Error::ERROR_CORRUPT_LOG_OVERFULL), // STATUS_CORRUPT_LOG_OVERFULL
+    (0xC000080A, Win32Error::ERROR_CORRUPT_LOG_CORRUPTED), // STATUS_CORRUPT_LOG_CORRUPTED
+    (0xC000080B, Win32Error::ERROR_CORRUPT_LOG_UNAVAILABLE), // STATUS_CORRUPT_LOG_UNAVAILABLE
+    (0xC000080C, Win32Error::ERROR_CORRUPT_LOG_DELETED_FULL), // STATUS_CORRUPT_LOG_DELETED_FULL
+    (0xC000080D, Win32Error::ERROR_CORRUPT_LOG_CLEARED), // STATUS_CORRUPT_LOG_CLEARED
+    (0xC000080E, Win32Error::ERROR_ORPHAN_NAME_EXHAUSTED), // STATUS_ORPHAN_NAME_EXHAUSTED
+    (0xC000080F, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PROACTIVE_SCAN_IN_PROGRESS
+    (0xC0000810, Win32Error::ERROR_ENCRYPTED_IO_NOT_POSSIBLE), // STATUS_ENCRYPTED_IO_NOT_POSSIBLE
+    (0xC0000811, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CORRUPT_LOG_UPLEVEL_RECORDS
+    (0xC0000901, Win32Error::ERROR_FILE_CHECKED_OUT), // STATUS_FILE_CHECKED_OUT
+    (0xC0000902, Win32Error::ERROR_CHECKOUT_REQUIRED), // STATUS_CHECKOUT_REQUIRED
+    (0xC0000903, Win32Error::ERROR_BAD_FILE_TYPE), // STATUS_BAD_FILE_TYPE
+    (0xC0000904, Win32Error::ERROR_FILE_TOO_LARGE), // STATUS_FILE_TOO_LARGE
+    (0xC0000905, Win32Error::ERROR_FORMS_AUTH_REQUIRED), // STATUS_FORMS_AUTH_REQUIRED
+    (0xC0000906, Win32Error::ERROR_VIRUS_INFECTED), // STATUS_VIRUS_INFECTED
+    (0xC0000907, Win32Error::ERROR_VIRUS_DELETED), // STATUS_VIRUS_DELETED
+    (0xC0000908, Win32Error::ERROR_BAD_MCFG_TABLE), // STATUS_BAD_MCFG_TABLE
+    (0xC0000909, Win32Error::ERROR_CANNOT_BREAK_OPLOCK), // STATUS_CANNOT_BREAK_OPLOCK
+    (0xC000090A, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_BAD_KEY
+    (0xC000090B, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_BAD_DATA
+    (0xC000090C, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_NO_KEY
+    (0xC0000910, Win32Error::ERROR_FILE_HANDLE_REVOKED), // STATUS_FILE_HANDLE_REVOKED
+    (0xC0009898, Win32Error::ERROR_WOW_ASSERTION), // STATUS_WOW_ASSERTION
+    (0xC000A000, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_SIGNATURE
+    (0xC000A001, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_HMAC_NOT_SUPPORTED
+    (0xC000A002, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_AUTH_TAG_MISMATCH
+    (0xC000A003, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_STATE_TRANSITION
+    (0xC000A004, Win32Error::ERROR_INVALID_KERNEL_INFO_VERSION), // STATUS_INVALID_KERNEL_INFO_VERSION
+    (0xC000A005, Win32Error::ERROR_INVALID_PEP_INFO_VERSION), // STATUS_INVALID_PEP_INFO_VERSION
+    (0xC000A006, Win32Error::ERROR_HANDLE_REVOKED), // STATUS_HANDLE_REVOKED
+    (0xC000A007, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_EOF_ON_GHOSTED_RANGE
+    (0xC000A010, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_IPSEC_QUEUE_OVERFLOW
+    (0xC000A011, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_ND_QUEUE_OVERFLOW
+    (0xC000A012, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_HOPLIMIT_EXCEEDED
+    (0xC000A013, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_PROTOCOL_NOT_SUPPORTED
+    (0xC000A014, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_FASTPATH_REJECTED
+    (0xC000A080, Win32Error::ERROR_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED), // STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED
+    (0xC000A081, Win32Error::ERROR_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR), // STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR
+    (0xC000A082, Win32Error::ERROR_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR), // STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR
+    (0xC000A083, Win32Error::ERROR_XML_PARSE_ERROR), // STATUS_XML_PARSE_ERROR
+    (0xC000A084, Win32Error::ERROR_XMLDSIG_ERROR), // STATUS_XMLDSIG_ERROR
+    (0xC000A085, Win32Error::ERROR_WRONG_COMPARTMENT), // STATUS_WRONG_COMPARTMENT
+    (0xC000A086, Win32Error::ERROR_AUTHIP_FAILURE), // STATUS_AUTHIP_FAILURE
+    (0xC000A087, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS
+    (0xC000A088, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_DS_OID_NOT_FOUND
+    (0xC000A089, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INCORRECT_ACCOUNT_TYPE
+    (0xC000A100, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_HASH_NOT_SUPPORTED
+    (0xC000A101, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_HASH_NOT_PRESENT
+    (0xC000A121, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_SECONDARY_IC_PROVIDER_NOT_REGISTERED
+    (0xC000A122, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GPIO_CLIENT_INFORMATION_INVALID
+    (0xC000A123, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GPIO_VERSION_NOT_SUPPORTED
+    (0xC000A124, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GPIO_INVALID_REGISTRATION_PACKET
+    (0xC000A125, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GPIO_OPERATION_DENIED
+    (0xC000A126, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_GPIO_INCOMPATIBLE_CONNECT_MODE
+    (0xC000A141, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CANNOT_SWITCH_RUNLEVEL
+    (0xC000A142, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_INVALID_RUNLEVEL_SETTING
+    (0xC000A143, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RUNLEVEL_SWITCH_TIMEOUT
+    (0xC000A145, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RUNLEVEL_SWITCH_AGENT_TIMEOUT
+    (0xC000A146, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_RUNLEVEL_SWITCH_IN_PROGRESS
+    (0xC000A200, Win32Error::ERROR_NOT_APPCONTAINER), // STATUS_NOT_APPCONTAINER
+    (0xC000A201, Win32Error::ERROR_NOT_SUPPORTED_IN_APPCONTAINER), // STATUS_NOT_SUPPORTED_IN_APPCONTAINER
+    (0xC000A202, Win32Error::ERROR_INVALID_PACKAGE_SID_LENGTH), // STATUS_INVALID_PACKAGE_SID_LENGTH
+    (0xC000A281, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APP_DATA_NOT_FOUND
+    (0xC000A282, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APP_DATA_EXPIRED
+    (0xC000A283, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APP_DATA_CORRUPT
+    (0xC000A284, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APP_DATA_LIMIT_EXCEEDED
+    (0xC000A285, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_APP_DATA_REBOOT_REQUIRED
+    (0xC000A2A1, Win32Error::ERROR_OFFLOAD_READ_FLT_NOT_SUPPORTED), // STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED
+    (0xC000A2A2, Win32Error::ERROR_OFFLOAD_WRITE_FLT_NOT_SUPPORTED), // STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED
+    (0xC000A2A3, Win32Error::ERROR_OFFLOAD_READ_FILE_NOT_SUPPORTED), // STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED
+    (0xC000A2A4, Win32Error::ERROR_OFFLOAD_WRITE_FILE_NOT_SUPPORTED), // STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED
+    (0xC000CF00, Win32Error::ERROR_MR_MID_NOT_FOUND), // STATUS_CLOUD_FILE_PROVIDER_UNKNOWN
+    (0xC000CF01, Win32Error::ERROR_CLOUD_FILE_PROVIDER_NOT_RUNNING), // STATUS_CLOUD_FILE_PROVIDER_NOT_RUNNING
+    (0xC000CF02, Win32Error::ERROR_CLOUD_FILE_METADATA_CORRUPT), // STATUS_CLOUD_FILE_METADATA_CORRUPT
+    (0xC000CF03, Win32Error::ERROR_CLOUD_FILE_METADATA_TOO_LARGE), // STATUS_CLOUD_FILE_METADATA_TOO_LARGE
+    (0xC0020001, Win32Error::RPC_S_INVALID_STRING_BINDING), // RPC_NT_INVALID_STRING_BINDING
+    (0xC0020002, Win32Error::RPC_S_WRONG_KIND_OF_BINDING), // RPC_NT_WRONG_KIND_OF_BINDING
+    (0xC0020003, Win32Error::RPC_S_INVALID_BINDING), // RPC_NT_INVALID_BINDING
+    (0xC0020004, Win32Error::RPC_S_PROTSEQ_NOT_SUPPORTED), // RPC_NT_PROTSEQ_NOT_SUPPORTED
+    (0xC0020005, Win32Error::RPC_S_INVALID_RPC_PROTSEQ), // RPC_NT_INVALID_RPC_PROTSEQ
+    (0xC0020006, Win32Error::RPC_S_INVALID_STRING_UUID), // RPC_NT_INVALID_STRING_UUID
+    (0xC0020007, Win32Error::RPC_S_INVALID_ENDPOINT_FORMAT), // RPC_NT_INVALID_ENDPOINT_FORMAT
+    (0xC0020008, Win32Error::RPC_S_INVALID_NET_ADDR), // RPC_NT_INVALID_NET_ADDR
+    (0xC0020009, Win32Error::RPC_S_NO_ENDPOINT_FOUND), // RPC_NT_NO_ENDPOINT_FOUND
+    (0xC002000A, Win32Error::RPC_S_INVALID_TIMEOUT), // RPC_NT_INVALID_TIMEOUT
+    (0xC002000B, Win32Error::RPC_S_OBJECT_NOT_FOUND), // RPC_NT_OBJECT_NOT_FOUND
+    (0xC002000C, Win32Error::RPC_S_ALREADY_REGISTERED), // RPC_NT_ALREADY_REGISTERED
+    (0xC002000D, Win32Error::RPC_S_TYPE_ALREADY_REGISTERED), // RPC_NT_TYPE_ALREADY_REGISTERED
+    (0xC002000E, Win32Error::RPC_S_ALREADY_LISTENING), // RPC_NT_ALREADY_LISTENING
+    (0xC002000F, Win32Error::RPC_S_NO_PROTSEQS_REGISTERED), // RPC_NT_NO_PROTSEQS_REGISTERED
+    (0xC0020010, Win32Error::RPC_S_NOT_LISTENING), // RPC_NT_NOT_LISTENING
+    (0xC0020011, Win32Error::RPC_S_UNKNOWN_MGR_TYPE), // RPC_NT_UNKNOWN_MGR_TYPE
+    (0xC0020012, Win32Error::RPC_S_UNKNOWN_IF), // RPC_NT_UNKNOWN_IF
+    (0xC0020013, Win32Error::RPC_S_NO_BINDINGS), // RPC_NT_NO_BINDINGS
+    (0xC0020014, Win32Error::RPC_S_NO_PROTSEQS), // RPC_NT_NO_PROTSEQS
+    (0xC0020015, Win32Error::RPC_S_CANT_CREATE_ENDPOINT), // RPC_NT_CANT_CREATE_ENDPOINT
+    (0xC0020016, Win32Error::RPC_S_OUT_OF_RESOURCES), // RPC_NT_OUT_OF_RESOURCES
+    (0xC0020017, Win32Error::RPC_S_SERVER_UNAVAILABLE), // RPC_NT_SERVER_UNAVAILABLE
+    (0xC0020018, Win32Error::RPC_S_SERVER_TOO_BUSY), // RPC_NT_SERVER_TOO_BUSY
+    (0xC0020019, Win32Error::RPC_S_INVALID_NETWORK_OPTIONS), // RPC_NT_INVALID_NETWORK_OPTIONS
+    (0xC002001A, Win32Error::RPC_S_NO_CALL_ACTIVE), // RPC_NT_NO_CALL_ACTIVE
+    (0xC002001B, Win32Error::RPC_S_CALL_FAILED), // RPC_NT_CALL_FAILED
+    (0xC002001C, Win32Error::RPC_S_CALL_FAILED_DNE), // RPC_NT_CALL_FAILED_DNE
+    (0xC002001D, Win32Error::RPC_S_PROTOCOL_ERROR), // RPC_NT_PROTOCOL_ERROR
+    (0xC002001F, Win32Error::RPC_S_UNSUPPORTED_TRANS_SYN), // RPC_NT_UNSUPPORTED_TRANS_SYN
+    (0xC0020021, Win32Error::RPC_S_UNSUPPORTED_TYPE), // RPC_NT_UNSUPPORTED_TYPE
+    (0xC0020022, Win32Error::RPC_S_INVALID_TAG), // RPC_NT_INVALID_TAG
+    (0xC0020023, Win32Error::RPC_S_INVALID_BOUND), // RPC_NT_INVALID_BOUND
+    (0xC0020024, Win32Error::RPC_S_NO_ENTRY_NAME), // RPC_NT_NO_ENTRY_NAME
+    (0xC0020025, Win32Error::RPC_S_INVALID_NAME_SYNTAX), // RPC_NT_INVALID_NAME_SYNTAX
+    (0xC0020026, Win32Error::RPC_S_UNSUPPORTED_NAME_SYNTAX), // RPC_NT_UNSUPPORTED_NAME_SYNTAX
+    (0xC0020028, Win32Error::RPC_S_UUID_NO_ADDRESS), // RPC_NT_UUID_NO_ADDRESS
+    (0xC0020029, Win32Error::RPC_S_DUPLICATE_ENDPOINT), // RPC_NT_DUPLICATE_ENDPOINT
+    (0xC002002A, Win32Error::RPC_S_UNKNOWN_AUTHN_TYPE), // RPC_NT_UNKNOWN_AUTHN_TYPE
+    (0xC002002B, Win32Error::RPC_S_MAX_CALLS_TOO_SMALL), // RPC_NT_MAX_CALLS_TOO_SMALL
+    (0xC002002C, Win32Error::RPC_S_STRING_TOO_LONG), // RPC_NT_STRING_TOO_LONG
+    (0xC002002D, Win32Error::RPC_S_PROTSEQ_NOT_FOUND), // RPC_NT_PROTSEQ_NOT_FOUND
+    (0xC002002E, Win32Error::RPC_S_PROCNUM_OUT_OF_RANGE), // RPC_NT_PROCNUM_OUT_OF_RANGE
+    (0xC002002F, Win32Error::RPC_S_BINDING_HAS_NO_AUTH), // RPC_NT_BINDING_HAS_NO_AUTH
+    (0xC0020030, Win32Error::RPC_S_UNKNOWN_AUTHN_SERVICE), // RPC_NT_UNKNOWN_AUTHN_SERVICE
+    (0xC0020031, Win32Error::RPC_S_UNKNOWN_AUTHN_LEVEL), // RPC_NT_UNKNOWN_AUTHN_LEVEL
+    (0xC0020032, Win32Error::RPC_S_INVALID_AUTH_IDENTITY), // RPC_NT_INVALID_AUTH_IDENTITY
+    (0xC0020033, Win32Error::RPC_S_UNKNOWN_AUTHZ_SERVICE), // RPC_NT_UNKNOWN_AUTHZ_SERVICE
+    (0xC0020034, Win32Error::EPT_S_INVALID_ENTRY), // EPT_NT_INVALID_ENTRY
+    (0xC0020035, Win32Error::EPT_S_CANT_PERFORM_OP), // EPT_NT_CANT_PERFORM_OP
+    (0xC0020036, Win32Error::EPT_S_NOT_REGISTERED), // EPT_NT_NOT_REGISTERED
+    (0xC0020037, Win32Error::RPC_S_NOTHING_TO_EXPORT), // RPC_NT_NOTHING_TO_EXPORT
+    (0xC0020038, Win32Error::RPC_S_INCOMPLETE_NAME), // RPC_NT_INCOMPLETE_NAME
+    (0xC0020039, Win32Error::RPC_S_INVALID_VERS_OPTION), // RPC_NT_INVALID_VERS_OPTION
+    (0xC002003A, Win32Error::RPC_S_NO_MORE_MEMBERS), // RPC_NT_NO_MORE_MEMBERS
+    (0xC002003B, Win32Error::RPC_S_NOT_ALL_OBJS_UNEXPORTED), // RPC_NT_NOT_ALL_OBJS_UNEXPORTED
+    (0xC002003C, Win32Error::RPC_S_INTERFACE_NOT_FOUND), // RPC_NT_INTERFACE_NOT_FOUND
+    (0xC002003D, Win32Error::RPC_S_ENTRY_ALREADY_EXISTS), // RPC_NT_ENTRY_ALREADY_EXISTS
+    (0xC002003E, Win32Error::RPC_S_ENTRY_NOT_FOUND), // RPC_NT_ENTRY_NOT_FOUND
+    (0xC002003F, Win32Error::RPC_S_NAME_SERVICE_UNAVAILABLE), // RPC_NT_NAME_SERVICE_UNAVAILABLE
+    (0xC0020040, Win32Error::RPC_S_INVALID_NAF_ID), // RPC_NT_INVALID_NAF_ID
+    (0xC0020041, Win32Error::RPC_S_CANNOT_SUPPORT), // RPC_NT_CANNOT_SUPPORT
+    (0xC0020042, Win32Error::RPC_S_NO_CONTEXT_AVAILABLE), // RPC_NT_NO_CONTEXT_AVAILABLE
+    (0xC0020043, Win32Error::RPC_S_INTERNAL_ERROR), // RPC_NT_INTERNAL_ERROR
+    (0xC0020044, Win32Error::RPC_S_ZERO_DIVIDE), // RPC_NT_ZERO_DIVIDE
+    (0xC0020045, Win32Error::RPC_S_ADDRESS_ERROR), // RPC_NT_ADDRESS_ERROR
+    (0xC0020046, Win32Error::RPC_S_FP_DIV_ZERO), // RPC_NT_FP_DIV_ZERO
+    (0xC0020047, Win32Error::RPC_S_FP_UNDERFLOW), // RPC_NT_FP_UNDERFLOW
+    (0xC0020048, Win32Error::RPC_S_FP_OVERFLOW), // RPC_NT_FP_OVERFLOW
+    (0xC0020049, Win32Error::RPC_S_CALL_IN_PROGRESS), // RPC_NT_CALL_IN_PROGRESS
+    (0xC002004A, Win32Error::RPC_S_NO_MORE_BINDINGS), // RPC_NT_NO_MORE_BINDINGS
+    (0xC002004B, Win32Error::RPC_S_GROUP_MEMBER_NOT_FOUND), // RPC_NT_GROUP_MEMBER_NOT_FOUND
+    (0xC002004C, Win32Error::EPT_S_CANT_CREATE), // EPT_NT_CANT_CREATE
+    (0xC002004D, Win32Error::RPC_S_INVALID_OBJECT), // RPC_NT_INVALID_OBJECT
+    (0xC002004F, Win32Error::RPC_S_NO_INTERFACES), // RPC_NT_NO_INTERFACES
+    (0xC0020050, Win32Error::RPC_S_CALL_CANCELLED), // RPC_NT_CALL_CANCELLED
+    (0xC0020051, Win32Error::RPC_S_BINDING_INCOMPLETE), // RPC_NT_BINDING_INCOMPLETE
+    (0xC0020052, Win32Error::RPC_S_COMM_FAILURE), // RPC_NT_COMM_FAILURE
+    (0xC0020053, Win32Error::RPC_S_UNSUPPORTED_AUTHN_LEVEL), // RPC_NT_UNSUPPORTED_AUTHN_LEVEL
+    (0xC0020054, Win32Error::RPC_S_NO_PRINC_NAME), // RPC_NT_NO_PRINC_NAME
+    (0xC0020055, Win32Error::RPC_S_NOT_RPC_ERROR), // RPC_NT_NOT_RPC_ERROR
+    (0xC0020057, Win32Error::RPC_S_SEC_PKG_ERROR), // RPC_NT_SEC_PKG_ERROR
+    (0xC0020058, Win32Error::RPC_S_NOT_CANCELLED), // RPC_NT_NOT_CANCELLED
+    (0xC0020062, Win32Error::RPC_S_INVALID_ASYNC_HANDLE), // RPC_NT_INVALID_ASYNC_HANDLE
+    (0xC0020063, Win32Error::RPC_S_INVALID_ASYNC_CALL), // RPC_NT_INVALID_ASYNC_CALL
+    (0xC0020064, Win32Error::RPC_S_PROXY_ACCESS_DENIED), // RPC_NT_PROXY_ACCESS_DENIED
+    (0xC0020065, Win32Error::RPC_S_COOKIE_AUTH_FAILED), // RPC_NT_COOKIE_AUTH_FAILED
+    (0xC0030001, Win32Error::RPC_X_NO_MORE_ENTRIES), // RPC_NT_NO_MORE_ENTRIES
+    (0xC0030002, Win32Error::RPC_X_SS_CHAR_TRANS_OPEN_FAIL), // RPC_NT_SS_CHAR_TRANS_OPEN_FAIL
+    (0xC0030003, Win32Error::RPC_X_SS_CHAR_TRANS_SHORT_FILE), // RPC_NT_SS_CHAR_TRANS_SHORT_FILE
+    (0xC0030004, Win32Error::RPC_X_SS_IN_NULL_CONTEXT), // RPC_NT_SS_IN_NULL_CONTEXT
+    (0xC0030005, Win32Error::ERROR_INVALID_HANDLE), // RPC_NT_SS_CONTEXT_MISMATCH
+    (0xC0030006, Win32Error::RPC_X_SS_CONTEXT_DAMAGED), // RPC_NT_SS_CONTEXT_DAMAGED
+    (0xC0030007, Win32Error::RPC_X_SS_HANDLES_MISMATCH), // RPC_NT_SS_HANDLES_MISMATCH
+    (0xC0030008, Win32Error::RPC_X_SS_CANNOT_GET_CALL_HANDLE), // RPC_NT_SS_CANNOT_GET_CALL_HANDLE
+    (0xC0030009, Win32Error::RPC_X_NULL_REF_POINTER), // RPC_NT_NULL_REF_POINTER
+    (0xC003000A, Win32Error::RPC_X_ENUM_VALUE_OUT_OF_RANGE), // RPC_NT_ENUM_VALUE_OUT_OF_RANGE
+    (0xC003000B, Win32Error::RPC_X_BYTE_COUNT_TOO_SMALL), // RPC_NT_BYTE_COUNT_TOO_SMALL
+    (0xC003000C, Win32Error::RPC_X_BAD_STUB_DATA), // RPC_NT_BAD_STUB_DATA
+    (0xC0030059, Win32Error::RPC_X_INVALID_ES_ACTION), // RPC_NT_INVALID_ES_ACTION
+    (0xC003005A, Win32Error::RPC_X_WRONG_ES_VERSION), // RPC_NT_WRONG_ES_VERSION
+    (0xC003005B, Win32Error::RPC_X_WRONG_STUB_VERSION), // RPC_NT_WRONG_STUB_VERSION
+    (0xC003005C, Win32Error::RPC_X_INVALID_PIPE_OBJECT), // RPC_NT_INVALID_PIPE_OBJECT
+    (0xC003005D, Win32Error::RPC_X_WRONG_PIPE_ORDER), // RPC_NT_INVALID_PIPE_OPERATION
+    (0xC003005E, Win32Error::RPC_X_WRONG_PIPE_VERSION), // RPC_NT_WRONG_PIPE_VERSION
+    (0xC003005F, Win32Error::RPC_X_PIPE_CLOSED), // RPC_NT_PIPE_CLOSED
+    (0xC0030060, Win32Error::RPC_X_PIPE_DISCIPLINE_ERROR), // RPC_NT_PIPE_DISCIPLINE_ERROR
+    (0xC0030061, Win32Error::RPC_X_PIPE_EMPTY), // RPC_NT_PIPE_EMPTY
+];
+
+/// The facilities [`NTSTATUS_TO_WIN32`] has entries for: generic, `RPC_NT_*` and RPC stubs.
+#[cfg(test)]
+const MAPPED_FACILITIES: [u16; 3] = [0x0, 0x2, 0x3];
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ntstatus::RawNtStatus,
+        util::catalog_entries,
+        win32_error::{MAPPED_FACILITIES, NTSTATUS_TO_WIN32},
+        NtStatus, Win32Error,
+    };
+
+    #[test]
+    fn test_win32_table_sorted() {
+        assert!(NTSTATUS_TO_WIN32.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_win32_table_facilities() {
+        for &(status, _) in NTSTATUS_TO_WIN32 {
+            let facility = ((status >> 16) & 0xFFF) as u16;
+            assert!(MAPPED_FACILITIES.contains(&facility), "{status:#010X}");
+        }
+
+        // Families outside the table are intentionally unmapped, not mapped to the wrong code
+        for facility in [0xA, 0x29, 0x2A, 0x3A, 0x5C] {
+            assert!(!MAPPED_FACILITIES.contains(&facility));
+            let status = RawNtStatus::from(0xC000_0001 | (facility as u32) << 16);
+            assert!(status.to_win32_error() == Win32Error::ERROR_MR_MID_NOT_FOUND);
+        }
+    }
+
+    #[test]
+    fn test_win32_table_generic_facility() {
+        for (name, value) in catalog_entries() {
+            let Some(value) = value.strip_prefix("0x") else {
+                continue;
+            };
+            let bits = u32::from_str_radix(value, 16).unwrap();
+            if (bits >> 16) & 0xFFF == 0 {
+                assert!(
+                    NTSTATUS_TO_WIN32
+                        .binary_search_by_key(&bits, |&(status, _)| status)
+                        .is_ok(),
+                    "{name} is unmapped"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ntstatus_to_win32_error() {
+        let cases = [
+            (NtStatus::STATUS_SUCCESS, Win32Error::ERROR_SUCCESS),
+            (
+                NtStatus::STATUS_ACCESS_DENIED,
+                Win32Error::ERROR_ACCESS_DENIED,
+            ),
+            (
+                NtStatus::STATUS_OBJECT_NAME_NOT_FOUND,
+                Win32Error::ERROR_FILE_NOT_FOUND,
+            ),
+            (
+                NtStatus::STATUS_OBJECT_PATH_NOT_FOUND,
+                Win32Error::ERROR_PATH_NOT_FOUND,
+            ),
+            (
+                NtStatus::STATUS_INVALID_HANDLE,
+                Win32Error::ERROR_INVALID_HANDLE,
+            ),
+            (
+                NtStatus::STATUS_BUFFER_TOO_SMALL,
+                Win32Error::ERROR_INSUFFICIENT_BUFFER,
+            ),
+            (
+                NtStatus::STATUS_BUFFER_OVERFLOW,
+                Win32Error::ERROR_MORE_DATA,
+            ),
+            (NtStatus::STATUS_PENDING, Win32Error::ERROR_IO_PENDING),
+            (NtStatus::STATUS_BREAKPOINT, Win32Error::new(0x80000003)),
+            (
+                NtStatus::STATUS_TOO_MANY_THREADS,
+                Win32Error::ERROR_TOO_MANY_THREADS,
+            ),
+            (NtStatus::STATUS_DS_BUSY, Win32Error::ERROR_DS_BUSY),
+            (
+                NtStatus::STATUS_SMARTCARD_WRONG_PIN,
+                Win32Error::SCARD_W_WRONG_CHV,
+            ),
+        ];
+
+        for (status, error) in cases {
+            assert!(status.to_win32_error() == error);
+            assert!(Win32Error::from(status) == error);
+        }
+    }
+
+    #[test]
+    fn test_raw_ntstatus_to_win32_error() {
+        let cases: [(u32, u32); 6] = [
+            // customer statuses pass through untouched
+            (0xE1230042, 0xE1230042),
+            // RPC_NT_CALL_FAILED maps whether or not status-rpc is enabled
+            (0xC002001B, 1726),
+            // FACILITY_NTWIN32 carries the code in the low word
+            (0xC0070005, 5),
+            (0x80070057, 87),
+            // the reserved bit is ignored
+            (0xD0000022, 5),
+            (0xC0FF0001, 317),
+        ];
+
+        for (status, error) in cases {
+            assert_eq!(RawNtStatus::from(status).to_win32_error().value(), error);
+        }
+    }
+
+    #[test]
+    fn test_win32_error_to_ntstatus() {
+        assert!(Win32Error::ERROR_ACCESS_DENIED.to_ntstatus().is_none());
+        assert!(Win32Error::ERROR_FILE_NOT_FOUND.to_ntstatus().is_none());
+        assert!(Win32Error::new(0xDEAD).to_ntstatus().is_none());
+        assert!(
+            Win32Error::ERROR_DIR_NOT_EMPTY.to_ntstatus()
+                == Some(NtStatus::STATUS_DIRECTORY_NOT_EMPTY.raw())
+        );
+        assert!(Win32Error::ERROR_SUCCESS.to_ntstatus() == Some(NtStatus::STATUS_SUCCESS.raw()));
+        assert!(
+            Win32Error::new(0x80000003).to_ntstatus() == Some(NtStatus::STATUS_BREAKPOINT.raw())
+        );
+
+        // every unambiguous code round-trips
+        for &(_, error) in NTSTATUS_TO_WIN32 {
+            if let Some(status) = error.to_ntstatus() {
+                assert!(status.to_win32_error() == error);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[cfg(not(feature = "nosym"))]
+    fn test_win32_error_to_string() {
+        use anyhow::__private::format;
+        assert_eq!(
+            format!("{:?}", Win32Error::ERROR_ACCESS_DENIED),
+            "ERROR_ACCESS_DENIED"
+        );
+        assert_eq!(
+            format!("{:?}", Win32Error::new(0xDEAD)),
+            "Win32Error(57005)"
+        );
+        assert_eq!(
+            Win32Error::RPC_S_CALL_FAILED.name(),
+            Some("RPC_S_CALL_FAILED")
+        );
+    }
+}